@@ -0,0 +1,214 @@
+//! Native controls of the header extension builder: the thread pool which builds the
+//! commitments, and the estimation of the build time used by block authoring to stop adding DA
+//! data before the header extension would overrun the slot.
+use crate::metrics::avail::HeaderExtensionBuilderMetrics as Metrics;
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::{
+	num::NonZeroUsize,
+	str::FromStr,
+	sync::{Mutex, OnceLock},
+	time::Duration,
+};
+
+/// Weight kept by past builds on each new build, in the build time estimation.
+const DECAY: f64 = 0.9;
+
+static POOL: OnceLock<ThreadPool> = OnceLock::new();
+static BUILD_TIME: Mutex<BuildTimeModel> = Mutex::new(BuildTimeModel::new());
+
+/// Scheduling priority of the header builder threads.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThreadPriority {
+	#[default]
+	Normal,
+	/// Lowest priority, so commitment building yields to networking and import.
+	Low,
+}
+
+impl FromStr for ThreadPriority {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"normal" => Ok(Self::Normal),
+			"low" => Ok(Self::Low),
+			_ => Err(format!(
+				"Invalid thread priority `{s}`, expected `normal` or `low`"
+			)),
+		}
+	}
+}
+
+/// Thread pool configuration of the header builder.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolConfig {
+	/// Number of threads, one per CPU core if `None`.
+	pub threads: Option<NonZeroUsize>,
+	pub priority: ThreadPriority,
+}
+
+/// Creates the thread pool of the header builder. Until then, commitments are built on the global
+/// `rayon` pool.
+pub fn init_pool(config: PoolConfig) -> Result<(), String> {
+	let mut builder = ThreadPoolBuilder::new().thread_name(|idx| format!("header-builder-{idx}"));
+	if let Some(threads) = config.threads {
+		builder = builder.num_threads(threads.get());
+	}
+	if config.priority == ThreadPriority::Low {
+		builder = builder.start_handler(|_| lower_thread_priority());
+	}
+
+	let pool = builder.build().map_err(|e| e.to_string())?;
+	Metrics::observe_pool_threads(pool.current_num_threads());
+	POOL.set(pool)
+		.map_err(|_| "Header builder pool is already initialized".to_string())
+}
+
+/// Runs `build` on the header builder pool, so its parallel work is done by the pool threads.
+pub fn install<R: Send>(build: impl FnOnce() -> R + Send) -> R {
+	match POOL.get() {
+		Some(pool) => pool.install(build),
+		None => build(),
+	}
+}
+
+#[cfg(target_os = "linux")]
+fn lower_thread_priority() {
+	// On Linux, the niceness of `PRIO_PROCESS` 0 is the one of the calling thread.
+	const LOWEST_PRIORITY: libc::c_int = 19;
+	// SAFETY: `setpriority` only reads its arguments.
+	if unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, LOWEST_PRIORITY) } != 0 {
+		log::warn!("Header builder thread priority cannot be lowered");
+	}
+}
+
+#[cfg(not(target_os = "linux"))]
+fn lower_thread_priority() {
+	log::warn!("Header builder thread priority is only supported on Linux");
+}
+
+/// Records that building the header extension of `data_len` bytes of DA data took `took`.
+pub fn observe_build(data_len: usize, took: Duration) {
+	let Ok(mut model) = BUILD_TIME.lock() else {
+		return;
+	};
+
+	if let Some(estimate) = model.estimate(data_len as f64) {
+		Metrics::observe_estimated_execution_time(Duration::from_micros(estimate as u64));
+	}
+	model.observe(data_len as f64, took.as_micros() as f64);
+}
+
+/// Estimates how long building the header extension of `data_len` bytes of DA data takes, once
+/// enough builds were observed.
+pub fn estimate_build_time(data_len: usize) -> Option<Duration> {
+	let model = BUILD_TIME.lock().ok()?;
+	let estimate = model.estimate(data_len as f64)?;
+	Some(Duration::from_micros(estimate as u64))
+}
+
+/// Linear model `micros = fixed + per_byte * data_len` of the build time, fitted by least
+/// squares over the past builds, which lose weight by `DECAY` on each build.
+#[derive(Debug, Default)]
+struct BuildTimeModel {
+	weight: f64,
+	sum_x: f64,
+	sum_y: f64,
+	sum_xx: f64,
+	sum_xy: f64,
+}
+
+impl BuildTimeModel {
+	const fn new() -> Self {
+		Self {
+			weight: 0.0,
+			sum_x: 0.0,
+			sum_y: 0.0,
+			sum_xx: 0.0,
+			sum_xy: 0.0,
+		}
+	}
+
+	fn observe(&mut self, x: f64, y: f64) {
+		self.weight = self.weight * DECAY + 1.0;
+		self.sum_x = self.sum_x * DECAY + x;
+		self.sum_y = self.sum_y * DECAY + y;
+		self.sum_xx = self.sum_xx * DECAY + x * x;
+		self.sum_xy = self.sum_xy * DECAY + x * y;
+	}
+
+	fn estimate(&self, x: f64) -> Option<f64> {
+		// A single build cannot tell the fixed cost from the cost per byte.
+		if self.weight <= 1.0 {
+			return None;
+		}
+
+		let det = self.weight * self.sum_xx - self.sum_x * self.sum_x;
+		let mean_y = self.sum_y / self.weight;
+		// Builds of (almost) the same size can only estimate the mean.
+		if det <= f64::EPSILON * self.weight * self.sum_xx {
+			return Some(mean_y);
+		}
+
+		let per_byte = ((self.weight * self.sum_xy - self.sum_x * self.sum_y) / det).max(0.0);
+		let fixed = mean_y - per_byte * self.sum_x / self.weight;
+		Some((fixed + per_byte * x).max(0.0))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn thread_priority_from_str() {
+		assert_eq!("normal".parse(), Ok(ThreadPriority::Normal));
+		assert_eq!("low".parse(), Ok(ThreadPriority::Low));
+		assert!("high".parse::<ThreadPriority>().is_err());
+	}
+
+	#[test]
+	fn build_time_needs_two_builds() {
+		let mut model = BuildTimeModel::new();
+		assert_eq!(model.estimate(1_000.0), None);
+		model.observe(1_000.0, 500.0);
+		assert_eq!(model.estimate(1_000.0), None);
+		model.observe(1_000.0, 700.0);
+		let estimate = model.estimate(1_000_000.0).unwrap();
+		assert!(estimate > 500.0 && estimate < 700.0);
+	}
+
+	#[test]
+	fn build_time_follows_data_len() {
+		let mut model = BuildTimeModel::new();
+		// 100ms plus 1µs per byte.
+		for len in [0.0, 1_000.0, 250_000.0, 10_000.0, 2_000_000.0, 500_000.0] {
+			model.observe(len, 100_000.0 + len);
+		}
+
+		for len in [0.0, 100_000.0, 4_000_000.0] {
+			let estimate = model.estimate(len).unwrap();
+			assert!(
+				(estimate - (100_000.0 + len)).abs() < 1.0,
+				"{len}: {estimate}"
+			);
+		}
+	}
+
+	#[test]
+	fn recent_builds_weight_more() {
+		let mut model = BuildTimeModel::new();
+		for _ in 0..10 {
+			model.observe(0.0, 100.0);
+			model.observe(1_000.0, 1_100.0);
+		}
+		for _ in 0..30 {
+			model.observe(0.0, 200.0);
+			model.observe(1_000.0, 2_200.0);
+		}
+
+		let estimate = model.estimate(1_000.0).unwrap();
+		assert!((estimate - 2_200.0).abs() < 50.0, "{estimate}");
+	}
+}
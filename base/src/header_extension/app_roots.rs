@@ -0,0 +1,234 @@
+use super::HeaderExtensionBuilderData;
+use avail_core::{data_proof::TxDataRoots, AppId, Keccak256};
+use binary_merkle_tree::{merkle_proof, merkle_root};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_runtime::{ConsensusEngineId, Digest, DigestItem};
+use sp_std::{collections::btree_map::BTreeMap, iter::repeat, vec::Vec};
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Prefix used to derive the leaf of an `AppId` namespace, so it can never collide with the leaf
+/// of a data submission.
+pub const APP_ROOT_LEAF_PREFIX: &[u8] = b"avail:app_root";
+
+/// Engine id of the consensus digest item signalling the [`DataRootVersion`] of a block.
+/// Headers without it commit a [`DataRootVersion::V1`] data root.
+pub const DATA_ROOT_VERSION_ENGINE_ID: ConsensusEngineId = *b"avdr";
+
+/// Layout of the submitted data sub-trie committed in the data root.
+///
+/// Headers signal it with the digest item of [`DataRootVersion::digest_item`].
+#[derive(Clone, Copy, Debug, Default, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DataRootVersion {
+	/// One flat Merkle tree over all data submissions, in transaction order.
+	#[default]
+	V1,
+	/// Data submissions are grouped per `AppId` into sub-roots, and the submitted root is the
+	/// Merkle root of those sub-roots sorted by `AppId`. See [`AppRoot`].
+	/// Full and hash-only submissions use domain separated leaves, see
+	/// [`SubmittedData::leaf_of`](super::SubmittedData::leaf_of).
+	V2,
+}
+
+impl DataRootVersion {
+	/// Digest item signalling `self` in the header, or `None` for `V1` so legacy headers are
+	/// left unchanged.
+	pub fn digest_item(self) -> Option<DigestItem> {
+		(self != Self::V1)
+			.then(|| DigestItem::Consensus(DATA_ROOT_VERSION_ENGINE_ID, self.encode()))
+	}
+
+	/// Data root version signalled by `digest`, `V1` if it signals none.
+	pub fn from_digest(digest: &Digest) -> Self {
+		digest
+			.convert_first(|item| item.consensus_try_to::<Self>(&DATA_ROOT_VERSION_ENGINE_ID))
+			.unwrap_or_default()
+	}
+}
+
+/// Sub-root of the data submissions of one `AppId`.
+#[derive(Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AppRoot {
+	pub app_id: AppId,
+	/// Number of data submissions of `app_id`.
+	pub count: u32,
+	/// Merkle root of the [`DataRootVersion::V2`] leaves of the data submissions of `app_id`, in
+	/// transaction order and balanced with `H256::zero` leaves.
+	pub root: H256,
+}
+
+impl AppRoot {
+	/// Leaf of this namespace in the submitted sub-trie:
+	/// `keccak_256(APP_ROOT_LEAF_PREFIX ++ app_id ++ count ++ root)`, where `app_id` and `count`
+	/// are encoded as big endian.
+	pub fn leaf(&self) -> H256 {
+		let mut preimage = APP_ROOT_LEAF_PREFIX.to_vec();
+		preimage.extend_from_slice(&self.app_id.0.to_be_bytes());
+		preimage.extend_from_slice(&self.count.to_be_bytes());
+		preimage.extend_from_slice(self.root.as_bytes());
+		H256(keccak_256(&preimage))
+	}
+}
+
+/// Merkle proof of one leaf of the namespaced submitted sub-trie.
+#[derive(Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AppRootProof {
+	/// Namespace of the leaf, or `None` if the leaf is a `H256::zero` balancing leaf.
+	pub app_root: Option<AppRoot>,
+	pub proof: Vec<H256>,
+	pub number_of_leaves: u32,
+	pub leaf_index: u32,
+}
+
+/// Proof of the data submissions of one `AppId` against the submitted root of
+/// [`DataRootVersion::V2`].
+#[derive(Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum AppDataProof {
+	/// The namespace of the `AppId` is included, and `leaves` are all its data submission leaves.
+	/// Recomputing `app_root.root` from `leaves` proves the completeness of the set.
+	Inclusion {
+		app_root: AppRootProof,
+		leaves: Vec<H256>,
+	},
+	/// The `AppId` has no data submissions: `left` and `right` are adjacent leaves whose
+	/// namespaces enclose it.
+	///  - `left` is `None` if `right` is the first leaf.
+	///  - `right` is `None` if `left` is the last leaf, and it is a balancing leaf if `left` is
+	///  the last namespace.
+	///  - Both are `None` if the block has no data submissions.
+	Absence {
+		left: Option<AppRootProof>,
+		right: Option<AppRootProof>,
+	},
+}
+
+/// Response of `KateApi::app_data_proof`.
+#[derive(Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AppDataProofResponse {
+	pub roots: TxDataRoots,
+	pub proof: AppDataProof,
+}
+
+impl HeaderExtensionBuilderData {
+	/// Generates the sub-trie roots using the `version` layout for the submitted sub-trie.
+	pub fn roots_of(&self, version: DataRootVersion) -> TxDataRoots {
+		match version {
+			DataRootVersion::V1 => self.roots(),
+			DataRootVersion::V2 => {
+				TxDataRoots::new(self.namespaced_submitted_root(), self.bridged_root())
+			},
+		}
+	}
+
+	/// Generates the data root using the `version` layout for the submitted sub-trie.
+	pub fn data_root_of(&self, version: DataRootVersion) -> H256 {
+		self.roots_of(version).data_root
+	}
+
+	/// Sub-roots of the data submissions, one per `AppId` and sorted by `AppId`.
+	pub fn app_roots(&self) -> Vec<AppRoot> {
+		let mut by_app = BTreeMap::<u32, Vec<H256>>::new();
+		for submission in self.data_submissions.iter() {
+			by_app
+				.entry(submission.id.0)
+				.or_default()
+				.push(submission.leaf_of(DataRootVersion::V2));
+		}
+
+		by_app
+			.into_iter()
+			.map(|(id, leaves)| AppRoot {
+				app_id: AppId(id),
+				count: leaves.len() as u32,
+				root: balanced_root(leaves),
+			})
+			.collect()
+	}
+
+	/// Generates the namespaced root of **Data Submit** extrinsics, using the leaves of
+	/// [`Self::app_roots`] after balancing the merkle tree with `H256::zero` leaves.
+	/// If `submitted` is empty, it will return `H256::zero()`.
+	pub fn namespaced_submitted_root(&self) -> H256 {
+		if self.data_submissions.is_empty() {
+			return H256::zero();
+		}
+
+		balanced_root(self.app_roots().iter().map(AppRoot::leaf).collect())
+	}
+
+	/// Creates the proof of inclusion and completeness, or of absence, of the data submissions of
+	/// `app_id` against [`Self::namespaced_submitted_root`].
+	pub fn app_data_proof(&self, app_id: AppId) -> AppDataProof {
+		let app_roots = self.app_roots();
+		let ns_leaves = app_roots.iter().map(AppRoot::leaf).collect::<Vec<_>>();
+
+		match app_roots.binary_search_by_key(&app_id.0, |r| r.app_id.0) {
+			Ok(idx) => AppDataProof::Inclusion {
+				app_root: app_root_proof(&app_roots, &ns_leaves, idx),
+				leaves: self
+					.data_submissions
+					.iter()
+					.filter(|s| s.id == app_id)
+					.map(|s| s.leaf_of(DataRootVersion::V2))
+					.collect(),
+			},
+			Err(_) if app_roots.is_empty() => AppDataProof::Absence {
+				left: None,
+				right: None,
+			},
+			Err(idx) => {
+				let balanced_len = ns_leaves.len().next_power_of_two();
+				let left = idx
+					.checked_sub(1)
+					.map(|left| app_root_proof(&app_roots, &ns_leaves, left));
+				let right =
+					(idx < balanced_len).then(|| app_root_proof(&app_roots, &ns_leaves, idx));
+				AppDataProof::Absence { left, right }
+			},
+		}
+	}
+}
+
+/// Merkle root of `leaves` after balancing the tree with `H256::zero` leaves.
+fn balanced_root(leaves: Vec<H256>) -> H256 {
+	let balanced_len = leaves.len().next_power_of_two();
+	let len = leaves.len();
+	merkle_root::<Keccak256, _>(
+		leaves
+			.into_iter()
+			.chain(repeat(H256::zero()).take(balanced_len - len)),
+	)
+}
+
+/// Merkle proof of the `leaf_idx` leaf of the balanced namespaced sub-trie built from `leaves`.
+/// Indexes past `app_roots` refer to balancing leaves.
+fn app_root_proof(app_roots: &[AppRoot], leaves: &[H256], leaf_idx: usize) -> AppRootProof {
+	let balanced_len = leaves.len().next_power_of_two();
+	let balanced = leaves
+		.iter()
+		.copied()
+		.chain(repeat(H256::zero()))
+		.take(balanced_len)
+		.collect::<Vec<_>>();
+	let mp = merkle_proof::<Keccak256, _, _>(balanced, leaf_idx);
+
+	AppRootProof {
+		app_root: app_roots.get(leaf_idx).cloned(),
+		proof: mp.proof,
+		number_of_leaves: mp.number_of_leaves as u32,
+		leaf_index: mp.leaf_index as u32,
+	}
+}
@@ -1,4 +1,4 @@
-use super::HeaderExtensionDataFilter;
+use super::{DataRootVersion, HeaderExtensionDataFilter};
 use avail_core::OpaqueExtrinsic;
 use avail_core::{
 	app_extrinsic::AppExtrinsic,
@@ -12,7 +12,7 @@ use derive_more::Constructor;
 use sp_core::H256;
 use sp_io::hashing::keccak_256;
 use sp_runtime_interface::pass_by::PassByCodec;
-use sp_std::{iter::repeat, vec::Vec};
+use sp_std::{collections::btree_map::BTreeMap, iter::repeat, vec::Vec};
 
 #[derive(Constructor, Debug, Encode, Decode, Clone, PartialEq, Eq)]
 pub struct BridgedData {
@@ -20,11 +20,100 @@ pub struct BridgedData {
 	pub addr_msg: AddressedMessage,
 }
 
-#[derive(Debug, Constructor, Encode, Decode, PartialEq, Eq)]
+/// Maximum number of proofs created by [`HeaderExtensionBuilderData::proofs_of`].
+pub const MAX_DATA_PROOFS: usize = 1_024;
+
+/// Prefix used to derive the leaf of a commitment-only submission in [`DataRootVersion::V1`].
+///
+/// It does not separate the leaf kinds: a full submission whose data is
+/// `DATA_HASH_LEAF_PREFIX ++ data_hash ++ len` has the leaf of that commitment-only submission.
+pub const DATA_HASH_LEAF_PREFIX: &[u8] = b"avail:data_hash";
+
+/// First byte of the leaf preimage of a full submission, from [`DataRootVersion::V2`].
+pub const FULL_LEAF_TAG: u8 = 0;
+/// First byte of the leaf preimage of a commitment-only submission, from [`DataRootVersion::V2`].
+pub const DATA_HASH_LEAF_TAG: u8 = 1;
+
+/// Kind of leaf a data submission contributes to the submitted sub-trie.
+#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq, Eq)]
+pub enum SubmittedDataKind {
+	/// `data` contains the full blob, which is also part of the data matrix.
+	Full,
+	/// `data` contains only the 32 bytes hash of a blob of `len` bytes, which is not part of the
+	/// data matrix.
+	HashOnly { len: u32 },
+}
+
+#[derive(Debug, Encode, Decode, PartialEq, Eq)]
 pub struct SubmittedData {
 	pub id: AppId,
 	pub tx_index: u32,
 	pub data: Vec<u8>,
+	pub kind: SubmittedDataKind,
+}
+
+impl SubmittedData {
+	pub fn new(id: AppId, tx_index: u32, data: Vec<u8>) -> Self {
+		Self {
+			id,
+			tx_index,
+			data,
+			kind: SubmittedDataKind::Full,
+		}
+	}
+
+	pub fn new_hash_only(id: AppId, tx_index: u32, data_hash: H256, len: u32) -> Self {
+		Self {
+			id,
+			tx_index,
+			data: data_hash.as_bytes().to_vec(),
+			kind: SubmittedDataKind::HashOnly { len },
+		}
+	}
+
+	/// Leaf of this submission in the submitted sub-trie of [`DataRootVersion::V1`].
+	///  - Full submissions use `keccak_256(data)`.
+	///  - Hash-only submissions use `keccak_256(DATA_HASH_LEAF_PREFIX ++ data_hash ++ len)`,
+	///  where `len` is encoded as big endian.
+	///
+	/// Both kinds hash caller provided bytes, so they are not domain separated, see
+	/// [`DATA_HASH_LEAF_PREFIX`].
+	pub fn leaf(&self) -> H256 {
+		match self.kind {
+			SubmittedDataKind::Full => H256(keccak_256(&self.data)),
+			SubmittedDataKind::HashOnly { len } => {
+				let mut preimage = DATA_HASH_LEAF_PREFIX.to_vec();
+				preimage.extend_from_slice(&self.data);
+				preimage.extend_from_slice(&len.to_be_bytes());
+				H256(keccak_256(&preimage))
+			},
+		}
+	}
+
+	/// Leaf of this submission in the submitted sub-trie of `version`.
+	///
+	/// From [`DataRootVersion::V2`], the leaf kinds are domain separated by the first byte of
+	/// their preimage:
+	///  - Full submissions use `keccak_256(FULL_LEAF_TAG ++ data)`.
+	///  - Hash-only submissions use `keccak_256(DATA_HASH_LEAF_TAG ++ data_hash ++ len)`, where
+	///  `len` is encoded as big endian.
+	pub fn leaf_of(&self, version: DataRootVersion) -> H256 {
+		if version == DataRootVersion::V1 {
+			return self.leaf();
+		}
+
+		let (tag, len) = match self.kind {
+			SubmittedDataKind::Full => (FULL_LEAF_TAG, None),
+			SubmittedDataKind::HashOnly { len } => (DATA_HASH_LEAF_TAG, Some(len)),
+		};
+		let mut preimage = Vec::with_capacity(1 + self.data.len() + 4);
+		preimage.push(tag);
+		preimage.extend_from_slice(&self.data);
+		if let Some(len) = len {
+			preimage.extend_from_slice(&len.to_be_bytes());
+		}
+		H256(keccak_256(&preimage))
+	}
 }
 
 impl GetAppId for SubmittedData {
@@ -118,6 +207,9 @@ impl HeaderExtensionBuilderData {
 	///   we end up overpaying L1 for data attestation, where we want to prove data inclusion
 	///   on Avail.
 	/// - It should not be possible to pass an internal node as a blob leaf.
+	///
+	/// Commitment-only submissions (`DataAvailability::submit_data_hash`) use a prefixed leaf,
+	/// see [`SubmittedData::leaf`].
 	pub fn submitted_root(&self) -> H256 {
 		if self.data_submissions.is_empty() {
 			return H256::zero();
@@ -143,6 +235,34 @@ impl HeaderExtensionBuilderData {
 		Some(proof)
 	}
 
+	/// Creates the proofs of the data submissions and bridge messages of `tx_indices`, in the
+	/// given order. Each sub-trie is built at most once, so this is much cheaper than calling
+	/// [`Self::submitted_proof_of`] or [`Self::bridged_proof_of`] for every transaction.
+	/// Transactions which are neither a data submission nor a bridge message are skipped, and
+	/// only the first [`MAX_DATA_PROOFS`] transactions are proven.
+	pub fn proofs_of(&self, tx_indices: &[u32]) -> Vec<(u32, SubTrie, MerkleProof<H256, Vec<u8>>)> {
+		let leaf_indices = self.leaf_indices();
+		let mut submitted = None;
+		let mut bridged = None;
+
+		tx_indices
+			.iter()
+			.take(MAX_DATA_PROOFS)
+			.filter_map(|tx_idx| {
+				let (leaf_idx, sub_trie) = leaf_indices.get(tx_idx).cloned()?;
+				let tx_idx = *tx_idx;
+				let tree = match sub_trie {
+					SubTrie::DataSubmit => submitted
+						.get_or_insert_with(|| BalancedTree::new(self.balanced_submitted())),
+					SubTrie::Bridge => {
+						bridged.get_or_insert_with(|| BalancedTree::new(self.balanced_bridged()))
+					},
+				};
+				Some((tx_idx, sub_trie, tree.proof_of(leaf_idx)?))
+			})
+			.collect()
+	}
+
 	pub fn leaf_idx(&self, tx_idx: u32) -> Option<(usize, SubTrie)> {
 		if let Some(idx) = self
 			.data_submissions
@@ -160,6 +280,27 @@ impl HeaderExtensionBuilderData {
 		}
 		None
 	}
+
+	/// Leaf index and sub-trie of every data submission and bridge message, by transaction index,
+	/// with the same precedence as [`Self::leaf_idx`].
+	fn leaf_indices(&self) -> BTreeMap<u32, (usize, SubTrie)> {
+		let submitted = self
+			.data_submissions
+			.iter()
+			.enumerate()
+			.map(|(idx, s)| (s.tx_index, (idx, SubTrie::DataSubmit)));
+		let bridged = self
+			.bridge_messages
+			.iter()
+			.enumerate()
+			.map(|(idx, b)| (b.tx_index, (idx, SubTrie::Bridge)));
+
+		let mut leaf_indices = BTreeMap::new();
+		for (tx_idx, leaf) in submitted.chain(bridged) {
+			leaf_indices.entry(tx_idx).or_insert(leaf);
+		}
+		leaf_indices
+	}
 }
 
 impl HeaderExtensionBuilderData {
@@ -167,7 +308,7 @@ impl HeaderExtensionBuilderData {
 		let balanced_len = next_power_of_two(&self.data_submissions);
 		self.data_submissions
 			.iter()
-			.map(|s| H256(keccak_256(&s.data)))
+			.map(SubmittedData::leaf)
 			.chain(repeat(H256::zero()))
 			.take(balanced_len)
 	}
@@ -212,6 +353,68 @@ impl From<Vec<ExtractedTxData>> for HeaderExtensionBuilderData {
 	}
 }
 
+/// Balanced Keccak Merkle tree kept in memory, so many proofs can be derived from it without
+/// rebuilding it. Nodes are computed as `binary_merkle_tree` does for balanced trees.
+struct BalancedTree {
+	leaves: Vec<Vec<u8>>,
+	/// Hashed leaves first, and the root last.
+	layers: Vec<Vec<H256>>,
+}
+
+impl BalancedTree {
+	fn new<I, T>(leaf_iter: I) -> Self
+	where
+		I: Iterator<Item = T>,
+		T: AsRef<[u8]>,
+	{
+		let leaves = leaf_iter.map(|l| l.as_ref().to_vec()).collect::<Vec<_>>();
+		let mut layer = leaves
+			.iter()
+			.map(|l| H256(keccak_256(l)))
+			.collect::<Vec<_>>();
+
+		let mut layers = Vec::new();
+		while layer.len() > 1 {
+			let next = layer
+				.chunks(2)
+				.map(|pair| match pair {
+					[left, right] => {
+						H256(keccak_256(&[left.as_bytes(), right.as_bytes()].concat()))
+					},
+					_ => pair[0],
+				})
+				.collect();
+			layers.push(layer);
+			layer = next;
+		}
+		layers.push(layer);
+
+		Self { leaves, layers }
+	}
+
+	fn proof_of(&self, leaf_idx: usize) -> Option<MerkleProof<H256, Vec<u8>>> {
+		let leaf = self.leaves.get(leaf_idx)?.clone();
+		let root = *self.layers.last()?.first()?;
+
+		let mut idx = leaf_idx;
+		let mut proof = Vec::new();
+		for layer in self.layers.iter().take(self.layers.len().saturating_sub(1)) {
+			if let Some(sibling) = layer.get(idx ^ 1) {
+				proof.push(*sibling);
+			}
+			idx /= 2;
+		}
+
+		Some(MerkleProof {
+			root,
+			proof,
+			number_of_leaves: self.leaves.len(),
+			leaf_index: leaf_idx,
+			leaf,
+		})
+	}
+}
+
 #[inline]
 fn next_power_of_two<T>(s: &[T]) -> usize {
 	s.len().checked_next_power_of_two().unwrap_or(1)
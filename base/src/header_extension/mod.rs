@@ -1,7 +1,15 @@
 //pub mod tests;
+pub mod app_roots;
 pub mod builder_data;
 pub mod traits;
 
 // Reexport
-pub use builder_data::{BridgedData, ExtractedTxData, HeaderExtensionBuilderData, SubmittedData};
+pub use app_roots::{
+	AppDataProof, AppDataProofResponse, AppRoot, AppRootProof, DataRootVersion,
+	DATA_ROOT_VERSION_ENGINE_ID,
+};
+pub use builder_data::{
+	BridgedData, ExtractedTxData, HeaderExtensionBuilderData, SubmittedData, SubmittedDataKind,
+	DATA_HASH_LEAF_PREFIX, DATA_HASH_LEAF_TAG, FULL_LEAF_TAG, MAX_DATA_PROOFS,
+};
 pub use traits::HeaderExtensionDataFilter;
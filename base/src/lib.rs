@@ -1,11 +1,15 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(unused_extern_crates)]
 
+#[cfg(feature = "std")]
+pub mod header_builder;
 #[cfg(feature = "std")]
 pub mod metrics;
 
 pub mod mem_tmp_storage;
-pub use mem_tmp_storage::{MemoryTemporaryStorage, StorageMap};
+pub use mem_tmp_storage::{ContextId, MemoryTemporaryStorage, StorageMap};
+#[cfg(feature = "std")]
+pub use mem_tmp_storage::{MemTmpStorageExt, MemTmpStorageScope};
 
 mod post_inherents;
 pub use post_inherents::*;
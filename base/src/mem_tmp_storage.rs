@@ -1,15 +1,35 @@
 use codec::{Decode, Encode};
+use sp_core::H256;
 use sp_runtime_interface::runtime_interface;
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 /// A simple key-value storage in memory.
 pub type StorageMap = BTreeMap<Vec<u8>, Vec<u8>>;
 
+/// Identifies the block-building context which owns a memory temporal storage.
+///
+/// The default context is used by runtime calls executed without a [`MemTmpStorageExt`], like
+/// block import or RPC calls.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Encode, Decode)]
+pub struct ContextId {
+	/// Parent of the block being built.
+	pub parent_hash: H256,
+	/// Block builder instance, unique across the whole process.
+	pub instance: u64,
+}
+
+#[cfg(feature = "std")]
+sp_externalities::decl_extension! {
+	/// Selects the memory temporal storage context of the runtime calls it is registered on.
+	pub struct MemTmpStorageExt(ContextId);
+}
+
 //////
 ////// Runtime Code
 //////
 
-/// TODO docs
+/// Key-value storage in memory of the current block-building context, used to pass auxiliary
+/// data from extrinsics to post-inherents.
 pub struct MemoryTemporaryStorage;
 impl MemoryTemporaryStorage {
 	/// Returns the value under `key` from the memory temporal storage.
@@ -24,12 +44,12 @@ impl MemoryTemporaryStorage {
 			.and_then(|raw| T::decode(&mut raw.as_slice()).ok())
 	}
 
-	/// TODO docs
+	/// Removes the value under `key`, and returns whether it was present.
 	pub fn remove(key: &[u8]) -> bool {
 		hosted_mem_tmp_storage::take(key).is_some()
 	}
 
-	/// TODO docs
+	/// Removes and returns the value under `key`.
 	pub fn take<T: Decode>(key: &[u8]) -> Option<T> {
 		hosted_mem_tmp_storage::take(key).and_then(|raw| T::decode(&mut raw.as_slice()).ok())
 	}
@@ -63,67 +83,142 @@ impl MemoryTemporaryStorage {
 #[cfg(feature = "std")]
 pub(crate) mod native {
 	use super::*;
-	use sp_std::sync::RwLock;
+	use sp_externalities::{Externalities, ExternalitiesExt};
+	use std::sync::RwLock;
+
+	pub static MEM_TMP_STORAGE: RwLock<BTreeMap<ContextId, StorageMap>> =
+		RwLock::new(BTreeMap::new());
 
-	pub static MEM_TMP_STORAGE: RwLock<StorageMap> = RwLock::new(StorageMap::new());
+	/// Context selected by the [`MemTmpStorageExt`] of `ext`, or the default one.
+	pub fn context(ext: &mut dyn Externalities) -> ContextId {
+		ext.extension::<MemTmpStorageExt>()
+			.map(|e| e.0)
+			.unwrap_or_default()
+	}
+}
+
+/// Memory temporal storage of one block-building context. The context storage is removed when
+/// the scope is dropped.
+#[cfg(feature = "std")]
+pub struct MemTmpStorageScope {
+	id: ContextId,
 }
 
-/// The memory temporal storage will be cleared at the begining of each block building.
+#[cfg(feature = "std")]
+impl MemTmpStorageScope {
+	/// Opens a new context for a block built on top of `parent_hash`.
+	pub fn new(parent_hash: H256) -> Self {
+		use std::sync::atomic::{AtomicU64, Ordering};
+		static NEXT_INSTANCE: AtomicU64 = AtomicU64::new(1);
+
+		let instance = NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed);
+		Self {
+			id: ContextId {
+				parent_hash,
+				instance,
+			},
+		}
+	}
+
+	pub fn id(&self) -> ContextId {
+		self.id
+	}
+
+	/// Extension to register on every runtime API instance executing calls of this context.
+	pub fn extension(&self) -> MemTmpStorageExt {
+		MemTmpStorageExt(self.id)
+	}
+
+	/// Returns the content of the memory temporal storage of this context.
+	pub fn storage(&self) -> StorageMap {
+		let Ok(guard) = native::MEM_TMP_STORAGE.read() else {
+			log_poisoned_sync();
+			return StorageMap::default();
+		};
+
+		guard.get(&self.id).cloned().unwrap_or_default()
+	}
+}
+
+#[cfg(feature = "std")]
+impl Drop for MemTmpStorageScope {
+	fn drop(&mut self) {
+		let Ok(mut guard) = native::MEM_TMP_STORAGE.write() else {
+			log_poisoned_sync();
+			return;
+		};
+
+		guard.remove(&self.id);
+	}
+}
+
+/// The memory temporal storage is split in contexts, one per block-building instance (see
+/// [`MemTmpStorageScope`]), so concurrent builders on the same or on different forks do not
+/// share data. The context of each call is selected by the registered [`MemTmpStorageExt`].
 ///
-/// This is a simple global database not aware of forks. Can be used for storing auxiliary information like/// the failed `Vector::SendMessage` transaction indexers.
+/// Can be used for storing auxiliary information like the failed `Vector::SendMessage`
+/// transaction indexers.
 ///
 /// # TODO
 /// - [ ] Improve error handling of poisoned sync: Panic?
 #[runtime_interface]
 pub trait HostedMemTmpStorage {
 	/// Insert auxiliary data into key-value storage.
-	fn insert(key: Vec<u8>, value: Vec<u8>) -> Option<Vec<u8>> {
+	fn insert(&mut self, key: Vec<u8>, value: Vec<u8>) -> Option<Vec<u8>> {
+		let context = native::context(self);
 		let Ok(mut guard) = native::MEM_TMP_STORAGE.write() else {
 			log_poisoned_sync();
 			return None;
 		};
 
-		guard.insert(key, value)
+		guard.entry(context).or_default().insert(key, value)
 	}
 
 	/// Returns the value under `key` from the memory temporal storage.
-	fn get(key: &[u8]) -> Option<Vec<u8>> {
+	fn get(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+		let context = native::context(self);
 		let Ok(guard) = native::MEM_TMP_STORAGE.read() else {
 			log_poisoned_sync();
 			return None;
 		};
 
-		guard.get(key).cloned()
+		guard.get(&context).and_then(|s| s.get(key)).cloned()
 	}
 
-	fn take(key: &[u8]) -> Option<Vec<u8>> {
+	fn take(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+		let context = native::context(self);
 		let Ok(mut guard) = native::MEM_TMP_STORAGE.write() else {
 			log_poisoned_sync();
 			return None;
 		};
 
-		guard.remove(key)
+		guard.get_mut(&context)?.remove(key)
 	}
 
 	/// Clears the memory temporal storage.
-	fn clear() {
+	fn clear(&mut self) {
+		let context = native::context(self);
 		let Ok(mut guard) = native::MEM_TMP_STORAGE.write() else {
 			log_poisoned_sync();
 			return;
 		};
 
-		guard.clear();
+		guard.remove(&context);
 	}
 
 	/// Returns the content of the memory temporal storage as a list of key-value pairs.
 	/// NOTE: Conversion to plain list is needed due to `ByPass` constraints.
-	fn storage() -> Vec<(Vec<u8>, Vec<u8>)> {
+	fn storage(&mut self) -> Vec<(Vec<u8>, Vec<u8>)> {
+		let context = native::context(self);
 		let Ok(guard) = native::MEM_TMP_STORAGE.read() else {
 			log_poisoned_sync();
 			return Vec::default();
 		};
 
-		guard.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
+		guard
+			.get(&context)
+			.map(|s| s.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+			.unwrap_or_default()
 	}
 }
 
@@ -131,3 +226,86 @@ pub trait HostedMemTmpStorage {
 fn log_poisoned_sync() {
 	log::error!("Memory Temporal Storage with a poisoned sync");
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_io::TestExternalities;
+	use std::{sync::Barrier, thread};
+
+	const KEY: &[u8] = b"mem_tmp_storage::tests";
+
+	fn ext_of(scope: &MemTmpStorageScope) -> TestExternalities {
+		let mut ext = TestExternalities::default();
+		ext.register_extension(scope.extension());
+		ext
+	}
+
+	#[test]
+	fn contexts_are_isolated() {
+		let parent_hash = H256::repeat_byte(1);
+		let scope_a = MemTmpStorageScope::new(parent_hash);
+		let scope_b = MemTmpStorageScope::new(parent_hash);
+		assert_ne!(scope_a.id(), scope_b.id());
+
+		ext_of(&scope_a).execute_with(|| {
+			MemoryTemporaryStorage::insert(KEY.to_vec(), 1u32);
+		});
+		ext_of(&scope_b).execute_with(|| {
+			assert_eq!(MemoryTemporaryStorage::get::<u32>(KEY), None);
+			MemoryTemporaryStorage::insert(KEY.to_vec(), 2u32);
+			MemoryTemporaryStorage::clear();
+		});
+		TestExternalities::default().execute_with(|| {
+			assert_eq!(MemoryTemporaryStorage::get::<u32>(KEY), None);
+		});
+
+		assert_eq!(scope_a.storage().get(KEY), Some(&1u32.encode()));
+		assert!(scope_b.storage().is_empty());
+	}
+
+	#[test]
+	fn context_is_removed_with_its_scope() {
+		let scope = MemTmpStorageScope::new(H256::repeat_byte(2));
+		let id = scope.id();
+		ext_of(&scope).execute_with(|| {
+			MemoryTemporaryStorage::insert(KEY.to_vec(), 1u32);
+		});
+		assert!(native::MEM_TMP_STORAGE.read().unwrap().contains_key(&id));
+
+		drop(scope);
+		assert!(!native::MEM_TMP_STORAGE.read().unwrap().contains_key(&id));
+	}
+
+	#[test]
+	fn concurrent_builders_do_not_interfere() {
+		const BUILDERS: u32 = 8;
+		const TXS: u32 = 100;
+		let barrier = Barrier::new(BUILDERS as usize);
+
+		thread::scope(|s| {
+			for builder in 0..BUILDERS {
+				let barrier = &barrier;
+				s.spawn(move || {
+					// Half of the builders share the same parent.
+					let scope = MemTmpStorageScope::new(H256::repeat_byte((builder % 2) as u8));
+					let mut ext = ext_of(&scope);
+					barrier.wait();
+
+					for tx in 0..TXS {
+						ext.execute_with(|| {
+							MemoryTemporaryStorage::update::<Vec<u32>, _>(KEY.to_vec(), |txs| {
+								txs.push(builder * TXS + tx)
+							});
+						});
+					}
+
+					let expected = (0..TXS).map(|tx| builder * TXS + tx).collect::<Vec<_>>();
+					ext.execute_with(|| {
+						assert_eq!(MemoryTemporaryStorage::get::<Vec<u32>>(KEY), Some(expected));
+					});
+				});
+			}
+		});
+	}
+}
@@ -1,7 +1,9 @@
 use core::time::Duration;
 use std::time::Instant;
 
-use substrate_prometheus_endpoint::{Histogram, PrometheusError, Registry};
+use substrate_prometheus_endpoint::{
+	register, Counter, Gauge, Histogram, PrometheusError, Registry, U64,
+};
 
 use crate::metrics::LOG_TARGET;
 
@@ -40,6 +42,9 @@ pub struct HeaderExtensionBuilderMetrics {
 	pub commitment_build_time: Histogram,
 	pub grid_rows: Histogram,
 	pub grid_cols: Histogram,
+	pub estimated_execution_time: Histogram,
+	pub pool_threads: Gauge<U64>,
+	pub deadline_skipped_txs: Counter<U64>,
 }
 
 impl HeaderExtensionBuilderMetrics {
@@ -63,6 +68,12 @@ impl HeaderExtensionBuilderMetrics {
 			"Header Extension Builder - Total Execution Time in microseconds",
 			buckets.to_vec(),
 		)?;
+		let estimated_execution_time = custom_histogram(
+			registry,
+			"avail_header_extension_builder_estimated_execution_time",
+			"Header Extension Builder - Estimated Total Execution Time in microseconds",
+			buckets.to_vec(),
+		)?;
 
 		let buckets = [
 			150.0,
@@ -120,12 +131,30 @@ impl HeaderExtensionBuilderMetrics {
 			buckets.to_vec(),
 		)?;
 
+		let pool_threads = register(
+			Gauge::new(
+				"avail_header_extension_builder_pool_threads",
+				"Header Extension Builder - Threads of the commitment building pool",
+			)?,
+			registry,
+		)?;
+		let deadline_skipped_txs = register(
+			Counter::new(
+				"avail_header_extension_builder_deadline_skipped_txs",
+				"Header Extension Builder - Transactions left out of authored blocks to build the header extension in time",
+			)?,
+			registry,
+		)?;
+
 		Ok(Self {
 			total_execution_time,
 			evaluation_grid_build_time,
 			commitment_build_time,
 			grid_rows,
 			grid_cols,
+			estimated_execution_time,
+			pool_threads,
+			deadline_skipped_txs,
 		})
 	}
 
@@ -138,6 +167,28 @@ impl HeaderExtensionBuilderMetrics {
 		}
 	}
 
+	pub(crate) fn observe_estimated_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.header_extension
+				.estimated_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_pool_threads(threads: usize) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics.header_extension.pool_threads.set(threads as u64);
+		}
+	}
+
+	/// Counts a transaction left out of an authored block to build its header extension in time.
+	pub fn observe_deadline_skipped_tx() {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics.header_extension.deadline_skipped_txs.inc();
+		}
+	}
+
 	pub(crate) fn observe_evaluation_grid_build_time(duration: Duration) {
 		if let Some(metrics) = AVAIL_METRICS.get() {
 			metrics
@@ -174,6 +225,8 @@ pub struct KateRpcMetrics {
 	pub query_proof_execution_time: Histogram,
 	pub query_block_length_execution_time: Histogram,
 	pub query_data_proof_execution_time: Histogram,
+	pub query_app_data_proof_execution_time: Histogram,
+	pub query_data_proofs_execution_time: Histogram,
 }
 
 impl KateRpcMetrics {
@@ -234,11 +287,32 @@ impl KateRpcMetrics {
 			buckets.to_vec(),
 		)?;
 
+		let query_app_data_proof_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_app_data_proof_execution_time",
+			"Kate RPC - Query App Data Proof Time in microseconds",
+			buckets.to_vec(),
+		)?;
+
+		let buckets = [
+			250.0, 500.0, 1000.0, 2500.0, 5000.0, // 0.25ms, 0.5ms, 1ms, 2.5ms, 5ms
+			10000.0, 25000.0, 50000.0, 100_000.0, // 10ms, 25ms, 50ms, 100ms
+			250_000.0, // 250ms
+		];
+		let query_data_proofs_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_data_proofs_execution_time",
+			"Kate RPC - Query Data Proofs Time in microseconds",
+			buckets.to_vec(),
+		)?;
+
 		Ok(Self {
 			query_rows_execution_time,
 			query_proof_execution_time,
 			query_block_length_execution_time,
 			query_data_proof_execution_time,
+			query_app_data_proof_execution_time,
+			query_data_proofs_execution_time,
 		})
 	}
 
@@ -277,10 +351,33 @@ impl KateRpcMetrics {
 				.observe(duration.as_micros() as f64);
 		}
 	}
+
+	pub(crate) fn observe_query_data_proofs_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_data_proofs_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_query_app_data_proof_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_app_data_proof_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
 }
 
 pub struct ImportBlockMetrics {
 	pub total_execution_time: Histogram,
+	pub header_extension_check_time: Histogram,
+	pub header_extension_wait_time: Histogram,
+	pub header_extension_saved_time: Histogram,
+	pub sync_checked_blocks: Counter<U64>,
+	pub sync_skipped_blocks: Counter<U64>,
 }
 impl ImportBlockMetrics {
 	pub fn new(registry: &Registry) -> Result<Self, PrometheusError> {
@@ -306,9 +403,47 @@ impl ImportBlockMetrics {
 			"Import Block - Total Execution Time in microseconds",
 			buckets.to_vec(),
 		)?;
+		let header_extension_check_time = custom_histogram(
+			registry,
+			"avail_import_block_header_extension_check_time",
+			"Import Block - Header Extension Check Time in microseconds",
+			buckets.to_vec(),
+		)?;
+		let header_extension_wait_time = custom_histogram(
+			registry,
+			"avail_import_block_header_extension_wait_time",
+			"Import Block - Time waiting for the Header Extension Check after the block execution in microseconds",
+			buckets.to_vec(),
+		)?;
+		let header_extension_saved_time = custom_histogram(
+			registry,
+			"avail_import_block_header_extension_saved_time",
+			"Import Block - Time saved by checking the Header Extension in parallel with the block execution in microseconds",
+			buckets.to_vec(),
+		)?;
+
+		let sync_checked_blocks = register(
+			Counter::new(
+				"avail_import_block_sync_checked_blocks",
+				"Import Block - Blocks fully checked during the initial sync",
+			)?,
+			registry,
+		)?;
+		let sync_skipped_blocks = register(
+			Counter::new(
+				"avail_import_block_sync_skipped_blocks",
+				"Import Block - Blocks not checked during the initial sync",
+			)?,
+			registry,
+		)?;
 
 		Ok(Self {
 			total_execution_time,
+			header_extension_check_time,
+			header_extension_wait_time,
+			header_extension_saved_time,
+			sync_checked_blocks,
+			sync_skipped_blocks,
 		})
 	}
 
@@ -320,11 +455,56 @@ impl ImportBlockMetrics {
 				.observe(duration.as_micros() as f64);
 		}
 	}
+
+	pub(crate) fn observe_header_extension_check_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.import_block
+				.header_extension_check_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	pub(crate) fn observe_header_extension_wait_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.import_block
+				.header_extension_wait_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
+	/// Counts a block imported during the initial sync, which was fully checked if `checked`.
+	pub fn observe_sync_block(checked: bool) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			let counter = if checked {
+				&metrics.import_block.sync_checked_blocks
+			} else {
+				&metrics.import_block.sync_skipped_blocks
+			};
+			counter.inc();
+		}
+	}
+
+	/// Observes the time saved by a header extension check of `check` running in parallel with
+	/// the block execution, once the import waited `wait` for it.
+	pub fn observe_header_extension_saved_time(check: Duration, wait: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.import_block
+				.header_extension_saved_time
+				.observe(check.saturating_sub(wait).as_micros() as f64);
+		}
+	}
 }
 
 pub enum ObserveKind {
 	ImportBlockTotalExecutionTime,
+	ImportBlockHeaderExtensionCheck,
+	ImportBlockHeaderExtensionWait,
 	KateQueryDataProof,
+	KateQueryDataProofs,
+	KateQueryAppDataProof,
 	KateQueryBlockLength,
 	KateQueryProof,
 	KateQueryRows,
@@ -354,9 +534,21 @@ impl Drop for MetricObserver {
 			ObserveKind::ImportBlockTotalExecutionTime => {
 				ImportBlockMetrics::observe_total_execution_time(duration)
 			},
+			ObserveKind::ImportBlockHeaderExtensionCheck => {
+				ImportBlockMetrics::observe_header_extension_check_time(duration)
+			},
+			ObserveKind::ImportBlockHeaderExtensionWait => {
+				ImportBlockMetrics::observe_header_extension_wait_time(duration)
+			},
 			ObserveKind::KateQueryDataProof => {
 				KateRpcMetrics::observe_query_data_proof_execution_time(duration)
 			},
+			ObserveKind::KateQueryDataProofs => {
+				KateRpcMetrics::observe_query_data_proofs_execution_time(duration)
+			},
+			ObserveKind::KateQueryAppDataProof => {
+				KateRpcMetrics::observe_query_app_data_proof_execution_time(duration)
+			},
 			ObserveKind::KateQueryBlockLength => {
 				KateRpcMetrics::observe_query_block_length_execution_time(duration)
 			},
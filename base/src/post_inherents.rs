@@ -1,8 +1,13 @@
-use crate::{MemoryTemporaryStorage, StorageMap};
+use crate::StorageMap;
 
+use codec::{Decode, Encode};
+use frame_support::traits::IsSubType;
+use scale_info::TypeInfo;
 use sp_api::decl_runtime_apis;
 use sp_runtime::traits::Block as BlockT;
 use sp_std::vec::Vec;
+#[cfg(feature = "std")]
+use {crate::MemTmpStorageScope, sp_core::H256};
 
 /// A pallet that provides or verifies an inherent extrinsic will implement this trait.
 ///
@@ -55,8 +60,140 @@ pub trait ProvidePostInherent {
 	/// is an inherent call, when implementing `ValidateUnsigned::validate_unsigned`.
 	/// Otherwise block producers can produce invalid blocks by including them after non inherents.
 	fn is_inherent(call: &Self::Call) -> bool;
+
+	/// Return whether every block must end with the post-inherent of this pallet.
+	fn is_required() -> bool {
+		false
+	}
+}
+
+/// Post-inherents of a set of pallets, implemented for tuples of [`ProvidePostInherent`].
+///
+/// Post-inherents are appended at the end of the block in the order of the tuple, and each pallet
+/// contributes at most one of them.
+pub trait ProvidePostInherents<Call> {
+	/// Creates the post-inherent calls of all pallets, in order.
+	fn create_post_inherents(data: &StorageMap) -> Vec<Call>;
+
+	/// Returns the position in the tuple of the pallet providing `call`, if it is a post-inherent.
+	fn provider_of(call: &Call) -> Option<u32>;
+
+	/// Returns the positions in the tuple of the pallets whose post-inherent is required.
+	fn required_providers() -> Vec<u32>;
+}
+
+/// Why the post-inherents of a block are invalid.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum PostInherentsError {
+	/// The required post-inherent of the pallet at `provider` is missing.
+	Missing { provider: u32 },
+	/// The post-inherent at `tx_index` is followed by other extrinsics.
+	Misplaced { tx_index: u32 },
+	/// The post-inherent at `tx_index` is duplicated or out of order.
+	Unordered { tx_index: u32 },
 }
 
+/// Checks that the post-inherents of `P` are the last extrinsics of a block, whose calls are
+/// `calls` (`None` for extrinsics which are not post-inherent candidates, like signed ones), that
+/// they follow the order of `P` and that all the required ones are present.
+pub fn check_post_inherents<Call, P: ProvidePostInherents<Call>>(
+	calls: &[Option<&Call>],
+) -> Result<(), PostInherentsError> {
+	let providers = calls
+		.iter()
+		.map(|call| call.and_then(P::provider_of))
+		.collect::<Vec<_>>();
+
+	// Post-inherents form the tail of the block.
+	let tail_start = providers
+		.iter()
+		.rposition(Option::is_none)
+		.map_or(0, |idx| idx + 1);
+	if let Some(tx_index) = providers[..tail_start].iter().position(Option::is_some) {
+		return Err(PostInherentsError::Misplaced {
+			tx_index: tx_index as u32,
+		});
+	}
+
+	let tail = providers[tail_start..].iter().flatten().copied();
+	let mut last = None;
+	for (tx_index, provider) in (tail_start..).zip(tail.clone()) {
+		if last.map_or(false, |last| provider <= last) {
+			return Err(PostInherentsError::Unordered {
+				tx_index: tx_index as u32,
+			});
+		}
+		last = Some(provider);
+	}
+
+	let present = tail.collect::<Vec<_>>();
+	match P::required_providers()
+		.into_iter()
+		.find(|provider| !present.contains(provider))
+	{
+		Some(provider) => Err(PostInherentsError::Missing { provider }),
+		None => Ok(()),
+	}
+}
+
+macro_rules! impl_provide_post_inherents {
+	($($provider:ident),+) => {
+		impl<Call, $($provider),+> ProvidePostInherents<Call> for ($($provider,)+)
+		where
+			$(
+				$provider: ProvidePostInherent,
+				$provider::Call: Into<Call>,
+				Call: IsSubType<$provider::Call>,
+			)+
+		{
+			fn create_post_inherents(data: &StorageMap) -> Vec<Call> {
+				let mut calls = Vec::new();
+				$(
+					if let Some(call) = $provider::create_inherent(data) {
+						calls.push(call.into());
+					}
+				)+
+				calls
+			}
+
+			#[allow(unused_assignments)]
+			fn provider_of(call: &Call) -> Option<u32> {
+				let mut position = 0u32;
+				$(
+					let sub_call = <Call as IsSubType<$provider::Call>>::is_sub_type(call);
+					if sub_call.map_or(false, $provider::is_inherent) {
+						return Some(position);
+					}
+					position += 1;
+				)+
+				None
+			}
+
+			#[allow(unused_assignments)]
+			fn required_providers() -> Vec<u32> {
+				let mut required = Vec::new();
+				let mut position = 0u32;
+				$(
+					if $provider::is_required() {
+						required.push(position);
+					}
+					position += 1;
+				)+
+				required
+			}
+		}
+	};
+}
+
+impl_provide_post_inherents!(A);
+impl_provide_post_inherents!(A, B);
+impl_provide_post_inherents!(A, B, C);
+impl_provide_post_inherents!(A, B, C, D);
+impl_provide_post_inherents!(A, B, C, D, E);
+impl_provide_post_inherents!(A, B, C, D, E, F);
+impl_provide_post_inherents!(A, B, C, D, E, F, G);
+impl_provide_post_inherents!(A, B, C, D, E, F, G, H);
+
 decl_runtime_apis! {
 	#[core_trait]
 	#[allow(unused_imports)]
@@ -65,17 +202,115 @@ decl_runtime_apis! {
 	}
 }
 
+/// Client API for post-inherents.
+#[cfg(feature = "std")]
 pub trait PostInherentsBackend {
-	fn init_post_inherent_data(&self);
-	fn post_inherent_data(&self) -> StorageMap;
+	/// Opens the post-inherent data context of a new block built on top of `parent_hash`. Its
+	/// data is removed once the returned scope is dropped.
+	fn post_inherent_scope<H: AsRef<[u8]>>(&self, parent_hash: H) -> MemTmpStorageScope;
 }
 
-/// Client API for post-inherents.
+#[cfg(feature = "std")]
 impl<T> PostInherentsBackend for T {
-	fn init_post_inherent_data(&self) {
-		MemoryTemporaryStorage::clear();
+	fn post_inherent_scope<H: AsRef<[u8]>>(&self, parent_hash: H) -> MemTmpStorageScope {
+		// Contexts are told apart by their instance, the parent hash is only informative.
+		let parent_hash = H256::decode(&mut parent_hash.as_ref()).unwrap_or_default();
+		MemTmpStorageScope::new(parent_hash)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Clone, Copy, Debug, PartialEq)]
+	enum TestCall {
+		Post(u8),
+		Other,
+	}
+
+	impl IsSubType<u8> for TestCall {
+		fn is_sub_type(&self) -> Option<&u8> {
+			match self {
+				TestCall::Post(id) => Some(id),
+				TestCall::Other => None,
+			}
+		}
+	}
+
+	impl From<u8> for TestCall {
+		fn from(id: u8) -> Self {
+			TestCall::Post(id)
+		}
 	}
-	fn post_inherent_data(&self) -> StorageMap {
-		MemoryTemporaryStorage::storage()
+
+	/// Post-inherent provider whose call is `ID`.
+	struct Provider<const ID: u8, const REQUIRED: bool>;
+
+	impl<const ID: u8, const REQUIRED: bool> ProvidePostInherent for Provider<ID, REQUIRED> {
+		type Call = u8;
+		type Error = ();
+
+		fn create_inherent(_: &StorageMap) -> Option<Self::Call> {
+			REQUIRED.then_some(ID)
+		}
+
+		fn is_inherent(call: &Self::Call) -> bool {
+			*call == ID
+		}
+
+		fn is_required() -> bool {
+			REQUIRED
+		}
+	}
+
+	type Providers = (Provider<1, true>, Provider<2, false>, Provider<3, true>);
+
+	fn check(calls: &[TestCall]) -> Result<(), PostInherentsError> {
+		let calls = calls.iter().map(Some).collect::<Vec<_>>();
+		check_post_inherents::<TestCall, Providers>(&calls)
+	}
+
+	#[test]
+	fn post_inherents_are_created_in_order() {
+		let calls = Providers::create_post_inherents(&StorageMap::new());
+		assert_eq!(calls, vec![TestCall::Post(1), TestCall::Post(3)]);
+		assert_eq!(Providers::required_providers(), vec![0, 2]);
+		assert_eq!(Providers::provider_of(&TestCall::Post(2)), Some(1));
+		assert_eq!(Providers::provider_of(&TestCall::Post(4)), None);
+		assert_eq!(Providers::provider_of(&TestCall::Other), None);
+	}
+
+	#[test]
+	fn valid_post_inherents() {
+		use TestCall::*;
+		assert_eq!(check(&[Other, Post(1), Post(3)]), Ok(()));
+		assert_eq!(check(&[Other, Post(1), Post(2), Post(3)]), Ok(()));
+		assert_eq!(check(&[Post(1), Post(3)]), Ok(()));
+	}
+
+	#[test]
+	fn invalid_post_inherents() {
+		use TestCall::*;
+		assert_eq!(
+			check(&[Other, Post(1)]),
+			Err(PostInherentsError::Missing { provider: 2 })
+		);
+		assert_eq!(
+			check(&[Other]),
+			Err(PostInherentsError::Missing { provider: 0 })
+		);
+		assert_eq!(
+			check(&[Post(1), Other, Post(3)]),
+			Err(PostInherentsError::Misplaced { tx_index: 0 })
+		);
+		assert_eq!(
+			check(&[Other, Post(3), Post(1)]),
+			Err(PostInherentsError::Unordered { tx_index: 2 })
+		);
+		assert_eq!(
+			check(&[Other, Post(1), Post(1), Post(3)]),
+			Err(PostInherentsError::Unordered { tx_index: 2 })
+		);
 	}
 }
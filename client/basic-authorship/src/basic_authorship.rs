@@ -19,7 +19,11 @@
 //! A consensus proposer for "basic" chains which use the primitive inherent-data.
 
 // FIXME #1021 move this into sp-consensus
-use avail_base::{PostInherentsBackend, PostInherentsProvider};
+use avail_base::{
+	metrics::avail::HeaderExtensionBuilderMetrics, HeaderExtensionDataFilter, MemTmpStorageScope,
+	PostInherentsBackend, PostInherentsProvider,
+};
+use avail_core::OpaqueExtrinsic;
 
 use codec::Encode;
 use futures::{
@@ -36,6 +40,7 @@ use sp_api::{ApiExt, CallApiAt, ProvideRuntimeApi};
 use sp_blockchain::{ApplyExtrinsicFailed::Validity, Error::ApplyExtrinsicFailed, HeaderBackend};
 use sp_consensus::{DisableProofRecording, EnableProofRecording, ProofRecording, Proposal};
 use sp_core::traits::SpawnNamed;
+use sp_externalities::Extensions;
 use sp_inherents::InherentData;
 use sp_runtime::{
 	traits::{BlakeTwo256, Block as BlockT, Hash as HashT, Header as HeaderT},
@@ -57,8 +62,24 @@ pub const DEFAULT_BLOCK_SIZE_LIMIT: usize = 4 * 1024 * 1024 + 512;
 
 const DEFAULT_SOFT_DEADLINE_PERCENT: Percent = Percent::from_percent(50);
 
+/// Default header builder deadline percentage of hard deadline.
+///
+/// Can be overwritten by [`ProposerFactory::set_header_builder_deadline`].
+pub const DEFAULT_HEADER_BUILDER_DEADLINE_PERCENT: Percent = Percent::from_percent(90);
+
 const LOG_TARGET: &'static str = "basic-authorship";
 
+/// Length of the DA data of `extrinsic`, which the header builder commits to.
+fn da_data_len<E: Encode>(extrinsic: &E) -> usize {
+	let Ok(opaque) = OpaqueExtrinsic::from_bytes(&extrinsic.encode()) else {
+		return 0;
+	};
+
+	<da_runtime::Runtime as HeaderExtensionDataFilter>::filter(&[], opaque, 0, 0)
+		.and_then(|data| data.app_extrinsic)
+		.map_or(0, |app_extrinsic| app_extrinsic.data.len())
+}
+
 /// [`Proposer`] factory.
 pub struct ProposerFactory<A, C, PR> {
 	spawn_handle: Box<dyn SpawnNamed>,
@@ -81,6 +102,11 @@ pub struct ProposerFactory<A, C, PR> {
 	/// we switch to a fixed-amount mode, in which after we see `MAX_SKIPPED_TRANSACTIONS`
 	/// transactions which exhaust resources, we will conclude that the block is full.
 	soft_deadline_percent: Percent,
+	/// Header builder deadline percentage of hard deadline.
+	///
+	/// The header extension of the block is built once all transactions are applied, so we stop
+	/// adding transactions whose DA data would not be committed before this deadline.
+	header_builder_deadline_percent: Percent,
 	telemetry: Option<TelemetryHandle>,
 	/// When estimating the block size, should the proof be included?
 	include_proof_in_block_size_estimation: bool,
@@ -106,6 +132,7 @@ impl<A, C> ProposerFactory<A, C, DisableProofRecording> {
 			metrics: PrometheusMetrics::new(prometheus),
 			default_block_size_limit: DEFAULT_BLOCK_SIZE_LIMIT,
 			soft_deadline_percent: DEFAULT_SOFT_DEADLINE_PERCENT,
+			header_builder_deadline_percent: DEFAULT_HEADER_BUILDER_DEADLINE_PERCENT,
 			telemetry,
 			client,
 			include_proof_in_block_size_estimation: false,
@@ -135,6 +162,7 @@ impl<A, C> ProposerFactory<A, C, EnableProofRecording> {
 			metrics: PrometheusMetrics::new(prometheus),
 			default_block_size_limit: DEFAULT_BLOCK_SIZE_LIMIT,
 			soft_deadline_percent: DEFAULT_SOFT_DEADLINE_PERCENT,
+			header_builder_deadline_percent: DEFAULT_HEADER_BUILDER_DEADLINE_PERCENT,
 			telemetry,
 			include_proof_in_block_size_estimation: true,
 			_phantom: PhantomData,
@@ -174,6 +202,19 @@ impl<A, C, PR> ProposerFactory<A, C, PR> {
 	pub fn set_soft_deadline(&mut self, percent: Percent) {
 		self.soft_deadline_percent = percent;
 	}
+
+	/// Set header builder deadline percentage.
+	///
+	/// The default value for the header builder deadline is:
+	/// [`DEFAULT_HEADER_BUILDER_DEADLINE_PERCENT`].
+	///
+	/// The header builder deadline indicates when the header extension of the block, built after
+	/// all transactions are applied, must be done. Transactions whose data would make the
+	/// estimated build time overrun it are skipped, up to `MAX_SKIPPED_TRANSACTIONS` of them
+	/// before we conclude that the block is full.
+	pub fn set_header_builder_deadline(&mut self, percent: Percent) {
+		self.header_builder_deadline_percent = percent;
+	}
 }
 
 impl<Block, C, A, PR> ProposerFactory<A, C, PR>
@@ -210,6 +251,7 @@ where
 			metrics: self.metrics.clone(),
 			default_block_size_limit: self.default_block_size_limit,
 			soft_deadline_percent: self.soft_deadline_percent,
+			header_builder_deadline_percent: self.header_builder_deadline_percent,
 			telemetry: self.telemetry.clone(),
 			_phantom: PhantomData,
 			include_proof_in_block_size_estimation: self.include_proof_in_block_size_estimation,
@@ -256,6 +298,7 @@ pub struct Proposer<Block: BlockT, C, A: TransactionPool, PR> {
 	default_block_size_limit: usize,
 	include_proof_in_block_size_estimation: bool,
 	soft_deadline_percent: Percent,
+	header_builder_deadline_percent: Percent,
 	telemetry: Option<TelemetryHandle>,
 	_phantom: PhantomData<PR>,
 }
@@ -339,14 +382,20 @@ where
 		block_size_limit: Option<usize>,
 	) -> Result<Proposal<Block, PR::Proof>, sp_blockchain::Error> {
 		let block_timer = time::Instant::now();
+		// Post-inherent data of this proposal, isolated from other builders and removed once
+		// the proposal is done.
+		let post_inherents_scope = self.client.post_inherent_scope(self.parent_hash);
+		let mut extensions = Extensions::new();
+		extensions.register(post_inherents_scope.extension());
+
 		let mut block_builder = BlockBuilderBuilder::new(&*self.client)
 			.on_parent_block(self.parent_hash)
 			.with_parent_block_number(self.parent_number)
 			.with_proof_recording(PR::ENABLED)
 			.with_inherent_digests(inherent_digests)
+			.with_extra_extensions(extensions)
 			.build()?;
 
-		self.client.init_post_inherent_data();
 		self.apply_inherents(&mut block_builder, inherent_data)?;
 
 		// TODO call `after_inherents` and check if we should apply extrinsincs here
@@ -356,7 +405,7 @@ where
 			.apply_extrinsics(&mut block_builder, deadline, block_size_limit)
 			.await?;
 
-		self.apply_post_inherents(&mut block_builder)?;
+		self.apply_post_inherents(&mut block_builder, &post_inherents_scope)?;
 
 		let (block, storage_changes, proof) = block_builder.build()?.into_inner();
 		let block_took = block_timer.elapsed();
@@ -419,11 +468,12 @@ where
 	fn apply_post_inherents(
 		&self,
 		block_builder: &mut sc_block_builder::BlockBuilder<'_, Block, C>,
+		scope: &MemTmpStorageScope,
 	) -> Result<(), sp_blockchain::Error> {
-		let data = self.client.post_inherent_data();
-		let post_inherents: Vec<_> = self
-			.client
-			.runtime_api()
+		let data = scope.storage();
+		let mut api = self.client.runtime_api();
+		api.register_extension(scope.extension());
+		let post_inherents: Vec<_> = api
 			.create_post_inherent_extrinsics(self.parent_hash, data)
 			.map_err(|api_err| sp_blockchain::Error::RuntimeApiError(api_err))?;
 
@@ -468,7 +518,13 @@ where
 		let left_micros: u64 = left.as_micros().saturated_into();
 		let soft_deadline =
 			now + time::Duration::from_micros(self.soft_deadline_percent.mul_floor(left_micros));
+		let header_builder_deadline = now
+			+ time::Duration::from_micros(
+				self.header_builder_deadline_percent.mul_floor(left_micros),
+			);
 		let mut skipped = 0;
+		let mut header_builder_skipped = 0;
+		let mut da_data_len_pushed = 0;
 		let mut unqueue_invalid = Vec::new();
 
 		let mut t1 = self.transaction_pool.ready_at(self.parent_number).fuse();
@@ -548,10 +604,42 @@ where
 				}
 			}
 
+			// Only the DA data of the block is committed by the header builder.
+			let pending_da_data_len = da_data_len(&pending_tx_data);
+			let header_builder_end = if pending_da_data_len > 0 {
+				avail_base::header_builder::estimate_build_time(
+					da_data_len_pushed + pending_da_data_len,
+				)
+				.map(|build_time| now + build_time)
+			} else {
+				None
+			};
+			if header_builder_end.is_some_and(|end| end > header_builder_deadline) {
+				pending_iterator.report_invalid(&pending_tx);
+				HeaderExtensionBuilderMetrics::observe_deadline_skipped_tx();
+				if header_builder_skipped < MAX_SKIPPED_TRANSACTIONS {
+					header_builder_skipped += 1;
+					debug!(
+						target: LOG_TARGET,
+						"Header extension would overrun the header builder deadline, \
+					 but will try {} more transactions before quitting.",
+						MAX_SKIPPED_TRANSACTIONS - header_builder_skipped,
+					);
+					continue;
+				} else {
+					debug!(
+						target: LOG_TARGET,
+						"Reached header builder deadline, proceeding with proposing."
+					);
+					break EndProposingReason::HitDeadline;
+				}
+			}
+
 			trace!(target: LOG_TARGET, "[{:?}] Pushing to the block.", pending_tx_hash);
 			match sc_block_builder::BlockBuilder::push(block_builder, pending_tx_data) {
 				Ok(()) => {
 					transaction_pushed = true;
+					da_data_len_pushed += pending_da_data_len;
 					debug!(target: LOG_TARGET, "[{:?}] Pushed to the block.", pending_tx_hash);
 				},
 				Err(ApplyExtrinsicFailed(Validity(e))) if e.exhausted_resources() => {
@@ -16,6 +16,15 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{
+	da_block_import::{DaImportConfig, DaSyncMode},
+	service::HeaderBuilderConfig,
+};
+use avail_base::header_builder::{PoolConfig, ThreadPriority};
+use sc_service::Configuration;
+use sp_runtime::Percent;
+use std::num::NonZeroUsize;
+
 /// An overarching CLI command definition.
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
@@ -41,6 +50,34 @@ pub struct Cli {
 	#[arg(long, conflicts_with_all = &["validator"])]
 	pub unsafe_da_sync: bool,
 
+	/// Fully check only a sample of the blocks imported during sync: `full`, `every:<N>` (one
+	/// block in N) or `random:<PERCENT>`. Finality checkpoints are always checked.
+	#[arg(long, conflicts_with_all = &["validator", "unsafe_da_sync"])]
+	pub da_sync_sampling: Option<DaSyncMode>,
+
+	/// Dump the blocks whose DA header extension does not match into the `da_mismatches`
+	/// directory of the base path, along with both extensions and their differences.
+	#[arg(long)]
+	pub dump_da_mismatches: bool,
+
+	/// Number of threads building the DA commitments of the header extension.
+	///
+	/// Defaults to one thread per CPU core.
+	#[arg(long)]
+	pub header_builder_threads: Option<NonZeroUsize>,
+
+	/// Scheduling priority of the header builder threads: `normal` or `low`.
+	///
+	/// `low` is only supported on Linux.
+	#[arg(long, default_value = "normal")]
+	pub header_builder_priority: ThreadPriority,
+
+	/// Percentage of the block authoring time by which the header extension must be built.
+	///
+	/// Transactions whose data would make the header builder overrun it are left out of the block.
+	#[arg(long, default_value_t = 90, value_parser = header_builder_deadline_range)]
+	pub header_builder_deadline: u8,
+
 	/// Provides storage monitoring options on the node
 	#[clap(flatten)]
 	pub storage_monitor: sc_storage_monitor::StorageMonitorParams,
@@ -90,10 +127,46 @@ pub struct Cli {
 	pub tx_state_logging_interval: u64,
 }
 
+impl Cli {
+	/// DA checks configuration of the block import.
+	pub fn da_import_config(&self, config: &Configuration) -> DaImportConfig {
+		DaImportConfig {
+			sync: self.da_sync_mode(),
+			mismatch_dump_dir: self
+				.dump_da_mismatches
+				.then(|| config.base_path.path().join("da_mismatches")),
+		}
+	}
+
+	/// Thread pool and deadline of the header builder.
+	pub fn header_builder_config(&self) -> HeaderBuilderConfig {
+		HeaderBuilderConfig {
+			pool: PoolConfig {
+				threads: self.header_builder_threads,
+				priority: self.header_builder_priority,
+			},
+			deadline: Percent::from_percent(self.header_builder_deadline),
+		}
+	}
+
+	/// DA checks applied to the blocks imported during sync.
+	pub fn da_sync_mode(&self) -> DaSyncMode {
+		if self.unsafe_da_sync {
+			DaSyncMode::Unsafe
+		} else {
+			self.da_sync_sampling.unwrap_or_default()
+		}
+	}
+}
+
 fn kate_max_cells_size_upper_bound(s: &str) -> Result<usize, String> {
 	clap_num::number_range(s, 0, 10_000)
 }
 
+fn header_builder_deadline_range(s: &str) -> Result<u8, String> {
+	clap_num::number_range(s, 1, 100)
+}
+
 /// Possible subcommands of the main binary.
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, clap::Subcommand)]
@@ -125,7 +125,7 @@ pub fn run() -> Result<()> {
 						unimplemented!();
 						/*
 						// ensure that we keep the task manager alive
-						let partial = new_partial(&config, cli.unsafe_da_sync)?;
+						let partial = new_partial(&config, cli.da_import_config(&config))?;
 						cmd.run(partial.client)
 						*/
 					},
@@ -139,7 +139,7 @@ pub fn run() -> Result<()> {
 						unimplemented!();
 						/*
 						// ensure that we keep the task manager alive
-						let partial = new_partial(&config, cli.unsafe_da_sync)?;
+						let partial = new_partial(&config, cli.da_import_config(&config))?;
 						let db = partial.backend.expose_db();
 						let storage = partial.backend.expose_storage();
 
@@ -150,7 +150,7 @@ pub fn run() -> Result<()> {
 						unimplemented!();
 						/*
 						// ensure that we keep the task manager alive
-						let partial = new_partial(&config, cli.unsafe_da_sync)?;
+						let partial = new_partial(&config, cli.da_import_config(&config))?;
 						let ext_builder = RemarkBuilder::new(partial.client.clone());
 
 						cmd.run(
@@ -166,7 +166,7 @@ pub fn run() -> Result<()> {
 						unimplemented!();
 						/*
 						// ensure that we keep the task manager alive
-						let partial = service::new_partial(&config, cli.unsafe_da_sync)?;
+						let partial = service::new_partial(&config, cli.da_import_config(&config))?;
 						// Register the *Remark* and *TKA* builders.
 						let ext_factory = ExtrinsicFactory(vec![
 							Box::new(RemarkBuilder::new(partial.client.clone())),
@@ -208,7 +208,7 @@ pub fn run() -> Result<()> {
 					..
 				} = new_partial(
 					&config,
-					cli.unsafe_da_sync,
+					cli.da_import_config(&config),
 					kate_rpc::Deps::default(),
 					transaction_state::CliDeps::default(),
 				)?;
@@ -224,7 +224,7 @@ pub fn run() -> Result<()> {
 					..
 				} = new_partial(
 					&config,
-					cli.unsafe_da_sync,
+					cli.da_import_config(&config),
 					kate_rpc::Deps::default(),
 					transaction_state::CliDeps::default(),
 				)?;
@@ -240,7 +240,7 @@ pub fn run() -> Result<()> {
 					..
 				} = new_partial(
 					&config,
-					cli.unsafe_da_sync,
+					cli.da_import_config(&config),
 					kate_rpc::Deps::default(),
 					transaction_state::CliDeps::default(),
 				)?;
@@ -257,7 +257,7 @@ pub fn run() -> Result<()> {
 					..
 				} = new_partial(
 					&config,
-					cli.unsafe_da_sync,
+					cli.da_import_config(&config),
 					kate_rpc::Deps::default(),
 					transaction_state::CliDeps::default(),
 				)?;
@@ -278,7 +278,7 @@ pub fn run() -> Result<()> {
 					..
 				} = new_partial(
 					&config,
-					cli.unsafe_da_sync,
+					cli.da_import_config(&config),
 					kate_rpc::Deps::default(),
 					transaction_state::CliDeps::default(),
 				)?;
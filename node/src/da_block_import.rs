@@ -4,33 +4,156 @@
 /// to Babe and Grandpa.
 /// It double-checks the **extension header** which contains the `Kate Commitment` and `Data
 /// Root`.
-use avail_base::metrics::avail::{MetricObserver, ObserveKind};
+use avail_base::{
+	metrics::avail::{ImportBlockMetrics, MetricObserver, ObserveKind},
+	MemTmpStorageScope, PostInherentsError, PostInherentsProvider,
+};
 use avail_core::{
 	ensure, header::HeaderExtension, BlockLengthColumns, BlockLengthRows, OpaqueExtrinsic,
 	BLOCK_CHUNK_SIZE,
 };
+use codec::Encode;
 use da_runtime::{
 	apis::{DataAvailApi, ExtensionBuilder},
 	Header as DaHeader,
 };
 use frame_system::limits::BlockLength;
+use futures::channel::oneshot;
+use rand::Rng as _;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::header_extension_diff::HeaderExtensionDiff;
 
 use sc_consensus::{
 	block_import::{BlockCheckParams, BlockImport as BlockImportT, BlockImportParams},
-	ImportResult,
+	ImportResult, StateAction, StorageChanges,
 };
-use sp_api::{ApiError, ProvideRuntimeApi};
+use sc_telemetry::log;
+use sp_api::{ApiError, ApiExt, CallApiAt, CallContext, Core, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_consensus::{BlockOrigin, Error as ConsensusError};
 use sp_core::H256;
-use sp_runtime::traits::Block as BlockT;
-use std::{marker::PhantomData, sync::Arc};
+use sp_runtime::{traits::Block as BlockT, Percent};
+use std::{
+	fs,
+	marker::PhantomData,
+	num::NonZeroU32,
+	path::{Path, PathBuf},
+	str::FromStr,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::{Duration, Instant},
+};
+
+/// Number of blocks imported during the initial sync between two coverage logs.
+const SYNC_COVERAGE_LOG_PERIOD: u64 = 1_000;
+
+/// DA checks applied to the blocks imported during the initial sync.
+///
+/// Finality checkpoints, i.e. blocks imported with a justification, are fully checked unless the
+/// mode is `Unsafe`. Blocks past the last finality checkpoint are imported once the node caught
+/// up with the network, and they are always fully checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DaSyncMode {
+	/// Every block is fully checked.
+	#[default]
+	Full,
+	/// Only blocks whose number is a multiple of `n` are fully checked.
+	EveryNth(NonZeroU32),
+	/// Each block is fully checked with a probability of `ratio`.
+	Random(Percent),
+	/// No block is checked.
+	Unsafe,
+}
+
+impl DaSyncMode {
+	/// Returns whether the synced block `number` is fully checked.
+	fn samples(&self, number: u32, is_checkpoint: bool) -> bool {
+		match self {
+			Self::Full => true,
+			Self::Unsafe => false,
+			_ if is_checkpoint => true,
+			Self::EveryNth(n) => number % n.get() == 0,
+			Self::Random(ratio) => {
+				rand::thread_rng().gen_ratio(u32::from(ratio.deconstruct()), 100)
+			},
+		}
+	}
+}
+
+impl FromStr for DaSyncMode {
+	type Err = String;
+
+	/// Parses `full`, `every:<N>`, `random:<PERCENT>` or `unsafe`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || {
+			format!("Invalid DA sync mode `{s}`, expected `full`, `every:<N>`, `random:<PERCENT>` or `unsafe`")
+		};
+
+		match s.split_once(':') {
+			None if s == "full" => Ok(Self::Full),
+			None if s == "unsafe" => Ok(Self::Unsafe),
+			Some(("every", n)) => n.parse().map(Self::EveryNth).map_err(|_| invalid()),
+			Some(("random", percent)) => match percent.parse::<u8>() {
+				Ok(percent) if percent <= 100 => Ok(Self::Random(Percent::from_percent(percent))),
+				_ => Err(invalid()),
+			},
+			_ => Err(invalid()),
+		}
+	}
+}
+
+/// DA checks configuration of the [`BlockImport`].
+#[derive(Clone, Debug, Default)]
+pub struct DaImportConfig {
+	/// DA checks applied to the blocks imported during the initial sync.
+	pub sync: DaSyncMode,
+	/// Directory where blocks with a mismatching header extension are dumped, if any.
+	pub mismatch_dump_dir: Option<PathBuf>,
+}
+
+/// Coverage of the DA checks over the blocks imported during the initial sync.
+#[derive(Default)]
+struct SyncCoverage {
+	checked: AtomicU64,
+	skipped: AtomicU64,
+}
+
+impl SyncCoverage {
+	fn record(&self, checked: bool) {
+		ImportBlockMetrics::observe_sync_block(checked);
+		let counter = if checked {
+			&self.checked
+		} else {
+			&self.skipped
+		};
+		counter.fetch_add(1, Ordering::Relaxed);
+
+		let checked = self.checked.load(Ordering::Relaxed);
+		let total = checked + self.skipped.load(Ordering::Relaxed);
+		if total % SYNC_COVERAGE_LOG_PERIOD == 0 {
+			log::info!(
+				"🔍 DA sync coverage: {checked} of {total} synced blocks fully checked ({:.2}%)",
+				checked as f64 * 100.0 / total as f64
+			);
+		}
+	}
+}
+
+/// Result of a header extension check, and how long it took.
+type HeaderExtensionCheck = oneshot::Receiver<(Result<(), ConsensusError>, Duration)>;
 
 pub struct BlockImport<B, C, I> {
 	client: Arc<C>,
 	inner: I,
-	// If true, it skips the DA block import check during sync only.
-	unsafe_da_sync: bool,
+	// DA checks applied during sync only.
+	da_sync: DaSyncMode,
+	mismatch_dump_dir: Option<PathBuf>,
+	sync_coverage: Arc<SyncCoverage>,
+	// Checks header extensions in parallel with the block execution.
+	verification_pool: Arc<ThreadPool>,
 	_block: PhantomData<B>,
 }
 
@@ -39,19 +162,37 @@ where
 	B: BlockT<Extrinsic = OpaqueExtrinsic, Header = DaHeader, Hash = H256>,
 	I: BlockImportT<B> + Clone + Send + Sync,
 	I::Error: Into<ConsensusError>,
-	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync,
-	C::Api: DataAvailApi<B> + ExtensionBuilder<B>,
+	C: ProvideRuntimeApi<B> + CallApiAt<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: DataAvailApi<B> + ExtensionBuilder<B> + PostInherentsProvider<B>,
 {
-	pub fn new(client: Arc<C>, inner: I, unsafe_da_sync: bool) -> Self {
+	pub fn new(client: Arc<C>, inner: I, config: DaImportConfig) -> Self {
+		let verification_pool = ThreadPoolBuilder::new()
+			.thread_name(|idx| format!("da-import-verifier-{idx}"))
+			.build()
+			.expect("DA import verification pool can be built .qed");
+
 		Self {
 			client,
 			inner,
-			unsafe_da_sync,
+			da_sync: config.sync,
+			mismatch_dump_dir: config.mismatch_dump_dir,
+			sync_coverage: Default::default(),
+			verification_pool: Arc::new(verification_pool),
 			_block: PhantomData,
 		}
 	}
 
-	fn ensure_last_extrinsic_is_failed_send_message_txs(
+	/// Returns whether `block`, imported during the initial sync, is fully checked, and records
+	/// it in the sync coverage.
+	fn check_synced_block(&self, block: &BlockImportParams<B>) -> bool {
+		let is_checkpoint = block.justifications.is_some();
+		let checked = self.da_sync.samples(block.header.number, is_checkpoint);
+		self.sync_coverage.record(checked);
+		checked
+	}
+
+	/// Ensures the block ends with the post-inherents expected by the runtime.
+	fn ensure_valid_post_inherents(
 		&self,
 		block: &BlockImportParams<B>,
 	) -> Result<(), ConsensusError> {
@@ -62,13 +203,26 @@ where
 			return Err(err);
 		};
 
+		let parent_hash = <B as BlockT>::Hash::from(block.header.parent_hash);
+		let api = self.client.runtime_api();
+
+		let version = api
+			.api_version::<dyn ExtensionBuilder<B>>(parent_hash)
+			.ok()
+			.flatten()
+			.unwrap_or_default();
+		if version >= 5 {
+			return api
+				.check_post_inherents(parent_hash, body.clone())
+				.map_err(|_| err)?
+				.map_err(invalid_post_inherents);
+		}
+
+		// Older runtimes only append the `Vector` post-inherent.
 		let Some(last_extrinsic) = body.last() else {
 			return Err(err);
 		};
 
-		let parent_hash = <B as BlockT>::Hash::from(block.header.parent_hash);
-		let api = self.client.runtime_api();
-
 		let Ok(found) = api.check_if_extrinsic_is_post_inherent(parent_hash, last_extrinsic) else {
 			return Err(err);
 		};
@@ -78,15 +232,95 @@ where
 		Ok(())
 	}
 
-	fn ensure_valid_header_extension(
+	/// Executes the block in its own memory temporal storage context, and ensures its
+	/// post-inherents are the ones recomputed from that execution, like the failed
+	/// `Vector::send_message` indices.
+	///
+	/// The storage changes of the execution are passed to the inner import, so the block is not
+	/// executed twice.
+	fn ensure_valid_post_inherent_contents(
 		&self,
-		block: &BlockImportParams<B>,
+		block: &mut BlockImportParams<B>,
 	) -> Result<(), ConsensusError> {
-		let block_len = extension_block_len(&block.header.extension);
-		let extrinsics = || block.body.clone().unwrap_or_default();
-		let block_number: u32 = block.header.number;
+		if !matches!(
+			block.state_action,
+			StateAction::Execute | StateAction::ExecuteIfPossible
+		) {
+			return Ok(());
+		}
+
+		let Some(body) = block.body.clone() else {
+			return Err(block_doesnt_contain_post_inherent());
+		};
 		let parent_hash = <B as BlockT>::Hash::from(block.header.parent_hash);
-		let api = self.client.runtime_api();
+		let scope = MemTmpStorageScope::new(parent_hash);
+
+		let mut api = self.client.runtime_api();
+		api.set_call_context(CallContext::Onchain);
+		api.register_extension(scope.extension());
+		api.execute_block(parent_hash, B::new(block.header.clone(), body.clone()))
+			.map_err(execution_fail)?;
+		let state = self.client.state_at(parent_hash).map_err(execution_fail)?;
+		let changes = api
+			.into_storage_changes(&state, parent_hash)
+			.map_err(storage_changes_fail)?;
+
+		// Post-inherents created from the execution results, as the block author should have.
+		let expected = api
+			.create_post_inherent_extrinsics(parent_hash, scope.storage())
+			.map_err(execution_fail)?;
+		let tail_start = body.len().saturating_sub(expected.len());
+		ensure!(
+			body.len() >= expected.len() && body[tail_start..] == expected[..],
+			post_inherents_mismatch(&body[tail_start..], &expected)
+		);
+
+		block.state_action = StateAction::ApplyChanges(StorageChanges::Changes(changes));
+		Ok(())
+	}
+
+	/// Spawns the header extension check of `block` on the verification pool, so it runs in
+	/// parallel with the block execution.
+	fn spawn_header_extension_check(&self, block: &BlockImportParams<B>) -> HeaderExtensionCheck {
+		let (tx, rx) = oneshot::channel();
+		let client = self.client.clone();
+		let hash = block.post_hash();
+		let header = block.header.clone();
+		let extrinsics = block.body.clone().unwrap_or_default();
+		let dump_dir = self.mismatch_dump_dir.clone();
+
+		self.verification_pool.spawn(move || {
+			let start = Instant::now();
+			let result = {
+				let _metric_observer =
+					MetricObserver::new(ObserveKind::ImportBlockHeaderExtensionCheck);
+				Self::ensure_valid_header_extension(
+					&client,
+					hash,
+					&header,
+					extrinsics,
+					dump_dir.as_deref(),
+				)
+			};
+			// The import could have failed meanwhile, and dropped the receiver.
+			let _ = tx.send((result, start.elapsed()));
+		});
+
+		rx
+	}
+
+	fn ensure_valid_header_extension(
+		client: &C,
+		hash: H256,
+		header: &DaHeader,
+		extrinsics: Vec<OpaqueExtrinsic>,
+		dump_dir: Option<&Path>,
+	) -> Result<(), ConsensusError> {
+		let block_len = extension_block_len(&header.extension);
+		let extrinsics = || extrinsics.clone();
+		let block_number: u32 = header.number;
+		let parent_hash = <B as BlockT>::Hash::from(header.parent_hash);
+		let api = client.runtime_api();
 
 		// Calculate data root and extension.
 		let data_root = api
@@ -103,10 +337,14 @@ where
 			.map_err(build_ext_fail)?;
 
 		// Check equality between calculated and imported extensions.
-		ensure!(
-			block.header.extension == extension,
-			extension_mismatch(&block.header.extension, &extension)
-		);
+		if header.extension != extension {
+			let diff = HeaderExtensionDiff::new(&header.extension, &extension);
+			log::error!("❌ DA Extension mismatch at block #{block_number} ({hash:?}): {diff}");
+			if let Some(dir) = dump_dir {
+				dump_extension_mismatch(dir, hash, header, &extrinsics(), &extension, &diff);
+			}
+			return Err(extension_mismatch(&diff));
+		}
 		Ok(())
 	}
 }
@@ -117,30 +355,31 @@ where
 	B: BlockT<Extrinsic = OpaqueExtrinsic, Header = DaHeader, Hash = H256>,
 	I: BlockImportT<B> + Clone + Send + Sync,
 	I::Error: Into<ConsensusError>,
-	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync,
-	C::Api: DataAvailApi<B> + ExtensionBuilder<B>,
+	C: ProvideRuntimeApi<B> + CallApiAt<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: DataAvailApi<B> + ExtensionBuilder<B> + PostInherentsProvider<B>,
 {
 	type Error = ConsensusError;
 
 	/// It verifies that header extension (Kate commitment & data root) is properly calculated.
 	async fn import_block(
 		&mut self,
-		block: BlockImportParams<B>,
+		mut block: BlockImportParams<B>,
 	) -> Result<ImportResult, Self::Error> {
 		let _metric_observer = MetricObserver::new(ObserveKind::ImportBlockTotalExecutionTime);
 
 		// We only want to check for blocks that are not from "Own"
 		let is_own = matches!(block.origin, BlockOrigin::Own);
 
-		// We skip checks if we're syncing and unsafe_da_sync is true
+		// During sync, only the blocks sampled by the DA sync mode are checked.
 		let is_sync = matches!(
 			block.origin,
 			BlockOrigin::NetworkInitialSync | BlockOrigin::File
 		);
-		let skip_sync = self.unsafe_da_sync && is_sync;
-		if !is_own && !skip_sync && !block.with_state() {
-			self.ensure_last_extrinsic_is_failed_send_message_txs(&block)?;
-			self.ensure_valid_header_extension(&block)?;
+		if !is_own && !block.with_state() && (!is_sync || self.check_synced_block(&block)) {
+			self.ensure_valid_post_inherents(&block)?;
+			let header_extension_check = self.spawn_header_extension_check(&block);
+			self.ensure_valid_post_inherent_contents(&mut block)?;
+			wait_header_extension_check(header_extension_check).await?;
 		}
 
 		// Next import block stage & metrics
@@ -161,12 +400,29 @@ impl<B, C, I: Clone> Clone for BlockImport<B, C, I> {
 		Self {
 			client: self.client.clone(),
 			inner: self.inner.clone(),
-			unsafe_da_sync: self.unsafe_da_sync,
+			da_sync: self.da_sync,
+			mismatch_dump_dir: self.mismatch_dump_dir.clone(),
+			sync_coverage: self.sync_coverage.clone(),
+			verification_pool: self.verification_pool.clone(),
 			_block: PhantomData,
 		}
 	}
 }
 
+/// Waits for the header extension check, which ran in parallel with the block execution.
+async fn wait_header_extension_check(check: HeaderExtensionCheck) -> Result<(), ConsensusError> {
+	let start = Instant::now();
+	let (result, check_time) = {
+		let _metric_observer = MetricObserver::new(ObserveKind::ImportBlockHeaderExtensionWait);
+		check
+			.await
+			.map_err(|_| header_extension_check_cancelled())?
+	};
+	ImportBlockMetrics::observe_header_extension_saved_time(check_time, start.elapsed());
+
+	result
+}
+
 /// Calculate block length from `extension`.
 fn extension_block_len(extension: &HeaderExtension) -> BlockLength {
 	BlockLength::with_normal_ratio(
@@ -178,9 +434,43 @@ fn extension_block_len(extension: &HeaderExtension) -> BlockLength {
 	.expect("Valid BlockLength at genesis .qed")
 }
 
-fn extension_mismatch(imported: &HeaderExtension, generated: &HeaderExtension) -> ConsensusError {
-	let msg =
-		format!("DA Extension does NOT match\nExpected: {imported:#?}\nGenerated:{generated:#?}");
+/// Dumps the block with a mismatching header extension, both extensions and their `diff` into a
+/// new directory under `dir`, for offline analysis.
+fn dump_extension_mismatch(
+	dir: &Path,
+	hash: H256,
+	header: &DaHeader,
+	extrinsics: &[OpaqueExtrinsic],
+	generated: &HeaderExtension,
+	diff: &HeaderExtensionDiff,
+) {
+	let dir = dir.join(format!("{}_{hash:?}", header.number));
+	let dump = || -> std::io::Result<()> {
+		fs::create_dir_all(&dir)?;
+		fs::write(dir.join("block.scale"), (header, extrinsics).encode())?;
+		fs::write(dir.join("generated_extension.scale"), generated.encode())?;
+		fs::write(
+			dir.join("imported_extension.txt"),
+			format!("{:#?}", header.extension),
+		)?;
+		fs::write(
+			dir.join("generated_extension.txt"),
+			format!("{generated:#?}"),
+		)?;
+		fs::write(dir.join("diff.txt"), format!("{diff:#?}"))
+	};
+
+	match dump() {
+		Ok(()) => log::info!("DA Extension mismatch dumped into {}", dir.display()),
+		Err(e) => log::warn!(
+			"DA Extension mismatch cannot be dumped into {}: {e}",
+			dir.display()
+		),
+	}
+}
+
+fn extension_mismatch(diff: &HeaderExtensionDiff) -> ConsensusError {
+	let msg = format!("DA Extension does NOT match: {diff}");
 	ConsensusError::ClientImport(msg)
 }
 
@@ -194,7 +484,80 @@ fn build_ext_fail(e: ApiError) -> ConsensusError {
 	ConsensusError::ClientImport(msg)
 }
 
+fn header_extension_check_cancelled() -> ConsensusError {
+	let msg = "Header extension check was cancelled".to_string();
+	ConsensusError::ClientImport(msg)
+}
+
+fn execution_fail(e: ApiError) -> ConsensusError {
+	let msg = format!("Block execution fails due to: {e:?}");
+	ConsensusError::ClientImport(msg)
+}
+
+fn storage_changes_fail(e: String) -> ConsensusError {
+	let msg = format!("Storage changes cannot be collected: {e}");
+	ConsensusError::ClientImport(msg)
+}
+
+fn post_inherents_mismatch(
+	imported: &[OpaqueExtrinsic],
+	expected: &[OpaqueExtrinsic],
+) -> ConsensusError {
+	let msg =
+		format!("Post inherents do NOT match\nImported: {imported:?}\nExpected: {expected:?}");
+	ConsensusError::ClientImport(msg)
+}
+
+fn invalid_post_inherents(e: PostInherentsError) -> ConsensusError {
+	let msg = format!("Invalid post inherents: {e:?}");
+	ConsensusError::ClientImport(msg)
+}
+
 fn block_doesnt_contain_post_inherent() -> ConsensusError {
 	let msg = "Block does not contain post inherent".to_string();
 	ConsensusError::ClientImport(msg)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn da_sync_mode_from_str() {
+		let every = |n| DaSyncMode::EveryNth(NonZeroU32::new(n).unwrap());
+		assert_eq!("full".parse(), Ok(DaSyncMode::Full));
+		assert_eq!("unsafe".parse(), Ok(DaSyncMode::Unsafe));
+		assert_eq!("every:10".parse(), Ok(every(10)));
+		assert_eq!(
+			"random:25".parse(),
+			Ok(DaSyncMode::Random(Percent::from_percent(25)))
+		);
+
+		for invalid in [
+			"",
+			"every:0",
+			"every:",
+			"random:101",
+			"random:-1",
+			"sometimes:1",
+		] {
+			assert!(invalid.parse::<DaSyncMode>().is_err(), "{invalid}");
+		}
+	}
+
+	#[test]
+	fn da_sync_mode_samples() {
+		let every = DaSyncMode::EveryNth(NonZeroU32::new(4).unwrap());
+		let sampled = (1..=16).filter(|n| every.samples(*n, false)).count();
+		assert_eq!(sampled, 4);
+		assert!(every.samples(5, true));
+
+		let never = DaSyncMode::Random(Percent::zero());
+		assert!((1..=16).all(|n| !never.samples(n, false)));
+		assert!(never.samples(1, true));
+		assert!(DaSyncMode::Random(Percent::one()).samples(1, false));
+
+		assert!(!DaSyncMode::Unsafe.samples(1, true));
+		assert!(DaSyncMode::Full.samples(1, false));
+	}
+}
@@ -0,0 +1,193 @@
+/// # Header Extension Diff
+///
+/// Field-level comparison between an imported header extension and the one generated during the
+/// block import, so a mismatch can be reported without dumping whole commitments.
+use avail_core::{data_lookup::compact::CompactDataLookup, header::HeaderExtension};
+use sp_core::H256;
+use std::fmt;
+
+/// Size in bytes of one KZG commitment, one per extended row.
+const COMMITMENT_SIZE: usize = 48;
+/// Maximum number of indices listed per field in the summary.
+const MAX_LISTED_INDICES: usize = 8;
+
+/// Fields which differ between an imported and a generated header extension, as
+/// `(imported, generated)` pairs or indices.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HeaderExtensionDiff {
+	pub rows: Option<(u16, u16)>,
+	pub cols: Option<(u16, u16)>,
+	pub data_root: Option<(H256, H256)>,
+	/// Number of commitments, if it differs.
+	pub commitment_count: Option<(usize, usize)>,
+	/// Indices of the commitments which differ, including those present in only one extension.
+	pub commitments: Vec<usize>,
+	pub app_lookup_size: Option<(u32, u32)>,
+	/// Indices of the app lookup entries which differ, including those present in only one
+	/// extension.
+	pub app_lookup_entries: Vec<usize>,
+}
+
+impl HeaderExtensionDiff {
+	pub fn new(imported: &HeaderExtension, generated: &HeaderExtension) -> Self {
+		match (imported, generated) {
+			(HeaderExtension::V3(imported), HeaderExtension::V3(generated)) => {
+				let (imp_kate, gen_kate) = (&imported.commitment, &generated.commitment);
+				let imp_lookup = CompactDataLookup::from_data_lookup(&imported.app_lookup);
+				let gen_lookup = CompactDataLookup::from_data_lookup(&generated.app_lookup);
+
+				let imp_commitments = imp_kate.commitment.chunks(COMMITMENT_SIZE);
+				let gen_commitments = gen_kate.commitment.chunks(COMMITMENT_SIZE);
+
+				Self {
+					rows: pair_if_ne(imp_kate.rows, gen_kate.rows),
+					cols: pair_if_ne(imp_kate.cols, gen_kate.cols),
+					data_root: pair_if_ne(imp_kate.data_root, gen_kate.data_root),
+					commitment_count: pair_if_ne(imp_commitments.len(), gen_commitments.len()),
+					commitments: differing_indices(imp_commitments, gen_commitments),
+					app_lookup_size: pair_if_ne(imp_lookup.size, gen_lookup.size),
+					app_lookup_entries: differing_indices(
+						imp_lookup.index.iter(),
+						gen_lookup.index.iter(),
+					),
+				}
+			},
+		}
+	}
+
+	/// Returns `true` if no field differs.
+	pub fn is_empty(&self) -> bool {
+		*self == Self::default()
+	}
+}
+
+/// Compact summary of the differing fields.
+impl fmt::Display for HeaderExtensionDiff {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut fields = Vec::new();
+		if let Some((imported, generated)) = self.rows {
+			fields.push(format!("rows {imported} != {generated}"));
+		}
+		if let Some((imported, generated)) = self.cols {
+			fields.push(format!("cols {imported} != {generated}"));
+		}
+		if let Some((imported, generated)) = self.data_root {
+			fields.push(format!("data_root {imported:?} != {generated:?}"));
+		}
+		if let Some((imported, generated)) = self.commitment_count {
+			fields.push(format!("commitment count {imported} != {generated}"));
+		}
+		if !self.commitments.is_empty() {
+			fields.push(format!("commitments at {}", list(&self.commitments)));
+		}
+		if let Some((imported, generated)) = self.app_lookup_size {
+			fields.push(format!("app lookup size {imported} != {generated}"));
+		}
+		if !self.app_lookup_entries.is_empty() {
+			fields.push(format!(
+				"app lookup entries at {}",
+				list(&self.app_lookup_entries)
+			));
+		}
+
+		if fields.is_empty() {
+			write!(f, "no differences")
+		} else {
+			write!(f, "{}", fields.join(", "))
+		}
+	}
+}
+
+fn pair_if_ne<T: PartialEq>(imported: T, generated: T) -> Option<(T, T)> {
+	(imported != generated).then_some((imported, generated))
+}
+
+/// Indices where `imported` and `generated` differ, including the tail of the longest one.
+fn differing_indices<T: PartialEq>(
+	mut imported: impl Iterator<Item = T>,
+	mut generated: impl Iterator<Item = T>,
+) -> Vec<usize> {
+	let mut indices = Vec::new();
+	for idx in 0.. {
+		match (imported.next(), generated.next()) {
+			(None, None) => break,
+			(imported, generated) if imported != generated => indices.push(idx),
+			_ => {},
+		}
+	}
+	indices
+}
+
+/// Lists the first `MAX_LISTED_INDICES` indices, and how many others there are.
+fn list(indices: &[usize]) -> String {
+	let listed = indices
+		.iter()
+		.take(MAX_LISTED_INDICES)
+		.map(ToString::to_string)
+		.collect::<Vec<_>>()
+		.join(", ");
+
+	match indices.len().checked_sub(MAX_LISTED_INDICES) {
+		Some(more) if more > 0 => format!("[{listed}] and {more} more"),
+		_ => format!("[{listed}]"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use avail_core::{
+		header::extension::v3, kate_commitment::v3::KateCommitment, AppId, DataLookup,
+	};
+
+	/// Extension with one commitment per byte of `commitments`, and the app lookup of
+	/// `(app_id, len)` entries.
+	fn extension(rows: u16, commitments: &[u8], lookup: &[(u32, u32)]) -> HeaderExtension {
+		let commitment = commitments
+			.iter()
+			.flat_map(|byte| [*byte; COMMITMENT_SIZE])
+			.collect();
+		let app_lookup =
+			DataLookup::from_id_and_len_iter(lookup.iter().map(|(id, len)| (AppId(*id), *len)))
+				.expect("Valid lookup .qed");
+
+		HeaderExtension::V3(v3::HeaderExtension {
+			app_lookup,
+			commitment: KateCommitment::new(rows, 4, H256::zero(), commitment),
+		})
+	}
+
+	#[test]
+	fn equal_extensions_have_no_diff() {
+		let ext = extension(2, &[1, 2, 3, 4], &[(0, 2), (1, 2)]);
+		let diff = HeaderExtensionDiff::new(&ext, &ext);
+		assert!(diff.is_empty());
+		assert_eq!(diff.to_string(), "no differences");
+	}
+
+	#[test]
+	fn diff_lists_differing_fields() {
+		let imported = extension(2, &[1, 2, 3, 4], &[(0, 2), (1, 2)]);
+		let generated = extension(4, &[1, 9, 3, 4, 5, 6, 7, 8], &[(0, 2), (2, 2)]);
+		let diff = HeaderExtensionDiff::new(&imported, &generated);
+
+		assert_eq!(diff.rows, Some((2, 4)));
+		assert_eq!(diff.cols, None);
+		assert_eq!(diff.data_root, None);
+		assert_eq!(diff.commitment_count, Some((4, 8)));
+		assert_eq!(diff.commitments, vec![1, 4, 5, 6, 7]);
+		assert_eq!(diff.app_lookup_size, None);
+		assert_eq!(diff.app_lookup_entries, vec![1]);
+		assert_eq!(
+			diff.to_string(),
+			"rows 2 != 4, commitment count 4 != 8, commitments at [1, 4, 5, 6, 7], app lookup entries at [1]"
+		);
+	}
+
+	#[test]
+	fn summary_truncates_long_lists() {
+		assert_eq!(list(&[1, 2]), "[1, 2]");
+		let indices = (0..20).collect::<Vec<_>>();
+		assert_eq!(list(&indices), "[0, 1, 2, 3, 4, 5, 6, 7] and 12 more");
+	}
+}
@@ -1,6 +1,7 @@
 pub mod chains;
 
 pub mod da_block_import;
+mod header_extension_diff;
 pub use da_block_import::BlockImport;
 
 pub mod cli;
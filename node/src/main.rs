@@ -8,6 +8,7 @@ mod service;
 mod cli;
 mod command;
 mod da_block_import;
+mod header_extension_diff;
 mod rpc;
 mod transaction_state;
 
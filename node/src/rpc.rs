@@ -30,6 +30,8 @@
 
 //! # Data Availability Changes
 //! - Add Kate RPC extension.
+//! - Add DA control RPC extension.
+//! - Add Vector RPC extension.
 //! - Remove `sc_rpc::dev` extension.
 
 #![warn(missing_docs)]
@@ -133,6 +135,7 @@ where
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use da_rpc::{Da, DaApiServer};
 	use kate_rpc::metrics::KateApiMetricsServer;
 	use kate_rpc::{Kate, KateApiServer};
 	use mmr_rpc::{Mmr, MmrApiServer};
@@ -143,6 +146,7 @@ where
 	use sc_sync_state_rpc::{SyncState, SyncStateApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
+	use vector_rpc::{Vector, VectorApiServer};
 
 	#[cfg(feature = "testing-environment")]
 	use testing_rpc::{TestingApiServer, TestingEnv};
@@ -228,6 +232,8 @@ where
 	)?;
 
 	io.merge(StateMigration::new(client.clone(), backend, deny_unsafe).into_rpc())?;
+	io.merge(Da::<C, Block>::new(client.clone()).into_rpc())?;
+	io.merge(Vector::<C, Block>::new(client.clone()).into_rpc())?;
 
 	if is_dev_chain || kate_rpc_deps.rpc_metrics_enabled {
 		io.merge(KateApiMetricsServer::into_rpc(Kate::<C, Block>::new(
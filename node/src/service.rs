@@ -20,7 +20,8 @@
 #![allow(dead_code)]
 
 use crate::transaction_state;
-use crate::{cli::Cli, rpc as node_rpc};
+use crate::{cli::Cli, da_block_import::DaImportConfig, rpc as node_rpc};
+use avail_base::header_builder::PoolConfig;
 use avail_core::AppId;
 use da_runtime::{apis::RuntimeApi, NodeBlock as Block, Runtime};
 
@@ -43,7 +44,7 @@ use sc_telemetry::{custom_telemetry::CustomTelemetryWorker, Telemetry, Telemetry
 use sc_transaction_pool_api::OffchainTransactionPoolFactory;
 use sp_api::ProvideRuntimeApi;
 use sp_core::crypto::Pair;
-use sp_runtime::{generic::Era, traits::Block as BlockT, SaturatedConversion};
+use sp_runtime::{generic::Era, traits::Block as BlockT, Percent, SaturatedConversion};
 use std::{path::Path, sync::Arc};
 use substrate_prometheus_endpoint::{PrometheusError, Registry};
 
@@ -172,7 +173,7 @@ pub fn create_extrinsic(
 #[allow(clippy::type_complexity)]
 pub fn new_partial(
 	config: &Configuration,
-	unsafe_da_sync: bool,
+	da_import: DaImportConfig,
 	kate_rpc_deps: kate_rpc::Deps,
 	tx_state_cli_deps: transaction_state::CliDeps,
 ) -> Result<
@@ -266,7 +267,7 @@ pub fn new_partial(
 		client.clone(),
 	)?;
 
-	let da_block_import = BlockImport::new(client.clone(), block_import, unsafe_da_sync);
+	let da_block_import = BlockImport::new(client.clone(), block_import, da_import);
 
 	let slot_duration = babe_link.config().slot_duration();
 	let (import_queue, babe_worker_handle) =
@@ -396,13 +397,23 @@ pub struct NewFullBase {
 	pub rpc_handlers: RpcHandlers,
 }
 
+/// Header builder configuration of the node.
+#[derive(Clone, Copy, Debug)]
+pub struct HeaderBuilderConfig {
+	/// Thread pool building the commitments.
+	pub pool: PoolConfig,
+	/// Percentage of the block authoring time by which the header extension must be built.
+	pub deadline: Percent,
+}
+
 /// Creates a full service from the configuration.
 #[allow(clippy::too_many_arguments)]
 pub fn new_full_base(
 	config: Configuration,
 	disable_hardware_benchmarks: bool,
 	with_startup_data: impl FnOnce(&BlockImport, &sc_consensus_babe::BabeLink<Block>),
-	unsafe_da_sync: bool,
+	da_import: DaImportConfig,
+	header_builder: HeaderBuilderConfig,
 	kate_rpc_deps: kate_rpc::Deps,
 	tx_state_cli_deps: transaction_state::CliDeps,
 ) -> Result<NewFullBase, ServiceError> {
@@ -424,7 +435,7 @@ pub fn new_full_base(
 		select_chain,
 		transaction_pool,
 		other: (rpc_builder, import_setup, rpc_setup, mut telemetry, tx_state_deps),
-	} = new_partial(&config, unsafe_da_sync, kate_rpc_deps, tx_state_cli_deps)?;
+	} = new_partial(&config, da_import, kate_rpc_deps, tx_state_cli_deps)?;
 
 	let shared_voter_state = rpc_setup;
 	let auth_disc_publish_non_global_ips = config.network.allow_non_globals_in_dht;
@@ -470,6 +481,7 @@ pub fn new_full_base(
 	if let Some(reg) = prometheus_registry.as_ref() {
 		extend_metrics(reg)?;
 	}
+	avail_base::header_builder::init_pool(header_builder.pool).map_err(ServiceError::Other)?;
 
 	let rpc_handlers = sc_service::spawn_tasks(sc_service::SpawnTasksParams {
 		config,
@@ -504,13 +516,14 @@ pub fn new_full_base(
 	(with_startup_data)(&block_import, &babe_link);
 
 	if let sc_service::config::Role::Authority { .. } = &role {
-		let proposer = sc_basic_authorship::ProposerFactory::new(
+		let mut proposer = sc_basic_authorship::ProposerFactory::new(
 			task_manager.spawn_handle(),
 			client.clone(),
 			transaction_pool.clone(),
 			prometheus_registry.as_ref(),
 			telemetry.as_ref().map(|x| x.handle()),
 		);
+		proposer.set_header_builder_deadline(header_builder.deadline);
 
 		let client_clone = client.clone();
 		let slot_duration = babe_link.config().slot_duration();
@@ -722,6 +735,8 @@ pub fn new_full_base(
 /// Builds a new service for a full client.
 pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceError> {
 	let database_path = config.database.path().map(Path::to_path_buf);
+	let da_import = cli.da_import_config(&config);
+	let header_builder = cli.header_builder_config();
 	let kate_rpc_deps = kate_rpc::Deps {
 		max_cells_size: cli.kate_max_cells_size,
 		rpc_enabled: cli.kate_rpc_enabled,
@@ -737,7 +752,8 @@ pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceE
 		config,
 		cli.no_hardware_benchmarks,
 		|_, _| (),
-		cli.unsafe_da_sync,
+		da_import,
+		header_builder,
 		kate_rpc_deps,
 		tx_state_cli_deps,
 	)
@@ -11,7 +11,7 @@ use codec::{Decode, Encode};
 use frame_benchmarking::{
 	impl_benchmark_test_suite, v1::BenchmarkError, v2::*, whitelisted_caller,
 };
-use frame_support::traits::Get;
+use frame_support::traits::{Get, Hooks};
 use frame_system::{
 	limits::BlockLength, native::hosted_header_builder::hosted_header_builder, RawOrigin,
 };
@@ -297,6 +297,139 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn submit_data_hash() -> Result<(), BenchmarkError> {
+		if <T as frame_system::Config>::DataRootVersion::get() != DataRootVersion::V2 {
+			return Err(BenchmarkError::Skip);
+		}
+		let caller = whitelisted_caller::<T::AccountId>();
+		let origin = RawOrigin::Signed(caller.clone());
+		let len = T::MaxAppDataLength::get();
+		let data = generate_bounded::<AppDataFor<T>>(len);
+		let data_hash = H256(sp_io::hashing::keccak_256(&data));
+
+		#[extrinsic_call]
+		_(origin, data_hash, len);
+
+		assert_last_event::<T>(
+			Event::DataHashSubmitted {
+				who: caller,
+				data_hash,
+				len,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_block_length_proposal() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let rows = T::MaxBlockRows::get().0;
+		let cols = T::MaxBlockCols::get().0;
+		let at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		_(origin, rows, cols, at);
+
+		assert_eq!(BlockLenProposals::<T>::count(), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_block_length_proposal() -> Result<(), BenchmarkError> {
+		let rows = T::MaxBlockRows::get().0;
+		let cols = T::MaxBlockCols::get().0;
+		let at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Pallet::<T>::schedule_block_length_proposal(RawOrigin::Root.into(), rows, cols, at)
+			.map_err(|_| BenchmarkError::Stop("Cannot schedule block length proposal"))?;
+		let id = Pallet::<T>::pending_block_length_proposals()[0].0.clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, id);
+
+		assert_eq!(BlockLenProposals::<T>::count(), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn apply_block_length_proposals(
+		p: Linear<1, { T::MaxPendingBlockLenProposals::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let rows = T::MaxBlockRows::get().0;
+		let cols = T::MaxBlockCols::get().0;
+		let now = frame_system::Pallet::<T>::block_number();
+		let at = now + 1u32.into();
+		for _ in 0..p {
+			Pallet::<T>::schedule_block_length_proposal(RawOrigin::Root.into(), rows, cols, at)
+				.map_err(|_| BenchmarkError::Stop("Cannot schedule block length proposal"))?;
+		}
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(at);
+		}
+
+		assert_eq!(BlockLenProposals::<T>::count(), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_block_length_controller() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let config = BlockLengthControllerConfig {
+			target_utilization: Perbill::from_percent(50),
+			window: 10,
+			max_step: 1,
+		};
+
+		#[extrinsic_call]
+		_(origin, Some(config));
+
+		assert_eq!(BlockLengthController::<T>::get(), Some(config));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn note_scalar_usage() -> Result<(), BenchmarkError> {
+		#[block]
+		{
+			Pallet::<T>::note_scalar_usage();
+		}
+
+		assert_eq!(ScalarUsage::<T>::get().blocks, 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn adjust_block_length() -> Result<(), BenchmarkError> {
+		let config = BlockLengthControllerConfig {
+			target_utilization: Perbill::from_percent(50),
+			window: 1,
+			max_step: 1,
+		};
+		// A fully used window always triggers a growth.
+		ScalarUsage::<T>::put(BlockScalarUsage {
+			blocks: 1,
+			used: 100,
+			available: 100,
+		});
+		Pallet::<T>::submit_block_length_proposal(
+			RawOrigin::Root.into(),
+			T::MinBlockRows::get().0,
+			T::MinBlockCols::get().0,
+		)
+		.map_err(|_| BenchmarkError::Stop("Cannot reset block length"))?;
+
+		#[block]
+		{
+			Pallet::<T>::adjust_block_length(&config);
+		}
+
+		assert_ne!(DynamicBlockLength::<T>::get().rows, T::MinBlockRows::get());
+		Ok(())
+	}
+
 	#[benchmark(extra)]
 	fn commitment_builder_64(
 		i: Linear<32, { T::MaxBlockRows::get().0 }>,
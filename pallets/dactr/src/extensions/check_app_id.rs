@@ -51,7 +51,7 @@ where
 
 	/// It validates that `AppId` is correct and already registered for the call and potential nested calls.
 	/// Transaction validation:
-	///  - `DataAvailability::submit_data(..)` extrinsic can use `AppId != 0`.
+	///  - `DataAvailability::submit_data(..)` and `DataAvailability::submit_data_hash(..)` extrinsics can use `AppId != 0`.
 	///  - `Utility::batch/batch_all/force_batch(..)` extrinsic can use `AppId != 0` If the wrapped calls are ALL `DataAvailability::submit_data(..)`.
 	///  - Any other call must use `AppId == 0`.
 	///  - It also ensures that Kate's evaluation grid can be generated during the header
@@ -114,7 +114,9 @@ where
 		let mut iterations = 0;
 
 		while let Some(call) = stack.pop() {
-			if let Some(DACall::<T>::submit_data { .. }) = call.is_sub_type() {
+			if let Some(DACall::<T>::submit_data { .. } | DACall::<T>::submit_data_hash { .. }) =
+				call.is_sub_type()
+			{
 				let next_app_id =
 					maybe_next_app_id.get_or_insert_with(<Pallet<T>>::peek_next_application_id);
 				ensure!(
@@ -229,6 +231,7 @@ mod tests {
 		InvalidTransactionCustomId::{ForbiddenAppId, InvalidAppId},
 	};
 	use frame_system::pallet::Call as SysCall;
+	use sp_core::H256;
 	use sp_runtime::transaction_validity::InvalidTransaction;
 	use test_case::test_case;
 
@@ -246,6 +249,13 @@ mod tests {
 		})
 	}
 
+	fn submit_data_hash_call() -> RuntimeCall {
+		RuntimeCall::DataAvailability(DACall::submit_data_hash {
+			data_hash: H256::repeat_byte(1),
+			len: 32,
+		})
+	}
+
 	fn to_invalid_tx(custom_id: InvalidTransactionCustomId) -> TransactionValidity {
 		Err(TransactionValidityError::Invalid(
 			InvalidTransaction::Custom(custom_id as u8),
@@ -254,6 +264,8 @@ mod tests {
 
 	#[test_case(1, submit_data_call() => Ok(ValidTransaction::default()); "Submit Data call should be allowed to use any valid AppId" )]
 	#[test_case(100, submit_data_call() => to_invalid_tx(InvalidAppId); "Submit Data call with invalid AppId should be blocked" )]
+	#[test_case(1, submit_data_hash_call() => Ok(ValidTransaction::default()); "Submit Data Hash call should be allowed to use any valid AppId" )]
+	#[test_case(100, submit_data_hash_call() => to_invalid_tx(InvalidAppId); "Submit Data Hash call with invalid AppId should be blocked" )]
 	#[test_case(0, remark_call() => Ok(ValidTransaction::default()); "Any Non-Submit-Data call with AppId == 0 should be allowed" )]
 	#[test_case(1, remark_call() => to_invalid_tx(ForbiddenAppId); "Any Non-Submit-Data call with valid AppId != 0 should be blocked" )]
 	fn do_validate_test(id: u32, call: RuntimeCall) -> TransactionValidity {
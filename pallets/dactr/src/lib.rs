@@ -2,20 +2,23 @@
 // `construct_runtime!` does a lot of recursion and requires us to increase the limit to 256.
 #![recursion_limit = "256"]
 
+use avail_base::header_extension::DataRootVersion;
 use avail_core::{
 	AppId, BlockLengthColumns, BlockLengthRows, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO,
 	NORMAL_DISPATCH_RATIO,
 };
 use codec::{Compact, CompactLen as _};
 use frame_support::weights::constants::ExtrinsicBaseWeight;
-use frame_support::{dispatch::DispatchClass, traits::Get, weights::Weight};
-use frame_system::{limits::BlockLength, pallet::DynamicBlockLength};
+use frame_support::{dispatch::DispatchClass, ensure, traits::Get, weights::Weight};
+use frame_system::{
+	limits::BlockLength, pallet::DynamicBlockLength, pallet_prelude::BlockNumberFor,
+};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::{CheckedAdd, One, SaturatedConversion};
 use sp_core::H256;
 use sp_io::hashing::blake2_256;
-use sp_runtime::Perbill;
+use sp_runtime::{traits::Zero, Perbill};
 use sp_std::{mem::replace, vec, vec::Vec};
 
 pub use crate::{pallet::*, weights::WeightInfo};
@@ -23,17 +26,26 @@ pub use crate::{pallet::*, weights::WeightInfo};
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 mod extensions;
+pub mod migrations;
 #[cfg(feature = "std")]
 pub mod mock;
 #[cfg(test)]
 mod tests;
 pub use extensions::check_app_id::CheckAppId;
 pub use extensions::check_batch_transactions::CheckBatchTransactions;
-use frame_support::dispatch::DispatchFeeModifier;
+use frame_support::dispatch::{
+	DispatchFeeModifier, DispatchInfo, GetDispatchInfo, PostDispatchInfo,
+};
+use pallet_transaction_payment::{Config as TxPaymentConfig, OnChargeTransaction};
+use sp_runtime::traits::Dispatchable;
 pub mod weights;
 
 pub const LOG_TARGET: &str = "runtime::da_control";
 
+/// Balance type used by the transaction payment pallet to charge fees.
+pub type FeeBalanceOf<T> =
+	<<T as TxPaymentConfig>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{pallet_prelude::*, DefaultNoBound};
@@ -55,6 +67,67 @@ pub mod pallet {
 
 	pub type AppKeyInfoFor<T> = AppKeyInfo<<T as frame_system::Config>::AccountId>;
 
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, RuntimeDebug, MaxEncodedLen)]
+	pub struct BlockLenProposal<BlockNumber> {
+		/// Proposed number of rows.
+		pub rows: BlockLengthRows,
+		/// Proposed number of columns.
+		pub cols: BlockLengthColumns,
+		/// Block at which the proposed dimensions will be applied.
+		pub at: BlockNumber,
+	}
+
+	pub type BlockLenProposalFor<T> = BlockLenProposal<BlockNumberFor<T>>;
+
+	/// Governance parameters of the automatic block length controller.
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[derive(Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+	pub struct BlockLengthControllerConfig {
+		/// Target ratio of used scalars over the scalars available for DA.
+		pub target_utilization: Perbill,
+		/// Number of blocks observed between two adjustments.
+		pub window: u32,
+		/// Maximum number of times the rows or the cols can be doubled (or halved) in one
+		/// adjustment.
+		pub max_step: u32,
+	}
+
+	/// Scalar usage accumulated since the last evaluation of the block length controller.
+	#[derive(Clone, Default, Encode, Decode, TypeInfo, PartialEq, RuntimeDebug, MaxEncodedLen)]
+	pub struct BlockScalarUsage {
+		/// Number of observed blocks.
+		pub blocks: u32,
+		/// Sum of the scalars used by DA extrinsics.
+		pub used: u64,
+		/// Sum of the scalars available for DA extrinsics.
+		pub available: u64,
+	}
+
+	/// Breakdown of the fee paid by a `submit_data` extrinsic.
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, RuntimeDebug)]
+	pub struct SubmitDataFeeEstimate<Balance> {
+		/// Weight of the extrinsic execution and of its data root leaf.
+		pub regular_weight: Weight,
+		/// Weight based on the share of the matrix used by the data.
+		pub scalar_weight: Weight,
+		/// Charged weight, the maximum of `regular_weight` and `scalar_weight`.
+		pub weight: Weight,
+		/// Encoded length of the extrinsic.
+		pub len: u32,
+		/// Base fee of the extrinsic.
+		pub base_fee: Balance,
+		/// Fee paid for the encoded length.
+		pub len_fee: Balance,
+		/// Fee paid for the weight, after the fee multiplier and the modifier are applied.
+		pub adjusted_weight_fee: Balance,
+		/// Modifier applied to the weight fee.
+		pub fee_modifier: DispatchFeeModifier,
+		/// Total fee, without tip.
+		pub total: Balance,
+	}
+
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
 		use super::*;
@@ -76,6 +149,7 @@ pub mod pallet {
 			pub const MaxBlockCols: BlockLengthColumns = BlockLengthColumns(1024);
 			pub const MaxAppKeyLength: u32 = 32;
 			pub const MaxAppDataLength: u32 = 1_048_576; // 1 Mb
+			pub const MaxPendingBlockLenProposals: u32 = 16;
 		}
 
 		#[frame_support::register_default_impl(TestDefaultConfig)]
@@ -85,6 +159,7 @@ pub mod pallet {
 			type MaxAppKeyLength = MaxAppKeyLength;
 			type MaxBlockCols = MaxBlockCols;
 			type MaxBlockRows = MaxBlockRows;
+			type MaxPendingBlockLenProposals = MaxPendingBlockLenProposals;
 			type MinBlockCols = MinBlockCols;
 			type MinBlockRows = MinBlockRows;
 			type WeightInfo = ();
@@ -126,11 +201,18 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxBlockCols: Get<BlockLengthColumns>;
 
+		/// Maximum number of scheduled block length proposals waiting to be applied.
+		#[pallet::constant]
+		type MaxPendingBlockLenProposals: Get<u32>;
+
 		/// Weights for this pallet.
 		type WeightInfo: weights::WeightInfo;
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Last application ID
@@ -143,10 +225,62 @@ pub mod pallet {
 	#[pallet::getter(fn application_key)]
 	pub type AppKeys<T: Config> = StorageMap<_, Blake2_128Concat, AppKeyFor<T>, AppKeyInfoFor<T>>;
 
+	/// Reverse index of `AppKeys`: application key of each application ID.
+	#[pallet::storage]
+	#[pallet::getter(fn application_key_by_id)]
+	pub type AppKeysById<T: Config> = StorageMap<_, Twox64Concat, AppId, AppKeyFor<T>>;
+
 	/// Store data fee modifier for submit_data call.
 	#[pallet::storage]
 	pub type SubmitDataFeeModifier<T: Config> = StorageValue<_, DispatchFeeModifier, ValueQuery>;
 
+	/// Next block length proposal ID
+	#[pallet::storage]
+	pub type NextBlockLenProposalId<T: Config> = StorageValue<_, T::BlockLenProposalId, ValueQuery>;
+
+	/// Scheduled block length proposals waiting to be applied.
+	#[pallet::storage]
+	pub type BlockLenProposals<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::BlockLenProposalId, BlockLenProposalFor<T>>;
+
+	/// Automatic block length controller parameters. The controller is disabled if it is not set.
+	#[pallet::storage]
+	pub type BlockLengthController<T: Config> = StorageValue<_, BlockLengthControllerConfig>;
+
+	/// Scalar usage observed by the block length controller in its current window.
+	#[pallet::storage]
+	pub type ScalarUsage<T: Config> = StorageValue<_, BlockScalarUsage, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads(2);
+
+			let pending = BlockLenProposals::<T>::count();
+			if pending != 0 {
+				Self::apply_block_length_proposals(now);
+				weight.saturating_accrue(T::WeightInfo::apply_block_length_proposals(pending));
+			}
+
+			if let Some(config) = BlockLengthController::<T>::get() {
+				// `on_finalize` observation.
+				weight.saturating_accrue(T::WeightInfo::note_scalar_usage());
+				if ScalarUsage::<T>::get().blocks >= config.window {
+					Self::adjust_block_length(&config);
+					weight.saturating_accrue(T::WeightInfo::adjust_block_length());
+				}
+			}
+
+			weight
+		}
+
+		fn on_finalize(_now: BlockNumberFor<T>) {
+			if BlockLengthController::<T>::exists() {
+				Self::note_scalar_usage();
+			}
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates an application key if `key` does not exist yet.
@@ -169,6 +303,7 @@ pub mod pallet {
 
 				Ok(id)
 			})?;
+			AppKeysById::<T>::insert(id, &key);
 
 			Self::deposit_event(Event::ApplicationKeyCreated { key, owner, id });
 			Ok(().into())
@@ -206,26 +341,7 @@ pub mod pallet {
 			ensure_root(origin)?;
 			let rows = BlockLengthRows(rows);
 			let cols = BlockLengthColumns(cols);
-
-			ensure!(
-				rows <= T::MaxBlockRows::get() && cols <= T::MaxBlockCols::get(),
-				Error::<T>::BlockDimensionsOutOfBounds
-			);
-			ensure!(
-				rows >= T::MinBlockRows::get() && cols >= T::MinBlockCols::get(),
-				Error::<T>::BlockDimensionsTooSmall
-			);
-
-			// Check if rows and cols are powers of 2
-			// Check if `rows` or `cols` are a power of 2: they must be nonzero and have no bits in common with `(rows or cols) - 1`.
-			ensure!(
-				rows.0 != 0 && (rows.0 & (rows.0 - 1)) == 0,
-				Error::<T>::NotPowerOfTwo
-			);
-			ensure!(
-				cols.0 != 0 && (cols.0 & (cols.0 - 1)) == 0,
-				Error::<T>::NotPowerOfTwo
-			);
+			let block_length = Self::block_length_of(rows, cols)?;
 
 			let current_block_dimension = DynamicBlockLength::<T>::get();
 			let is_increase =
@@ -235,10 +351,6 @@ pub mod pallet {
 				Error::<T>::InvalidBlockWeightReduction
 			);
 
-			let block_length =
-				BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
-					.map_err(|_| Error::<T>::BlockDimensionsOutOfBounds)?;
-
 			DynamicBlockLength::<T>::put(block_length);
 
 			Self::deposit_event(Event::BlockLengthProposalSubmitted { rows, cols });
@@ -272,6 +384,7 @@ pub mod pallet {
 			AppKeys::<T>::remove(&old_key);
 
 			// Insert the app info under the new key
+			AppKeysById::<T>::insert(app_key_info.id, &new_key);
 			AppKeys::<T>::insert(&new_key, app_key_info);
 
 			Self::deposit_event(Event::ApplicationKeySet { old_key, new_key });
@@ -293,6 +406,118 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Submits only the hash of a blob of `len` bytes.
+		///
+		/// The hash is included in the data root under its own leaf kind, but, unlike
+		/// `submit_data`, it does not take any space in the data matrix.
+		/// It requires `DataRootVersion::V2`, as the `V1` leaf of a hash can be forged by
+		/// `submit_data`.
+		#[pallet::call_index(5)]
+		#[pallet::weight((weight_helper::submit_data_hash::<T>(), DispatchClass::Normal))]
+		pub fn submit_data_hash(
+			origin: OriginFor<T>,
+			data_hash: H256,
+			len: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::DataRootVersion::get() == DataRootVersion::V2,
+				Error::<T>::DataHashRequiresDataRootV2
+			);
+			ensure!(len != 0, Error::<T>::DataCannotBeEmpty);
+
+			Self::deposit_event(Event::DataHashSubmitted {
+				who,
+				data_hash,
+				len,
+			});
+
+			Ok(().into())
+		}
+
+		/// Schedules new block dimensions to be applied at the beginning of block `at`.
+		///
+		/// Dimensions are validated as in `submit_block_length_proposal`, but reductions are
+		/// allowed because they are applied on an empty block.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::schedule_block_length_proposal())]
+		pub fn schedule_block_length_proposal(
+			origin: OriginFor<T>,
+			rows: u32,
+			cols: u32,
+			at: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let rows = BlockLengthRows(rows);
+			let cols = BlockLengthColumns(cols);
+			let _ = Self::block_length_of(rows, cols)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(at > now, Error::<T>::BlockLenProposalInThePast);
+			ensure!(
+				BlockLenProposals::<T>::count() < T::MaxPendingBlockLenProposals::get(),
+				Error::<T>::TooManyPendingBlockLenProposals
+			);
+
+			let id = Self::next_block_length_proposal_id()?;
+			let proposal = BlockLenProposal { rows, cols, at };
+			BlockLenProposals::<T>::insert(&id, proposal);
+
+			Self::deposit_event(Event::BlockLengthProposalScheduled { id, rows, cols, at });
+
+			Ok(().into())
+		}
+
+		/// Cancels a scheduled block length proposal which has not been applied yet.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::cancel_block_length_proposal())]
+		pub fn cancel_block_length_proposal(
+			origin: OriginFor<T>,
+			id: T::BlockLenProposalId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(
+				BlockLenProposals::<T>::contains_key(&id),
+				Error::<T>::UnknownBlockLenProposal
+			);
+
+			BlockLenProposals::<T>::remove(&id);
+
+			Self::deposit_event(Event::BlockLengthProposalCancelled { id });
+
+			Ok(().into())
+		}
+
+		/// Sets, or disables if `None`, the automatic block length controller.
+		///
+		/// When enabled, every `window` blocks, the block dimensions are doubled or halved (at most
+		/// `max_step` times) to move the observed scalar usage towards `target_utilization`,
+		/// always within `MinBlockRows..MaxBlockRows` and `MinBlockCols..MaxBlockCols`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_block_length_controller())]
+		pub fn set_block_length_controller(
+			origin: OriginFor<T>,
+			config: Option<BlockLengthControllerConfig>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			if let Some(config) = config.as_ref() {
+				ensure!(
+					config.window != 0
+						&& config.max_step != 0
+						&& !config.target_utilization.is_zero(),
+					Error::<T>::InvalidBlockLengthControllerConfig
+				);
+			}
+
+			BlockLengthController::<T>::set(config);
+			ScalarUsage::<T>::kill();
+
+			Self::deposit_event(Event::BlockLengthControllerSet { config });
+
+			Ok(().into())
+		}
 	}
 
 	/// Event for the pallet.
@@ -320,6 +545,33 @@ pub mod pallet {
 		SubmitDataFeeModifierSet {
 			value: DispatchFeeModifier,
 		},
+		DataHashSubmitted {
+			who: T::AccountId,
+			data_hash: H256,
+			len: u32,
+		},
+		BlockLengthProposalScheduled {
+			id: T::BlockLenProposalId,
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+			at: BlockNumberFor<T>,
+		},
+		BlockLengthProposalCancelled {
+			id: T::BlockLenProposalId,
+		},
+		BlockLengthProposalApplied {
+			id: T::BlockLenProposalId,
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+		},
+		BlockLengthControllerSet {
+			config: Option<BlockLengthControllerConfig>,
+		},
+		BlockLengthAdjusted {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+			utilization: Perbill,
+		},
 	}
 
 	/// Error for the System pallet
@@ -347,6 +599,16 @@ pub mod pallet {
 		UnknownAppKey,
 		/// Submit block length proposal was made with values not power of 2
 		NotPowerOfTwo,
+		/// The block length proposal is scheduled for the current or a past block.
+		BlockLenProposalInThePast,
+		/// There are already `MaxPendingBlockLenProposals` scheduled proposals.
+		TooManyPendingBlockLenProposals,
+		/// No scheduled block length proposal was found for the given Id.
+		UnknownBlockLenProposal,
+		/// The block length controller window, max step and target utilization must not be zero.
+		InvalidBlockLengthControllerConfig,
+		/// Data hashes can only be submitted while the data root is `DataRootVersion::V2`.
+		DataHashRequiresDataRootV2,
 	}
 
 	#[pallet::genesis_config]
@@ -377,6 +639,7 @@ pub mod pallet {
 					id: AppId(id),
 					owner,
 				};
+				AppKeysById::<T>::insert(value.id, &key);
 				AppKeys::<T>::insert(key, value);
 			}
 		}
@@ -392,6 +655,225 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Returns the application keys whose ID belongs to the `page`-th range of `page_size` IDs.
+	pub fn app_keys(page: u32, page_size: u32) -> Vec<(AppKeyFor<T>, AppKeyInfoFor<T>)> {
+		let start = page.saturating_mul(page_size);
+		let end = start.saturating_add(page_size).min(NextAppId::<T>::get().0);
+
+		(start..end)
+			.filter_map(|id| Self::app_by_id(AppId(id)))
+			.collect()
+	}
+
+	/// Returns the application key and its information for the given application ID.
+	pub fn app_by_id(id: AppId) -> Option<(AppKeyFor<T>, AppKeyInfoFor<T>)> {
+		let key = AppKeysById::<T>::get(id)?;
+		let info = AppKeys::<T>::get(&key)?;
+		Some((key, info))
+	}
+
+	/// Estimates the fee of a `submit_data` extrinsic carrying `data_len` bytes of data and
+	/// whose encoded length is `len`, using the current block length and fee modifier.
+	pub fn estimate_submit_data_fee(
+		data_len: u32,
+		len: u32,
+	) -> SubmitDataFeeEstimate<FeeBalanceOf<T>>
+	where
+		T: TxPaymentConfig,
+		T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	{
+		let (regular_weight, scalar_weight) =
+			weight_helper::submit_data_weights::<T>(data_len.saturated_into());
+		let weight = weight_helper::submit_data::<T>(data_len.saturated_into());
+
+		// Class and fee modifier do not depend on the data.
+		let mut info = Call::<T>::submit_data {
+			data: AppDataFor::<T>::default(),
+		}
+		.get_dispatch_info();
+		info.weight = weight;
+
+		let details =
+			pallet_transaction_payment::Pallet::<T>::compute_fee_details(len, &info, Zero::zero());
+		let total = details.final_fee();
+		let (base_fee, len_fee, adjusted_weight_fee) = details
+			.inclusion_fee
+			.map(|fee| (fee.base_fee, fee.len_fee, fee.adjusted_weight_fee))
+			.unwrap_or_default();
+
+		SubmitDataFeeEstimate {
+			regular_weight,
+			scalar_weight,
+			weight,
+			len,
+			base_fee,
+			len_fee,
+			adjusted_weight_fee,
+			fee_modifier: SubmitDataFeeModifier::<T>::get(),
+			total,
+		}
+	}
+
+	/// Returns the latest available block length proposal ID and increases it.
+	pub fn next_block_length_proposal_id() -> Result<T::BlockLenProposalId, Error<T>> {
+		NextBlockLenProposalId::<T>::try_mutate(|id| {
+			let new_id = id
+				.checked_add(&One::one())
+				.ok_or(Error::<T>::LastBlockLenProposalIdOverflowed)?;
+			Ok(replace(id, new_id))
+		})
+	}
+
+	/// Returns the scheduled block length proposals, sorted by the block they will be applied.
+	pub fn pending_block_length_proposals() -> Vec<(T::BlockLenProposalId, BlockLenProposalFor<T>)>
+	{
+		let mut proposals = BlockLenProposals::<T>::iter().collect::<Vec<_>>();
+		proposals.sort_by(|a, b| a.1.at.cmp(&b.1.at));
+		proposals
+	}
+
+	/// Validates `rows` and `cols` and builds the related block length.
+	fn block_length_of(
+		rows: BlockLengthRows,
+		cols: BlockLengthColumns,
+	) -> Result<BlockLength, Error<T>> {
+		ensure!(
+			rows <= T::MaxBlockRows::get() && cols <= T::MaxBlockCols::get(),
+			Error::<T>::BlockDimensionsOutOfBounds
+		);
+		ensure!(
+			rows >= T::MinBlockRows::get() && cols >= T::MinBlockCols::get(),
+			Error::<T>::BlockDimensionsTooSmall
+		);
+
+		// Check if rows and cols are powers of 2
+		// Check if `rows` or `cols` are a power of 2: they must be nonzero and have no bits in common with `(rows or cols) - 1`.
+		ensure!(
+			rows.0 != 0 && (rows.0 & (rows.0 - 1)) == 0,
+			Error::<T>::NotPowerOfTwo
+		);
+		ensure!(
+			cols.0 != 0 && (cols.0 & (cols.0 - 1)) == 0,
+			Error::<T>::NotPowerOfTwo
+		);
+
+		BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
+			.map_err(|_| Error::<T>::BlockDimensionsOutOfBounds)
+	}
+
+	/// Applies, in scheduled order, every block length proposal whose block is `now` or earlier.
+	/// If several proposals are due, the last scheduled one defines the final dimensions.
+	fn apply_block_length_proposals(now: BlockNumberFor<T>) {
+		let due = Self::pending_block_length_proposals()
+			.into_iter()
+			.filter(|(_, proposal)| proposal.at <= now);
+
+		for (id, proposal) in due {
+			BlockLenProposals::<T>::remove(&id);
+			let BlockLenProposal { rows, cols, .. } = proposal;
+
+			match Self::block_length_of(rows, cols) {
+				Ok(block_length) => {
+					DynamicBlockLength::<T>::put(block_length);
+					Self::deposit_event(Event::BlockLengthProposalApplied { id, rows, cols });
+				},
+				Err(e) => log::warn!(
+					target: LOG_TARGET,
+					"Scheduled block length proposal {id:?} ({rows:?}x{cols:?}) dropped: {e:?}"
+				),
+			}
+		}
+	}
+
+	/// Accumulates the scalars used by DA extrinsics in the current block, and the scalars
+	/// available for them.
+	fn note_scalar_usage() {
+		let block_length = DynamicBlockLength::<T>::get();
+		let max_scalars = block_length.rows.0.saturating_mul(block_length.cols.0);
+		let available = DA_DISPATCH_RATIO * max_scalars;
+		let used = frame_system::AllExtrinsicsLen::<T>::get()
+			.and_then(|len| len.total_num_scalars())
+			.unwrap_or_default();
+
+		ScalarUsage::<T>::mutate(|usage| {
+			usage.blocks = usage.blocks.saturating_add(1);
+			usage.used = usage.used.saturating_add(used.into());
+			usage.available = usage.available.saturating_add(available.into());
+		});
+	}
+
+	/// Moves the block dimensions towards the target utilization using the usage observed in the
+	/// last window, and starts a new window.
+	fn adjust_block_length(config: &BlockLengthControllerConfig) {
+		let usage = ScalarUsage::<T>::take();
+		let utilization = Perbill::from_rational(usage.used, usage.available.max(1));
+
+		let current = DynamicBlockLength::<T>::get();
+		let (rows, cols) =
+			Self::next_block_dimensions(current.rows, current.cols, utilization, config);
+		if rows == current.rows && cols == current.cols {
+			return;
+		}
+
+		match Self::block_length_of(rows, cols) {
+			Ok(block_length) => {
+				DynamicBlockLength::<T>::put(block_length);
+				Self::deposit_event(Event::BlockLengthAdjusted {
+					rows,
+					cols,
+					utilization,
+				});
+			},
+			Err(e) => log::warn!(
+				target: LOG_TARGET,
+				"Block length controller cannot apply {rows:?}x{cols:?}: {e:?}"
+			),
+		}
+	}
+
+	/// Computes the next block dimensions for the given `utilization`.
+	///
+	/// Each step doubles (or halves) the rows, or the cols once the rows reach their bound,
+	/// which halves (or doubles) the utilization. Both directions change the rows first, so
+	/// shrinking undoes the growth of the rows before touching the cols.
+	///  - It grows while the utilization is above the target.
+	///  - It shrinks while the utilization after shrinking would still be below or at the target.
+	pub fn next_block_dimensions(
+		mut rows: BlockLengthRows,
+		mut cols: BlockLengthColumns,
+		utilization: Perbill,
+		config: &BlockLengthControllerConfig,
+	) -> (BlockLengthRows, BlockLengthColumns) {
+		let target = config.target_utilization.deconstruct();
+		let mut utilization = utilization.deconstruct();
+
+		for _ in 0..config.max_step {
+			if utilization > target {
+				if rows.0.saturating_mul(2) <= T::MaxBlockRows::get().0 {
+					rows.0 *= 2;
+				} else if cols.0.saturating_mul(2) <= T::MaxBlockCols::get().0 {
+					cols.0 *= 2;
+				} else {
+					break;
+				}
+				utilization /= 2;
+			} else if utilization.saturating_mul(2) <= target {
+				if rows.0 / 2 >= T::MinBlockRows::get().0 {
+					rows.0 /= 2;
+				} else if cols.0 / 2 >= T::MinBlockCols::get().0 {
+					cols.0 /= 2;
+				} else {
+					break;
+				}
+				utilization = utilization.saturating_mul(2);
+			} else {
+				break;
+			}
+		}
+
+		(rows, cols)
+	}
+
 	/// Check if the block weight is acceptable to execute the extrinsic
 	/// We check the current normal ratio weight, if it's too high, it means we won't reduce the block size
 	pub fn is_block_weight_acceptable() -> bool {
@@ -412,6 +894,15 @@ pub mod weight_helper {
 
 	/// Weight for `dataAvailability::submit_data`.
 	pub fn submit_data<T: Config>(data_len: usize) -> Weight {
+		let (regular_weight, scalar_based_weight) = submit_data_weights::<T>(data_len);
+
+		// We return the biggest value between the regular weight and scalar based weight.
+		// I cannot think of a case where regular weight > matrix based weight.
+		scalar_based_weight.max(regular_weight)
+	}
+
+	/// Regular and scalar based weights for `dataAvailability::submit_data`.
+	pub fn submit_data_weights<T: Config>(data_len: usize) -> (Weight, Weight) {
 		/* Compute regular substrate weight. */
 		let data_len: u32 = data_len.saturated_into();
 		let data_prefix_len: u32 =
@@ -450,9 +941,14 @@ pub mod weight_helper {
 		let ref_time = data_scalar_ratio * max_weight_normal_ratio;
 		let scalar_based_weight = Weight::from_parts(ref_time, regular_weight.proof_size());
 
-		// We return the biggest value between the regular weight and scalar based weight.
-		// I cannot think of a case where regular weight > matrix based weight.
-		scalar_based_weight.max(regular_weight)
+		(regular_weight, scalar_based_weight)
+	}
+
+	/// Weight for `dataAvailability::submit_data_hash`.
+	/// It only pays for one extra leaf in the data root, as no space is used in the matrix.
+	pub fn submit_data_hash<T: Config>() -> Weight {
+		let leaf_len: u32 = H256::len_bytes().saturated_into();
+		T::WeightInfo::submit_data_hash().saturating_add(T::WeightInfo::data_root(leaf_len))
 	}
 
 	fn compact_len(value: &u32) -> Option<u32> {
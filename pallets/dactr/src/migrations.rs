@@ -0,0 +1,68 @@
+use crate::{AppKeys, AppKeysById, Config, Pallet, LOG_TARGET};
+
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use {
+	codec::{Decode, Encode},
+	frame_support::ensure,
+	sp_runtime::TryRuntimeError,
+	sp_std::vec::Vec,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// Populates the `AppKeysById` reverse index from the existing `AppKeys`.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping `AppKeysById` migration, on-chain storage version is {on_chain:?}"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut migrated = 0u64;
+			for (key, info) in AppKeys::<T>::iter() {
+				AppKeysById::<T>::insert(info.id, key);
+				migrated = migrated.saturating_add(1);
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Migrated {migrated} application keys into `AppKeysById`"
+			);
+			T::DbWeight::get().reads_writes(migrated.saturating_add(1), migrated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let app_keys = AppKeys::<T>::iter().count() as u32;
+			Ok(app_keys.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let app_keys = u32::decode(&mut &state[..])
+				.map_err(|_| "Invalid `AppKeys` count in pre-upgrade state")?;
+			ensure!(
+				AppKeysById::<T>::iter().count() as u32 == app_keys,
+				"`AppKeysById` does not index every application key"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"DA control storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
@@ -35,6 +35,7 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: BlockNumber = 250;
 	pub static ExistentialDeposit: u64 = 1;
+	pub static MockDataRootVersion: DataRootVersion = DataRootVersion::V2;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
@@ -46,6 +47,7 @@ impl frame_system::Config for Test {
 	type PalletInfo = PalletInfo;
 	type Randomness = TestRandomness<Test>;
 	type Extrinsic = Extrinsic;
+	type DataRootVersion = MockDataRootVersion;
 }
 
 parameter_types! {
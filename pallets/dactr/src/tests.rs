@@ -1,13 +1,18 @@
-use avail_core::{BlockLengthColumns, BlockLengthRows, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO};
+use avail_base::header_extension::DataRootVersion;
+use avail_core::{AppId, BlockLengthColumns, BlockLengthRows, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO};
 use frame_support::{assert_noop, assert_ok, error::BadOrigin};
 use frame_system::{limits::BlockLength, RawOrigin};
 use sp_core::H256;
 
 use crate::config_preludes::{
-	MaxAppDataLength, MaxBlockCols, MaxBlockRows, MinBlockCols, MinBlockRows,
+	MaxAppDataLength, MaxBlockCols, MaxBlockRows, MaxPendingBlockLenProposals, MinBlockCols,
+	MinBlockRows,
 };
 use crate::{
-	mock::{new_test_ext, DataAvailability, RuntimeEvent, RuntimeOrigin, System, Test},
+	mock::{
+		new_test_ext, DataAvailability, MockDataRootVersion, RuntimeEvent, RuntimeOrigin, System,
+		Test,
+	},
 	AppDataFor, AppKeyFor, AppKeyInfoFor, Event,
 };
 
@@ -37,6 +42,10 @@ mod create_application_key {
 					owner: ALICE
 				})
 			);
+			assert_eq!(
+				DataAvailability::application_key_by_id(new_id),
+				Some(new_key.clone())
+			);
 
 			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyCreated {
 				key: new_key,
@@ -118,6 +127,50 @@ mod submit_data {
 	}
 }
 
+mod submit_data_hash {
+	use super::*;
+
+	#[test]
+	fn submit_data_hash() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let data_hash = H256(sp_io::hashing::keccak_256(b"Avail"));
+
+			assert_ok!(DataAvailability::submit_data_hash(alice, data_hash, 5));
+
+			let event = RuntimeEvent::DataAvailability(Event::DataHashSubmitted {
+				who: ALICE,
+				data_hash,
+				len: 5,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn len_cannot_be_zero() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let data_hash = H256(sp_io::hashing::keccak_256(b""));
+
+			let err = DataAvailability::submit_data_hash(alice, data_hash, 0);
+			assert_noop!(err, Error::DataCannotBeEmpty);
+		})
+	}
+
+	#[test]
+	fn requires_data_root_v2() {
+		new_test_ext().execute_with(|| {
+			MockDataRootVersion::set(DataRootVersion::V1);
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let data_hash = H256(sp_io::hashing::keccak_256(b"Avail"));
+
+			let err = DataAvailability::submit_data_hash(alice, data_hash, 5);
+			assert_noop!(err, Error::DataHashRequiresDataRootV2);
+		})
+	}
+}
+
 mod submit_block_length_proposal {
 	use super::*;
 
@@ -251,6 +304,290 @@ mod submit_block_length_proposal {
 	}
 }
 
+mod schedule_block_length_proposal {
+	use super::*;
+	use crate::{BlockLenProposal, BlockLenProposals};
+	use frame_support::traits::Hooks;
+
+	#[test]
+	fn schedule_block_length_proposal() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let rows = BlockLengthRows(128);
+			let cols = BlockLengthColumns(128);
+			let old_block_length = System::block_length();
+
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root, rows.0, cols.0, 5
+			));
+			assert_eq!(System::block_length(), old_block_length);
+			assert_eq!(
+				DataAvailability::pending_block_length_proposals(),
+				vec![(0, BlockLenProposal { rows, cols, at: 5 })]
+			);
+
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthProposalScheduled {
+				id: 0,
+				rows,
+				cols,
+				at: 5,
+			});
+			System::assert_last_event(event);
+
+			// Nothing happens before the scheduled block.
+			DataAvailability::on_initialize(4);
+			assert_eq!(System::block_length(), old_block_length);
+
+			DataAvailability::on_initialize(5);
+			let new_block_length =
+				BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO)
+					.unwrap();
+			assert_eq!(System::block_length(), new_block_length);
+			assert_eq!(BlockLenProposals::<Test>::count(), 0);
+
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthProposalApplied {
+				id: 0,
+				rows,
+				cols,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn last_scheduled_proposal_wins() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root.clone(),
+				256,
+				256,
+				3
+			));
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root, 64, 64, 2
+			));
+
+			DataAvailability::on_initialize(3);
+			let block_length = BlockLength::with_normal_ratio(
+				BlockLengthRows(256),
+				BlockLengthColumns(256),
+				BLOCK_CHUNK_SIZE,
+				DA_DISPATCH_RATIO,
+			)
+			.unwrap();
+			assert_eq!(System::block_length(), block_length);
+			assert!(DataAvailability::pending_block_length_proposals().is_empty());
+		})
+	}
+
+	#[test]
+	fn cancel_block_length_proposal() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let old_block_length = System::block_length();
+
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root.clone(),
+				128,
+				128,
+				5
+			));
+			assert_ok!(DataAvailability::cancel_block_length_proposal(
+				root.clone(),
+				0
+			));
+			System::assert_last_event(RuntimeEvent::DataAvailability(
+				Event::BlockLengthProposalCancelled { id: 0 },
+			));
+
+			DataAvailability::on_initialize(5);
+			assert_eq!(System::block_length(), old_block_length);
+
+			let err = DataAvailability::cancel_block_length_proposal(root, 0);
+			assert_noop!(err, Error::UnknownBlockLenProposal);
+		})
+	}
+
+	#[test]
+	fn bad_origin() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::schedule_block_length_proposal(alice.clone(), 128, 128, 5);
+			assert_noop!(err, BadOrigin);
+
+			let err = DataAvailability::cancel_block_length_proposal(alice, 0);
+			assert_noop!(err, BadOrigin);
+		})
+	}
+
+	#[test]
+	fn invalid_proposals() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			let err = DataAvailability::schedule_block_length_proposal(root.clone(), 128, 128, 1);
+			assert_noop!(err, Error::BlockLenProposalInThePast);
+
+			let err = DataAvailability::schedule_block_length_proposal(root.clone(), 118, 128, 5);
+			assert_noop!(err, Error::NotPowerOfTwo);
+
+			let rows = MaxBlockRows::get().0 + 1;
+			let err = DataAvailability::schedule_block_length_proposal(root, rows, 128, 5);
+			assert_noop!(err, Error::BlockDimensionsOutOfBounds);
+		})
+	}
+
+	#[test]
+	fn too_many_pending_proposals() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			for _ in 0..MaxPendingBlockLenProposals::get() {
+				assert_ok!(DataAvailability::schedule_block_length_proposal(
+					root.clone(),
+					128,
+					128,
+					5
+				));
+			}
+
+			let err = DataAvailability::schedule_block_length_proposal(root, 128, 128, 5);
+			assert_noop!(err, Error::TooManyPendingBlockLenProposals);
+		})
+	}
+}
+
+mod block_length_controller {
+	use super::*;
+	use crate::{BlockLengthControllerConfig, BlockScalarUsage, ScalarUsage};
+	use frame_support::traits::Hooks;
+	use sp_runtime::Perbill;
+
+	fn config(target: u32, window: u32, max_step: u32) -> BlockLengthControllerConfig {
+		BlockLengthControllerConfig {
+			target_utilization: Perbill::from_percent(target),
+			window,
+			max_step,
+		}
+	}
+
+	fn dims(rows: u32, cols: u32) -> (BlockLengthRows, BlockLengthColumns) {
+		(BlockLengthRows(rows), BlockLengthColumns(cols))
+	}
+
+	#[test]
+	fn next_block_dimensions() {
+		let next = |rows, cols, utilization, config| {
+			DataAvailability::next_block_dimensions(
+				BlockLengthRows(rows),
+				BlockLengthColumns(cols),
+				Perbill::from_percent(utilization),
+				&config,
+			)
+		};
+
+		// Within the target band, nothing changes.
+		assert_eq!(next(256, 256, 50, config(50, 1, 4)), dims(256, 256));
+		assert_eq!(next(256, 256, 30, config(50, 1, 4)), dims(256, 256));
+		// Grows rows first, limited by `max_step`.
+		assert_eq!(next(256, 256, 100, config(50, 1, 1)), dims(512, 256));
+		assert_eq!(next(256, 256, 100, config(10, 1, 2)), dims(1024, 256));
+		// Then cols, once rows reached `MaxBlockRows`.
+		assert_eq!(next(1024, 256, 100, config(10, 1, 2)), dims(1024, 1024));
+		assert_eq!(next(1024, 1024, 100, config(10, 1, 2)), dims(1024, 1024));
+		// Shrinks rows first, then cols once rows reached `MinBlockRows`.
+		assert_eq!(next(256, 256, 10, config(50, 1, 1)), dims(128, 256));
+		assert_eq!(next(64, 64, 1, config(50, 1, 2)), dims(32, 32));
+		assert_eq!(next(32, 32, 1, config(50, 1, 4)), dims(32, 32));
+	}
+
+	#[test]
+	fn grow_then_shrink_restores_block_dimensions() {
+		let next = |(rows, cols): (BlockLengthRows, BlockLengthColumns), utilization, config| {
+			DataAvailability::next_block_dimensions(
+				rows,
+				cols,
+				Perbill::from_percent(utilization),
+				&config,
+			)
+		};
+
+		let original = dims(128, 256);
+		let grown = next(original, 100, config(10, 1, 2));
+		assert_eq!(grown, dims(512, 256));
+		assert_eq!(next(grown, 2, config(10, 1, 2)), original);
+
+		let grown = next(original, 100, config(50, 1, 1));
+		assert_eq!(next(grown, 25, config(50, 1, 1)), original);
+	}
+
+	#[test]
+	fn set_block_length_controller() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::set_block_length_controller(alice, None);
+			assert_noop!(err, BadOrigin);
+
+			let err =
+				DataAvailability::set_block_length_controller(root.clone(), Some(config(50, 0, 1)));
+			assert_noop!(err, Error::InvalidBlockLengthControllerConfig);
+
+			assert_ok!(DataAvailability::set_block_length_controller(
+				root.clone(),
+				Some(config(50, 2, 1))
+			));
+			System::assert_last_event(RuntimeEvent::DataAvailability(
+				Event::BlockLengthControllerSet {
+					config: Some(config(50, 2, 1)),
+				},
+			));
+
+			assert_ok!(DataAvailability::set_block_length_controller(root, None));
+			DataAvailability::on_finalize(1);
+			assert_eq!(ScalarUsage::<Test>::get(), BlockScalarUsage::default());
+		})
+	}
+
+	#[test]
+	fn shrinks_empty_blocks_after_window() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			assert_ok!(DataAvailability::submit_block_length_proposal(
+				root.clone(),
+				256,
+				256
+			));
+			assert_ok!(DataAvailability::set_block_length_controller(
+				root,
+				Some(config(50, 2, 1))
+			));
+
+			DataAvailability::on_finalize(1);
+			DataAvailability::on_initialize(2);
+			assert_eq!(System::block_length().cols, BlockLengthColumns(256));
+
+			DataAvailability::on_finalize(2);
+			assert_eq!(ScalarUsage::<Test>::get().blocks, 2);
+			DataAvailability::on_initialize(3);
+
+			let (rows, cols) = dims(128, 256);
+			assert_eq!(System::block_length().rows, rows);
+			assert_eq!(System::block_length().cols, cols);
+			assert_eq!(ScalarUsage::<Test>::get(), BlockScalarUsage::default());
+			System::assert_last_event(RuntimeEvent::DataAvailability(Event::BlockLengthAdjusted {
+				rows,
+				cols,
+				utilization: Perbill::from_percent(0),
+			}));
+		})
+	}
+}
+
 mod set_application_key {
 	use super::*;
 
@@ -271,6 +608,10 @@ mod set_application_key {
 			));
 
 			assert_eq!(DataAvailability::application_key(&new_key), old_info);
+			assert_eq!(
+				DataAvailability::application_key_by_id(AppId(0)),
+				Some(new_key.clone())
+			);
 
 			let event =
 				RuntimeEvent::DataAvailability(Event::ApplicationKeySet { old_key, new_key });
@@ -324,6 +665,74 @@ mod set_application_key {
 	}
 }
 
+mod app_keys {
+	use super::*;
+
+	fn app_key(key: &[u8], owner: u64, id: u32) -> (AppKeyFor<Test>, AppKeyInfoFor<Test>) {
+		let key = AppKeyFor::<Test>::try_from(key.to_vec()).unwrap();
+		(key, AppKeyInfoFor::<Test>::new(owner, AppId(id)))
+	}
+
+	#[test]
+	fn app_by_id() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				DataAvailability::app_by_id(AppId(1)),
+				Some(app_key(b"Reserved-1", 2, 1))
+			);
+			assert_eq!(DataAvailability::app_by_id(AppId(3)), None);
+		})
+	}
+
+	#[test]
+	fn app_keys_are_paginated_by_id() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let new_key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+			assert_ok!(DataAvailability::create_application_key(alice, new_key));
+
+			assert_eq!(
+				DataAvailability::app_keys(0, 3),
+				vec![
+					app_key(b"Avail", 1, 0),
+					app_key(b"Reserved-1", 2, 1),
+					app_key(b"A Brave New World", 2, 2),
+				]
+			);
+			assert_eq!(
+				DataAvailability::app_keys(1, 3),
+				vec![app_key(b"New App", ALICE, 3)]
+			);
+			assert_eq!(DataAvailability::app_keys(2, 3), vec![]);
+			assert_eq!(DataAvailability::app_keys(u32::MAX, u32::MAX), vec![]);
+		})
+	}
+
+	#[test]
+	fn migration_populates_reverse_index() {
+		use crate::{migrations::v1::MigrateToV1, AppKeysById, Pallet};
+		use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+		new_test_ext().execute_with(|| {
+			let _ = AppKeysById::<Test>::clear(u32::MAX, None);
+			StorageVersion::new(0).put::<Pallet<Test>>();
+			assert_eq!(DataAvailability::app_by_id(AppId(2)), None);
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+			assert_eq!(
+				DataAvailability::app_keys(0, 10),
+				vec![
+					app_key(b"Avail", 1, 0),
+					app_key(b"Reserved-1", 2, 1),
+					app_key(b"A Brave New World", 2, 2),
+				]
+			);
+		})
+	}
+}
+
 mod set_submit_data_fee_modifier {
 	use super::*;
 	use crate::SubmitDataFeeModifier;
@@ -369,3 +778,50 @@ mod set_submit_data_fee_modifier {
 		})
 	}
 }
+
+mod estimate_submit_data_fee {
+	use super::*;
+	use crate::{mock::TransactionPayment, weight_helper, SubmitDataFeeModifier};
+	use frame_support::dispatch::{DispatchFeeModifier, DispatchInfo};
+
+	#[test]
+	fn estimate_matches_computed_fee() {
+		new_test_ext().execute_with(|| {
+			let estimate = DataAvailability::estimate_submit_data_fee(1_000, 1_100);
+			let (regular_weight, scalar_weight) = weight_helper::submit_data_weights::<Test>(1_000);
+
+			assert_eq!(estimate.regular_weight, regular_weight);
+			assert_eq!(estimate.scalar_weight, scalar_weight);
+			assert_eq!(estimate.weight, regular_weight.max(scalar_weight));
+			assert_eq!(estimate.len, 1_100);
+			assert_eq!(
+				estimate.total,
+				estimate.base_fee + estimate.len_fee + estimate.adjusted_weight_fee
+			);
+
+			let info = DispatchInfo {
+				weight: estimate.weight,
+				..Default::default()
+			};
+			assert_eq!(
+				estimate.total,
+				TransactionPayment::compute_fee(1_100, &info, 0)
+			);
+		})
+	}
+
+	#[test]
+	fn fee_modifier_is_applied() {
+		new_test_ext().execute_with(|| {
+			let modifier = DispatchFeeModifier {
+				weight_maximum_fee: Some(1),
+				..Default::default()
+			};
+			SubmitDataFeeModifier::<Test>::put(modifier);
+
+			let estimate = DataAvailability::estimate_submit_data_fee(1_000, 1_100);
+			assert_eq!(estimate.fee_modifier, modifier);
+			assert!(estimate.adjusted_weight_fee <= 1);
+		})
+	}
+}
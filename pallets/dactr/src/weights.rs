@@ -22,6 +22,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ip-172-31-12-189`, CPU: `Intel(R) Xeon(R) Platinum 8175M CPU @ 2.50GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! Entries commented as placeholders were not produced by the benchmark CLI and must be
+//! regenerated with `benchmarking.rs`.
 
 // Executed Command:
 // ./target/release/avail-node
@@ -57,6 +60,13 @@ pub trait WeightInfo {
 	fn data_root(i: u32, ) -> Weight;
 	fn data_root_batch(i: u32, ) -> Weight;
 	fn set_submit_data_fee_modifier() -> Weight;
+	fn submit_data_hash() -> Weight;
+	fn schedule_block_length_proposal() -> Weight;
+	fn cancel_block_length_proposal() -> Weight;
+	fn apply_block_length_proposals(p: u32, ) -> Weight;
+	fn set_block_length_controller() -> Weight;
+	fn note_scalar_usage() -> Weight;
+	fn adjust_block_length() -> Weight;
 }
 
 /// Weights for `da_control` using the Avail node and recommended hardware.
@@ -66,6 +76,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	/// Storage: `DataAvailability::NextAppId` (r:1 w:1)
 	/// Proof: `DataAvailability::NextAppId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppKeysById` (r:0 w:1)
+	/// Proof: `DataAvailability::AppKeysById` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	fn create_application_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `312`
@@ -73,7 +85,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 24_049_000 picoseconds.
 		Weight::from_parts(24_820_000, 3583)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			// Placeholder: the `AppKeysById` write is added by hand, regenerate with `benchmarking.rs`.
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `System::DynamicBlockLength` (r:1 w:1)
 	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
@@ -98,6 +111,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `DataAvailability::AppKeys` (r:2 w:2)
 	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppKeysById` (r:0 w:1)
+	/// Proof: `DataAvailability::AppKeysById` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	fn set_application_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `377`
@@ -105,7 +120,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 32_681_000 picoseconds.
 		Weight::from_parts(33_451_000, 6176)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			// Placeholder: the `AppKeysById` write is added by hand, regenerate with `benchmarking.rs`.
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// The range of component `i` is `[0, 524288]`.
 	fn data_root(i: u32, ) -> Weight {
@@ -137,6 +153,81 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(3_921_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	fn submit_data_hash() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(11_388_000, 0)
+	}
+	/// Storage: `DataAvailability::CounterForBlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::CounterForBlockLenProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::NextBlockLenProposalId` (r:1 w:1)
+	/// Proof: `DataAvailability::NextBlockLenProposalId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::BlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::BlockLenProposals` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn schedule_block_length_proposal() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(15_733_000, 3489)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DataAvailability::BlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::BlockLenProposals` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::CounterForBlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::CounterForBlockLenProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn cancel_block_length_proposal() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(14_802_000, 3489)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DataAvailability::CounterForBlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::CounterForBlockLenProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::BlockLenProposals` (r:17 w:16)
+	/// Proof: `DataAvailability::BlockLenProposals` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:0 w:1)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 16]`.
+	fn apply_block_length_proposals(p: u32, ) -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(6_125_220, 1489)
+			// Standard Error: 6_218
+			.saturating_add(Weight::from_parts(7_496_010, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2499).saturating_mul(p.into()))
+	}
+	/// Storage: `DataAvailability::BlockLengthController` (r:0 w:1)
+	/// Proof: `DataAvailability::BlockLengthController` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::ScalarUsage` (r:0 w:1)
+	/// Proof: `DataAvailability::ScalarUsage` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn set_block_length_controller() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(7_764_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::DynamicBlockLength` (r:1 w:0)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// Storage: `System::AllExtrinsicsLen` (r:1 w:0)
+	/// Proof: `System::AllExtrinsicsLen` (`max_values`: Some(1), `max_size`: Some(5134), added: 5629, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::ScalarUsage` (r:1 w:1)
+	/// Proof: `DataAvailability::ScalarUsage` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn note_scalar_usage() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(14_410_000, 6619)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DataAvailability::ScalarUsage` (r:1 w:1)
+	/// Proof: `DataAvailability::ScalarUsage` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:1 w:1)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	fn adjust_block_length() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(12_691_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -145,6 +236,8 @@ impl WeightInfo for () {
 	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	/// Storage: `DataAvailability::NextAppId` (r:1 w:1)
 	/// Proof: `DataAvailability::NextAppId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppKeysById` (r:0 w:1)
+	/// Proof: `DataAvailability::AppKeysById` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	fn create_application_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `312`
@@ -152,7 +245,8 @@ impl WeightInfo for () {
 		// Minimum execution time: 24_049_000 picoseconds.
 		Weight::from_parts(24_820_000, 3583)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			// Placeholder: the `AppKeysById` write is added by hand, regenerate with `benchmarking.rs`.
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `System::DynamicBlockLength` (r:1 w:1)
 	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
@@ -177,6 +271,8 @@ impl WeightInfo for () {
 	}
 	/// Storage: `DataAvailability::AppKeys` (r:2 w:2)
 	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppKeysById` (r:0 w:1)
+	/// Proof: `DataAvailability::AppKeysById` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	fn set_application_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `377`
@@ -184,7 +280,8 @@ impl WeightInfo for () {
 		// Minimum execution time: 32_681_000 picoseconds.
 		Weight::from_parts(33_451_000, 6176)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			// Placeholder: the `AppKeysById` write is added by hand, regenerate with `benchmarking.rs`.
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// The range of component `i` is `[0, 524288]`.
 	fn data_root(i: u32, ) -> Weight {
@@ -216,4 +313,79 @@ impl WeightInfo for () {
 		Weight::from_parts(3_921_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	fn submit_data_hash() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(11_388_000, 0)
+	}
+	/// Storage: `DataAvailability::CounterForBlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::CounterForBlockLenProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::NextBlockLenProposalId` (r:1 w:1)
+	/// Proof: `DataAvailability::NextBlockLenProposalId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::BlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::BlockLenProposals` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn schedule_block_length_proposal() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(15_733_000, 3489)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DataAvailability::BlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::BlockLenProposals` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::CounterForBlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::CounterForBlockLenProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn cancel_block_length_proposal() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(14_802_000, 3489)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DataAvailability::CounterForBlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::CounterForBlockLenProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::BlockLenProposals` (r:17 w:16)
+	/// Proof: `DataAvailability::BlockLenProposals` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:0 w:1)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 16]`.
+	fn apply_block_length_proposals(p: u32, ) -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(6_125_220, 1489)
+			// Standard Error: 6_218
+			.saturating_add(Weight::from_parts(7_496_010, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2499).saturating_mul(p.into()))
+	}
+	/// Storage: `DataAvailability::BlockLengthController` (r:0 w:1)
+	/// Proof: `DataAvailability::BlockLengthController` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::ScalarUsage` (r:0 w:1)
+	/// Proof: `DataAvailability::ScalarUsage` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn set_block_length_controller() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(7_764_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::DynamicBlockLength` (r:1 w:0)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// Storage: `System::AllExtrinsicsLen` (r:1 w:0)
+	/// Proof: `System::AllExtrinsicsLen` (`max_values`: Some(1), `max_size`: Some(5134), added: 5629, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::ScalarUsage` (r:1 w:1)
+	/// Proof: `DataAvailability::ScalarUsage` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn note_scalar_usage() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(14_410_000, 6619)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DataAvailability::ScalarUsage` (r:1 w:1)
+	/// Proof: `DataAvailability::ScalarUsage` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:1 w:1)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	fn adjust_block_length() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(12_691_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -154,6 +154,7 @@ impl frame_system::Config for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
 	type SS58Prefix = ();
 	type HeaderExtensionDataFilter = ();
+	type DataRootVersion = ();
 	type SystemWeightInfo = ();
 	type Version = RuntimeVersion;
 }
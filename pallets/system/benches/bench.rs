@@ -119,6 +119,7 @@ impl frame_system::Config for Runtime {
 	type Extrinsic = UncheckedExtrinsic;
 	type MaxDiffAppIdPerBlock = ConstU32<1_024>;
 	type MaxTxPerAppIdPerBlock = ConstU32<8_192>;
+	type DataRootVersion = ();
 }
 
 impl module::Config for Runtime {
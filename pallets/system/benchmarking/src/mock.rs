@@ -51,6 +51,7 @@ impl frame_system::Config for Test {
 	type MaxDiffAppIdPerBlock = ConstU32<1_024>;
 	type MaxTxPerAppIdPerBlock = ConstU32<8_192>;
 	type HeaderExtensionDataFilter = ();
+	type DataRootVersion = ();
 	type Extrinsic = Extrinsic;
 }
 
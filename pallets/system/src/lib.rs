@@ -98,7 +98,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(unused_extern_crates)]
 
-use avail_base::{HeaderExtensionBuilderData, HeaderExtensionDataFilter};
+use avail_base::{
+	header_extension::DataRootVersion, HeaderExtensionBuilderData, HeaderExtensionDataFilter,
+};
 use avail_core::{
 	ensure,
 	header::{Header as DaHeader, HeaderExtension},
@@ -316,6 +318,7 @@ pub mod pallet {
 			type MaxDiffAppIdPerBlock = ConstU32<1_024>;
 			type MaxTxPerAppIdPerBlock = ConstU32<8_192>;
 			type HeaderExtensionDataFilter = ();
+			type DataRootVersion = ();
 		}
 
 		/// Default configurations of this pallet in a solo-chain environment.
@@ -415,6 +418,7 @@ pub mod pallet {
 			type MaxDiffAppIdPerBlock = ConstU32<1_024>;
 			type MaxTxPerAppIdPerBlock = ConstU32<8_192>;
 			type HeaderExtensionDataFilter = ();
+			type DataRootVersion = ();
 		}
 
 		/// Default configurations of this pallet in a relay-chain environment.
@@ -627,6 +631,12 @@ pub mod pallet {
 		/// a transaction is validated (see `CheckAppId` signed extension).
 		#[pallet::constant]
 		type MaxTxPerAppIdPerBlock: Get<u32>;
+
+		/// Layout of the submitted data sub-trie committed in the data root of the header.
+		/// Changing it changes the data root of every block built afterwards, so it must only be
+		/// switched along with a runtime upgrade.
+		#[pallet::constant]
+		type DataRootVersion: Get<DataRootVersion>;
 	}
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
@@ -1826,6 +1836,10 @@ impl<T: Config> Pallet<T> {
 		// stay to be inspected by the client and will be cleared by `Self::initialize`.
 		let number = <Number<T>>::get();
 		let parent_hash = <ParentHash<T>>::get();
+		// Signal the layout of the data root, which the header extension cannot carry.
+		if let Some(item) = T::DataRootVersion::get().digest_item() {
+			Self::deposit_log(item);
+		}
 		let digest = <Digest<T>>::get();
 		let block_number: u32 = number
 			.try_into()
@@ -1864,7 +1878,7 @@ impl<T: Config> Pallet<T> {
 
 		let extension = native::hosted_header_builder::da::HeaderExtensionBuilder::<T>::build(
 			header_extension_builder_data.to_app_extrinsics(),
-			header_extension_builder_data.data_root(),
+			header_extension_builder_data.data_root_of(T::DataRootVersion::get()),
 			block_length,
 			number.unique_saturated_into(),
 		);
@@ -120,6 +120,7 @@ impl Config for Test {
 	type Extrinsic = Extrinsic;
 	type MaxDiffAppIdPerBlock = ConstU32<1_024>;
 	type MaxTxPerAppIdPerBlock = ConstU32<8_192>;
+	type DataRootVersion = ();
 }
 
 #[allow(dead_code)]
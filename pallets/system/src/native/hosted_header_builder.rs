@@ -86,6 +86,20 @@ pub trait HeaderExtensionBuilder {
 	}
 }
 
+/// Runs `build` on the header builder pool, and records its duration for the build time
+/// estimation of block authoring.
+#[cfg(feature = "std")]
+fn build_on_pool(
+	submitted: Vec<AppExtrinsic>,
+	build: impl FnOnce(Vec<AppExtrinsic>) -> HeaderExtension + Send,
+) -> HeaderExtension {
+	let data_len = submitted.iter().map(|xt| xt.data.len()).sum();
+	let start = std::time::Instant::now();
+	let extension = avail_base::header_builder::install(|| build(submitted));
+	avail_base::header_builder::observe_build(data_len, start.elapsed());
+	extension
+}
+
 /// Hosted function to build the header using `kate` commitments.
 #[runtime_interface]
 pub trait HostedHeaderBuilder {
@@ -99,14 +113,16 @@ pub trait HostedHeaderBuilder {
 		block_number: u32,
 		seed: Seed,
 	) -> HeaderExtension {
-		crate::native::build_extension_v1::build_extension(
-			submitted,
-			data_root,
-			block_length,
-			block_number,
-			seed,
-			HeaderVersion::V3,
-		)
+		build_on_pool(submitted, |submitted| {
+			crate::native::build_extension_v1::build_extension(
+				submitted,
+				data_root,
+				block_length,
+				block_number,
+				seed,
+				HeaderVersion::V3,
+			)
+		})
 	}
 
 	/// Note: Whenever a new header version is introduced, ensure to create a corresponding version
@@ -119,13 +135,15 @@ pub trait HostedHeaderBuilder {
 		block_number: u32,
 		seed: Seed,
 	) -> HeaderExtension {
-		crate::native::build_extension_v2::build_extension(
-			submitted,
-			data_root,
-			block_length,
-			block_number,
-			seed,
-			HeaderVersion::V3,
-		)
+		build_on_pool(submitted, |submitted| {
+			crate::native::build_extension_v2::build_extension(
+				submitted,
+				data_root,
+				block_length,
+				block_number,
+				seed,
+				HeaderVersion::V3,
+			)
+		})
 	}
 }
@@ -1,10 +1,13 @@
 use crate::{
-	state::Configuration, BalanceOf, Call, Config, ConfigurationStorage, ExecutionStateRoots,
-	FunctionIds, FunctionInput, FunctionOutput, FunctionProof, Headers, Pallet, ProofInput,
-	PublicValuesInput, RotateVerificationKey, StepVerificationKey, Updater, ValidProof,
+	state::Configuration, AssetIdOf, AssetTransferMode, BalanceOf, BridgedAsset, BridgedAssetOf,
+	Call, Config, ConfigurationStorage, ExecutionStateRoots, FunctionIds, FunctionInput,
+	FunctionOutput, FunctionProof, FunctionVerifier, FunctionVerifiers, Headers, Pallet,
+	ProofInput, ProofSystem, PublicValuesInput, RotateVerificationKey, StepVerificationKey,
+	UpdaterRotation, Updaters, ValidProof,
 };
 use avail_core::data_proof::BOUNDED_DATA_MAX_LENGTH;
 use avail_core::data_proof::{AddressedMessage, Message};
+use codec::Decode;
 use frame_benchmarking::{
 	impl_benchmark_test_suite, v2::benchmarks, whitelisted_caller, BenchmarkError,
 };
@@ -13,10 +16,12 @@ use frame_support::{traits::Currency, BoundedVec};
 use frame_system::RawOrigin;
 use hex_literal::hex;
 use sp_core::{Get, H256};
-use sp_runtime::traits::Bounded;
-use sp_std::vec;
+use sp_runtime::traits::{Bounded, TrailingZeroInput};
+use sp_std::{vec, vec::Vec};
 
 const ACCOUNT1: [u8; 32] = [2u8; 32];
+/// Source chain domain whose light client is benchmarked.
+const DOMAIN: u32 = 2;
 pub const STEP_FUNCTION_ID: H256 = H256(hex!(
 	"af44af6890508b3b7f6910d4a4570a0d524769a23ce340b2c7400e140ad168ab"
 ));
@@ -175,8 +180,16 @@ const SP1_VERIFICATION_KEY: [u8; 32] =
 )]
 mod benchmarks {
 	use super::*;
-	use crate::{Broadcasters, Head, SP1VerificationKey, SyncCommitteeHashes};
+	use crate::{
+		AssetTransferLimits, BridgedAssets, Broadcasters, ChallengeWindow, Head, NextSlot,
+		OldestSlot, PendingMessage, PendingMessages, PinnedSlots, RetentionWindow,
+		SP1VerificationKey, SyncCommitteeHashes, Timestamps, TransferLimitScope, TransferLimits,
+		UpdaterReward, UpdaterRotations,
+	};
+	use frame_support::{traits::EnsureOrigin, weights::WeightMeter};
+	use sp_io::hashing::keccak_256;
 	use sp_runtime::traits::AccountIdConversion;
+	use sp_runtime::SaturatedConversion;
 
 	#[benchmark]
 	fn send_message_arbitrary_message(
@@ -218,6 +231,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		_(
 			RawOrigin::Root,
+			DOMAIN,
 			0,
 			BoundedVec::truncate_from(
 				hex!("0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df").to_vec(),
@@ -257,7 +271,7 @@ mod benchmarks {
 		};
 
 		#[extrinsic_call]
-		_(RawOrigin::Root, config);
+		_(RawOrigin::Root, DOMAIN, config);
 
 		Ok(())
 	}
@@ -276,30 +290,36 @@ mod benchmarks {
 			hex!("0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df").to_vec(),
 		);
 
-		Pallet::<T>::set_poseidon_hash(RawOrigin::Root.into(), 931, hash).unwrap();
+		Pallet::<T>::set_poseidon_hash(RawOrigin::Root.into(), DOMAIN, 931, hash).unwrap();
 
-		Updater::<T>::set(H256(ACCOUNT1));
-		ConfigurationStorage::<T>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		Updaters::<T>::insert(DOMAIN, BoundedVec::truncate_from(vec![H256(ACCOUNT1)]));
+		ConfigurationStorage::<T>::insert(
+			DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 
 		let account = T::AccountId::from(ACCOUNT1);
 		let origin = RawOrigin::Signed(account.clone());
 
 		// We use test values instead of dev / prod values
 		// We override dev config
-		FunctionIds::<T>::set(Some((STEP_FUNCTION_ID, ROTATE_FUNCTION_ID)));
-		StepVerificationKey::<T>::set(Some(
+		FunctionIds::<T>::insert(DOMAIN, (STEP_FUNCTION_ID, ROTATE_FUNCTION_ID));
+		StepVerificationKey::<T>::insert(
+			DOMAIN,
 			BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap(),
-		));
-		RotateVerificationKey::<T>::set(Some(
+		);
+		RotateVerificationKey::<T>::insert(
+			DOMAIN,
 			BoundedVec::try_from(ROTATE_VK.as_bytes().to_vec()).unwrap(),
-		));
+		);
 
 		#[extrinsic_call]
 		fulfill_call(
 			origin,
+			DOMAIN,
 			STEP_FUNCTION_ID,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -317,15 +337,19 @@ mod benchmarks {
 			hex!("0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df").to_vec(),
 		);
 
-		Pallet::<T>::set_poseidon_hash(RawOrigin::Root.into(), 931, hash).unwrap();
+		Pallet::<T>::set_poseidon_hash(RawOrigin::Root.into(), DOMAIN, 931, hash).unwrap();
 
-		Updater::<T>::set(H256(ACCOUNT1));
-		ConfigurationStorage::<T>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 342,
-		});
+		Updaters::<T>::insert(DOMAIN, BoundedVec::truncate_from(vec![H256(ACCOUNT1)]));
+		ConfigurationStorage::<T>::insert(
+			DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 342,
+			},
+		);
 
-		Headers::<T>::set(
+		Headers::<T>::insert(
+			DOMAIN,
 			slot,
 			H256(hex!(
 				"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57856"
@@ -337,17 +361,20 @@ mod benchmarks {
 
 		// We use test values instead of dev / prod values
 		// We override dev config
-		FunctionIds::<T>::set(Some((STEP_FUNCTION_ID, ROTATE_FUNCTION_ID)));
-		StepVerificationKey::<T>::set(Some(
+		FunctionIds::<T>::insert(DOMAIN, (STEP_FUNCTION_ID, ROTATE_FUNCTION_ID));
+		StepVerificationKey::<T>::insert(
+			DOMAIN,
 			BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap(),
-		));
-		RotateVerificationKey::<T>::set(Some(
+		);
+		RotateVerificationKey::<T>::insert(
+			DOMAIN,
 			BoundedVec::try_from(ROTATE_VK.as_bytes().to_vec()).unwrap(),
-		));
+		);
 
 		#[extrinsic_call]
 		fulfill_call(
 			origin,
+			DOMAIN,
 			ROTATE_FUNCTION_ID,
 			get_valid_rotate_input(),
 			get_valid_rotate_output(),
@@ -368,7 +395,8 @@ mod benchmarks {
 			)),
 		);
 
-		ExecutionStateRoots::<T>::set(
+		ExecutionStateRoots::<T>::insert(
+			DOMAIN,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -404,7 +432,8 @@ mod benchmarks {
 
 		let slot = 5085118;
 
-		ExecutionStateRoots::<T>::set(
+		ExecutionStateRoots::<T>::insert(
+			DOMAIN,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -434,7 +463,7 @@ mod benchmarks {
 		let origin = RawOrigin::Root;
 
 		#[extrinsic_call]
-		_(origin, Some((STEP_FUNCTION_ID, ROTATE_FUNCTION_ID)));
+		_(origin, DOMAIN, Some((STEP_FUNCTION_ID, ROTATE_FUNCTION_ID)));
 
 		Ok(())
 	}
@@ -444,7 +473,7 @@ mod benchmarks {
 		let origin = RawOrigin::Root;
 
 		#[extrinsic_call]
-		_(origin, H256(ACCOUNT1));
+		_(origin, DOMAIN, H256(ACCOUNT1));
 
 		Ok(())
 	}
@@ -455,7 +484,7 @@ mod benchmarks {
 		let value = Some(BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap());
 
 		#[extrinsic_call]
-		_(origin, value);
+		_(origin, DOMAIN, value);
 
 		Ok(())
 	}
@@ -466,7 +495,7 @@ mod benchmarks {
 		let value = Some(BoundedVec::try_from(ROTATE_VK.as_bytes().to_vec()).unwrap());
 
 		#[extrinsic_call]
-		_(origin, value);
+		_(origin, DOMAIN, value);
 
 		Ok(())
 	}
@@ -477,7 +506,7 @@ mod benchmarks {
 		let value = H256(SP1_VERIFICATION_KEY);
 
 		#[extrinsic_call]
-		_(origin, value);
+		_(origin, DOMAIN, value);
 
 		Ok(())
 	}
@@ -491,39 +520,48 @@ mod benchmarks {
 		let period = 754;
 
 		#[extrinsic_call]
-		_(origin, period, sync_committee_hash);
+		_(origin, DOMAIN, period, sync_committee_hash);
 
 		Ok(())
 	}
 
 	#[benchmark]
 	fn fulfill() -> Result<(), BenchmarkError> {
-		SP1VerificationKey::<T>::set(H256(SP1_VERIFICATION_KEY));
+		SP1VerificationKey::<T>::insert(DOMAIN, H256(SP1_VERIFICATION_KEY));
 
 		let slots_per_period = 8192;
 		let finality_threshold = 342u16;
 		let last_slot = 6867616u64;
 		let current_period = last_slot / slots_per_period;
 
-		SyncCommitteeHashes::<T>::set(
+		SyncCommitteeHashes::<T>::insert(
+			DOMAIN,
 			current_period,
 			H256(hex!(
 				"1010a184305750d5dbc946a74673f8391044ff0600b64a5d08b970fcdea4c055"
 			)),
 		);
-		Head::<T>::set(last_slot);
-
-		ConfigurationStorage::<T>::set(Configuration {
-			slots_per_period,
-			finality_threshold,
-		});
+		Head::<T>::insert(DOMAIN, last_slot);
+
+		ConfigurationStorage::<T>::insert(
+			DOMAIN,
+			Configuration {
+				slots_per_period,
+				finality_threshold,
+			},
+		);
 
-		Updater::<T>::set(H256(ACCOUNT1));
+		Updaters::<T>::insert(DOMAIN, BoundedVec::truncate_from(vec![H256(ACCOUNT1)]));
 		let account = T::AccountId::from(ACCOUNT1);
 		let origin = RawOrigin::Signed(account.clone());
 
 		#[extrinsic_call]
-		_(origin, get_valid_sp1_proof(), get_valid_public_values());
+		_(
+			origin,
+			DOMAIN,
+			get_valid_sp1_proof(),
+			get_valid_public_values(),
+		);
 
 		Ok(())
 	}
@@ -538,9 +576,277 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn register_asset() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let asset_id = H256::repeat_byte(1);
+		let asset = bridged_asset::<T>();
+
+		#[extrinsic_call]
+		_(origin, asset_id, asset);
+
+		assert_eq!(BridgedAssets::<T>::get(asset_id), Some(asset));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deregister_asset() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let asset_id = H256::repeat_byte(1);
+		BridgedAssets::<T>::insert(asset_id, bridged_asset::<T>());
+
+		#[extrinsic_call]
+		_(origin, asset_id);
+
+		assert!(!BridgedAssets::<T>::contains_key(asset_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_transfer_limits() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let scope = TransferLimitScope::Domain(2);
+		let asset_id = H256::zero();
+		let limits = Some(TransferLimits {
+			inflow: Some(1_000u32.into()),
+			outflow: Some(1_000u32.into()),
+		});
+
+		#[extrinsic_call]
+		_(origin, scope, asset_id, limits);
+
+		assert_eq!(AssetTransferLimits::<T>::get(scope, asset_id), limits);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn execute_pending_message() -> Result<(), BenchmarkError> {
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		let Message::FungibleToken { asset_id, amount } = message.message else {
+			return Err(BenchmarkError::Stop(
+				"Message is not a fungible token transfer",
+			));
+		};
+		let amount: BalanceOf<T> = amount.saturated_into();
+
+		// Both limits are checked and updated.
+		let limits = TransferLimits {
+			inflow: Some(amount),
+			outflow: None,
+		};
+		AssetTransferLimits::<T>::insert(TransferLimitScope::Global, asset_id, limits);
+		AssetTransferLimits::<T>::insert(
+			TransferLimitScope::Domain(message.origin_domain),
+			asset_id,
+			limits,
+		);
+		PinnedSlots::<T>::insert(message.origin_domain, 5085118, 1);
+		PendingMessages::<T>::insert(
+			message_root,
+			PendingMessage {
+				from: message.from,
+				to: message.to,
+				origin_domain: message.origin_domain,
+				slot: 5085118,
+				message_id: message.id,
+				transfer: Some((asset_id, amount)),
+				executable_at: 0u32.into(),
+			},
+		);
+		let pot = T::AccountId::from(T::PalletId::get().into_account_truncating());
+		T::Currency::make_free_balance_be(&pot, BalanceOf::<T>::max_value() / 2u32.into());
+		let origin = RawOrigin::Signed(whitelisted_caller());
+
+		#[extrinsic_call]
+		_(origin, message_root);
+
+		assert!(!PendingMessages::<T>::contains_key(message_root));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_pending_message() -> Result<(), BenchmarkError> {
+		let origin =
+			T::GuardianOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let message = get_valid_amb_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		PinnedSlots::<T>::insert(message.origin_domain, 5085118, 1);
+		PendingMessages::<T>::insert(
+			message_root,
+			PendingMessage {
+				from: message.from,
+				to: message.to,
+				origin_domain: message.origin_domain,
+				slot: 5085118,
+				message_id: message.id,
+				transfer: None,
+				executable_at: 0u32.into(),
+			},
+		);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, message_root);
+
+		assert!(!PendingMessages::<T>::contains_key(message_root));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_challenge_window() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, 100);
+
+		assert_eq!(ChallengeWindow::<T>::get(), 100);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_updater() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let updaters = (1..T::MaxUpdaters::get()).map(|i| H256::from_low_u64_be(i.into()));
+		Updaters::<T>::insert(
+			DOMAIN,
+			BoundedVec::truncate_from(updaters.collect::<Vec<_>>()),
+		);
+
+		#[extrinsic_call]
+		_(origin, DOMAIN, H256(ACCOUNT1));
+
+		assert!(Updaters::<T>::get(DOMAIN).contains(&H256(ACCOUNT1)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_updater() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let updaters = (1..=T::MaxUpdaters::get()).map(|i| H256::from_low_u64_be(i.into()));
+		Updaters::<T>::insert(
+			DOMAIN,
+			BoundedVec::truncate_from(updaters.collect::<Vec<_>>()),
+		);
+		let updater = H256::from_low_u64_be(T::MaxUpdaters::get().into());
+
+		#[extrinsic_call]
+		_(origin, DOMAIN, updater);
+
+		assert!(!Updaters::<T>::get(DOMAIN).contains(&updater));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_updater_rotation() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let rotation = UpdaterRotation::RoundRobin { blocks: 100 };
+
+		#[extrinsic_call]
+		_(origin, DOMAIN, rotation);
+
+		assert_eq!(UpdaterRotations::<T>::get(DOMAIN), rotation);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_updater_reward() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let reward: BalanceOf<T> = 1_000u32.into();
+
+		#[extrinsic_call]
+		_(origin, reward);
+
+		assert_eq!(UpdaterReward::<T>::get(), reward);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_retention_window() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, 100);
+
+		assert_eq!(RetentionWindow::<T>::get(), 100);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_slot() -> Result<(), BenchmarkError> {
+		let (slot, head) = (100, 200);
+		Head::<T>::insert(DOMAIN, head);
+		OldestSlot::<T>::insert(DOMAIN, slot);
+		NextSlot::<T>::insert(DOMAIN, slot, head);
+		Headers::<T>::insert(DOMAIN, slot, H256::repeat_byte(1));
+		Timestamps::<T>::insert(DOMAIN, slot, 1);
+		ExecutionStateRoots::<T>::insert(DOMAIN, slot, H256::repeat_byte(2));
+
+		#[block]
+		{
+			Pallet::<T>::prune_slots(DOMAIN, 1, 1, &mut WeightMeter::new());
+		}
+
+		assert_eq!(OldestSlot::<T>::get(DOMAIN), Some(head));
+		assert!(!Headers::<T>::contains_key(DOMAIN, slot));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_function_verifier() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let verifier = FunctionVerifier {
+			system: ProofSystem::Groth16Bn254,
+			verification_key: BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap(),
+		};
+
+		#[extrinsic_call]
+		_(origin, STEP_FUNCTION_ID, Some(verifier.clone()));
+
+		assert_eq!(
+			FunctionVerifiers::<T>::get(STEP_FUNCTION_ID),
+			Some(verifier)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn verify_account_proof() -> Result<(), BenchmarkError> {
+		let slot = 8581263;
+		Broadcasters::<T>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+		ExecutionStateRoots::<T>::insert(
+			DOMAIN,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		let account_proof = get_valid_account_proof();
+
+		#[block]
+		{
+			Pallet::<T>::broadcaster_storage_root(slot, 2, account_proof)?;
+		}
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
 
+fn bridged_asset<T: Config>() -> BridgedAssetOf<T> {
+	let id = AssetIdOf::<T>::decode(&mut TrailingZeroInput::zeroes())
+		.expect("Asset id is decodable from zeroes");
+	BridgedAsset {
+		id,
+		mode: AssetTransferMode::MintBurn,
+	}
+}
+
 pub fn get_valid_message() -> AddressedMessage {
 	let asset_id = H256::zero();
 	let amount = 1_000_000_000_000_000_000u128;
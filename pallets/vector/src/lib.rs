@@ -1,19 +1,22 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "512"]
 
-use crate::{storage_utils::MessageStatusEnum, verifier::Verifier};
+use crate::verifier::Verifier;
 use alloy_sol_types::{sol, SolValue};
-use ark_std::format;
 use avail_base::{MemoryTemporaryStorage, ProvidePostInherent};
 use avail_core::data_proof::{tx_uid, AddressedMessage, Message, MessageType};
-use sp1_verifier::{Groth16Verifier, GROTH16_VK_BYTES};
 
 use codec::Compact;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, UnixTime},
+	traits::{
+		fungibles,
+		tokens::{Fortitude, Precision, Preservation},
+		Currency, ExistenceRequirement, UnixTime,
+	},
 	PalletId,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use sp_core::H256;
 use sp_runtime::SaturatedConversion;
 use sp_std::{vec, vec::Vec};
@@ -21,6 +24,7 @@ use sp_std::{vec, vec::Vec};
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod constants;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 mod state;
@@ -31,6 +35,11 @@ mod verifier;
 mod weights;
 
 pub use pallet::*;
+pub use state::Configuration;
+pub use storage_utils::MessageStatusEnum;
+pub use verifier::{
+	Groth16Bn254Verifier, PlonkVerifier, ProofSystem, ProofVerifier, SP1Verifier, VerificationError,
+};
 
 sol! {
 	#[derive(Debug)]
@@ -53,6 +62,7 @@ pub type FunctionInput = BoundedVec<u8, ConstU32<256>>;
 pub type FunctionOutput = BoundedVec<u8, ConstU32<512>>;
 pub type FunctionProof = BoundedVec<u8, ConstU32<1048>>;
 pub type ValidProof = BoundedVec<BoundedVec<u8, ConstU32<2048>>, ConstU32<32>>;
+pub type MessageBatch = BoundedVec<(AddressedMessage, ValidProof), ConstU32<256>>;
 
 // Avail asset is supported for now
 pub const SUPPORTED_ASSET_ID: H256 = H256::zero();
@@ -63,6 +73,111 @@ pub const STEP_OUTPUT_LENGTH: u32 = 74;
 
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type AssetIdOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+pub type BridgedAssetOf<T> = BridgedAsset<AssetIdOf<T>>;
+pub type TransferLimitsOf<T> = TransferLimits<BalanceOf<T>>;
+pub type TransferUsageOf<T> = TransferUsage<BalanceOf<T>>;
+pub type PendingMessageOf<T> = PendingMessage<BalanceOf<T>, BlockNumberFor<T>>;
+
+/// How a bridged asset is moved in and out of Avail.
+#[derive(Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug)]
+pub enum AssetTransferMode {
+	/// Assets are minted on execution and burned on send.
+	MintBurn,
+	/// Assets are unlocked from the bridge pot on execution and locked into it on send.
+	LockUnlock,
+}
+
+/// How the updaters of a source chain domain take turns to submit light client updates.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug,
+)]
+pub enum UpdaterRotation {
+	/// Any updater can submit an update.
+	#[default]
+	FirstCome,
+	/// Updaters have exclusive turns of `blocks` blocks, in the order they were added.
+	RoundRobin { blocks: u32 },
+}
+
+/// Local fungible asset associated with a bridge asset id.
+#[derive(Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug)]
+pub struct BridgedAsset<AssetId> {
+	/// Local asset id.
+	pub id: AssetId,
+	/// Transfer mode of the asset.
+	pub mode: AssetTransferMode,
+}
+
+/// Scope that transfer limits apply to.
+#[derive(Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug)]
+pub enum TransferLimitScope {
+	/// Transfers from and to every domain.
+	Global,
+	/// Transfers from and to a single domain.
+	Domain(u32),
+}
+
+/// Maximum amounts that can be bridged in and out of Avail during a single window.
+/// A `None` limit leaves the direction unrestricted.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug,
+)]
+pub struct TransferLimits<Balance> {
+	/// Limit of the amount executed on Avail.
+	pub inflow: Option<Balance>,
+	/// Limit of the amount sent from Avail.
+	pub outflow: Option<Balance>,
+}
+
+/// Amounts bridged during a window.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug,
+)]
+pub struct TransferUsage<Balance> {
+	/// Index of the window the amounts belong to.
+	pub window: u32,
+	/// Amount executed on Avail.
+	pub inflow: Balance,
+	/// Amount sent from Avail.
+	pub outflow: Balance,
+}
+
+/// Verified incoming message whose execution has been delayed.
+#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug)]
+pub struct PendingMessage<Balance, BlockNumber> {
+	/// Sender of the message on the origin domain.
+	pub from: H256,
+	/// Recipient of the message.
+	pub to: H256,
+	/// Domain the message originates from.
+	pub origin_domain: u32,
+	/// Slot of the execution state root the message was proven against.
+	pub slot: u64,
+	/// Id of the message.
+	pub message_id: u64,
+	/// Bridge asset id and amount of a fungible token transfer, `None` for arbitrary messages.
+	pub transfer: Option<(H256, Balance)>,
+	/// Block from which the message can be executed.
+	pub executable_at: BlockNumber,
+}
+
+/// Proof verifier of a function id.
+#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug)]
+pub struct FunctionVerifier {
+	/// Proving system of the function proofs.
+	pub system: ProofSystem,
+	/// Verification key, in the format expected by the proving system.
+	pub verification_key: BoundedVec<u8, ConstU32<10_000>>,
+}
+
+/// Direction of a bridged transfer.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+enum TransferDirection {
+	Inflow,
+	Outflow,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -70,13 +185,16 @@ pub mod pallet {
 	use ethabi::Token::Uint;
 	use frame_support::dispatch::GetDispatchInfo;
 	use frame_support::traits::{LockableCurrency, UnfilteredDispatchable};
-	use frame_support::{pallet_prelude::ValueQuery, DefaultNoBound};
+	use frame_support::{
+		pallet_prelude::ValueQuery, storage::with_storage_layer, weights::WeightMeter,
+		DefaultNoBound,
+	};
 	use frame_system::pallet_prelude::*;
 	use primitive_types::H160;
 	use primitive_types::{H256, U256};
 	use sp_io::hashing::keccak_256;
 	use sp_io::hashing::sha2_256;
-	use sp_runtime::traits::AccountIdConversion;
+	use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
 	pub use weights::WeightInfo;
 
 	use crate::state::Configuration;
@@ -129,6 +247,14 @@ pub mod pallet {
 		InvalidFailedIndices,
 		/// Invalid updater
 		UpdaterMisMatch,
+		/// Updater is already authorized.
+		UpdaterAlreadyAdded,
+		/// Updater is not authorized.
+		UpdaterNotFound,
+		/// Maximum number of updaters reached.
+		TooManyUpdaters,
+		/// It is the turn of another updater.
+		NotUpdaterTurn,
 		/// Proof output parsing error
 		CannotParseOutputData,
 		/// Cannot get current message id
@@ -141,6 +267,26 @@ pub mod pallet {
 		SyncCommitteeStartMismatch,
 		/// Mock is not enabled.
 		MockIsNotEnabled,
+		/// The native asset id cannot be registered as a bridged asset.
+		NativeAssetCannotBeRegistered,
+		/// Given AssetId is already registered.
+		AssetAlreadyRegistered,
+		/// Transfer exceeds a transfer limit of the current window.
+		TransferLimitExceeded,
+		/// Message execution is pending.
+		MessagePending,
+		/// No pending message for the given message root.
+		PendingMessageNotFound,
+		/// Pending message cannot be executed yet.
+		PendingMessageNotReady,
+		/// Message has been cancelled by the guardian.
+		MessageCancelled,
+		/// Message batch is empty.
+		EmptyMessageBatch,
+		/// Message does not originate from the same domain as the rest of its batch.
+		OriginDomainMismatch,
+		/// Pruning is disabled while the retention window is zero.
+		RetentionWindowNotSet,
 	}
 
 	#[pallet::event]
@@ -148,12 +294,17 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Emit event once the head is updated.
 		HeadUpdated {
+			domain: u32,
 			slot: u64,
 			finalization_root: H256,
 			execution_state_root: H256,
 		},
 		/// Emit event once the sync committee updates.
-		SyncCommitteeUpdated { period: u64, root: U256 },
+		SyncCommitteeUpdated {
+			domain: u32,
+			period: u64,
+			root: U256,
+		},
 		/// Emit when new updater is set.
 		BroadcasterUpdated { old: H256, new: H256, domain: u32 },
 		/// Emit when message gets executed.
@@ -177,55 +328,128 @@ pub mod pallet {
 		WhitelistedDomainsUpdated,
 		/// Emit when configuration is updated.
 		ConfigurationUpdated {
+			domain: u32,
 			slots_per_period: u64,
 			finality_threshold: u16,
 		},
 		/// Emit function Ids that are updated.
-		FunctionIdsUpdated { value: Option<(H256, H256)> },
+		FunctionIdsUpdated {
+			domain: u32,
+			value: Option<(H256, H256)>,
+		},
 		/// Emit updated step verification key.
 		StepVerificationKeyUpdated {
+			domain: u32,
 			value: Option<BoundedVec<u8, ConstU32<10_000>>>,
 		},
 		/// Emit updated rotate verification key.
 		RotateVerificationKeyUpdated {
+			domain: u32,
 			value: Option<BoundedVec<u8, ConstU32<10_000>>>,
 		},
-		/// Emit new updater.
-		NewUpdater { old: H256, new: H256 },
+		/// Emit when the updaters of a domain are replaced by a single updater.
+		NewUpdater {
+			domain: u32,
+			old: BoundedVec<H256, T::MaxUpdaters>,
+			new: H256,
+		},
+		/// Emit when an updater is authorized.
+		UpdaterAdded { domain: u32, updater: H256 },
+		/// Emit when an updater is no longer authorized.
+		UpdaterRemoved { domain: u32, updater: H256 },
+		/// Emit when the updater rotation of a domain is updated.
+		UpdaterRotationUpdated {
+			domain: u32,
+			rotation: UpdaterRotation,
+		},
+		/// Emit when the reward of successful updates is updated.
+		UpdaterRewardUpdated { reward: BalanceOf<T> },
+		/// Emit when an updater is rewarded for a successful update.
+		UpdaterRewarded {
+			domain: u32,
+			updater: H256,
+			amount: BalanceOf<T>,
+		},
 		/// Emit new SP1 verification key.
-		NewSP1VerificationKey { old: H256, new: H256 },
+		NewSP1VerificationKey { domain: u32, old: H256, new: H256 },
 		/// Emit when new sync committee is updated.
-		SyncCommitteeHashUpdated { period: u64, hash: H256 },
+		SyncCommitteeHashUpdated {
+			domain: u32,
+			period: u64,
+			hash: H256,
+		},
 		/// Emit when mocks are enabled or disabled
 		MockEnabled { value: bool },
+		/// Emit when a bridge asset id is associated with a local asset.
+		AssetRegistered {
+			asset_id: H256,
+			asset: BridgedAssetOf<T>,
+		},
+		/// Emit when a bridge asset id is no longer supported.
+		AssetDeregistered { asset_id: H256 },
+		/// Emit when transfer limits of an asset are updated.
+		TransferLimitsUpdated {
+			scope: TransferLimitScope,
+			asset_id: H256,
+			limits: Option<TransferLimitsOf<T>>,
+		},
+		/// Emit when the execution of a verified message is delayed.
+		MessageQueued {
+			message_root: H256,
+			executable_at: BlockNumberFor<T>,
+		},
+		/// Emit when a pending message is cancelled.
+		MessageCancelled { message_root: H256 },
+		/// Emit when the challenge window is updated.
+		ChallengeWindowUpdated { blocks: u32 },
+		/// Emit when a message of a batch cannot be executed.
+		BatchMessageFailed { index: u32, error: DispatchError },
+		/// Emit once a message batch is processed.
+		BatchExecuted { executed: u32, failed: u32 },
+		/// Emit when the proof verifier of a function id is updated.
+		FunctionVerifierUpdated {
+			function_id: H256,
+			verifier: Option<FunctionVerifier>,
+		},
+		/// Emit when the retention window of the light client roots is updated.
+		RetentionWindowUpdated { slots: u64 },
+		/// Emit when the roots of the oldest slots of a domain are pruned.
+		SlotsPruned {
+			domain: u32,
+			count: u32,
+			oldest_slot: Option<u64>,
+		},
 	}
 
-	/// Storage for a head updates.
+	/// Storage for a head updates, per source chain domain.
 	#[pallet::storage]
 	#[pallet::getter(fn head)]
-	pub type Head<T: Config> = StorageValue<_, u64, ValueQuery>;
+	pub type Head<T: Config> = StorageMap<_, Identity, u32, u64, ValueQuery>;
 
-	/// Maps from a slot to a block header root.
+	/// Maps from a source chain domain and a slot to a block header root.
 	#[pallet::storage]
 	#[pallet::getter(fn headers)]
-	pub type Headers<T> = StorageMap<_, Identity, u64, H256, ValueQuery>;
+	pub type Headers<T> = StorageDoubleMap<_, Identity, u32, Identity, u64, H256, ValueQuery>;
 
-	/// Maps slot to the timestamp of when the headers mapping was updated with slot as a key
+	/// Maps source chain domain and slot to the timestamp of when the headers mapping was updated with slot as a key
 	#[pallet::storage]
-	pub type Timestamps<T> = StorageMap<_, Identity, u64, u64, ValueQuery>;
+	pub type Timestamps<T> = StorageDoubleMap<_, Identity, u32, Identity, u64, u64, ValueQuery>;
 
-	/// Maps from a slot to the current finalized ethereum execution state root.
+	/// Maps from a source chain domain and a slot to the finalized execution state root.
 	#[pallet::storage]
-	pub type ExecutionStateRoots<T> = StorageMap<_, Identity, u64, H256, ValueQuery>;
+	pub type ExecutionStateRoots<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, u64, H256, ValueQuery>;
 
-	/// Maps from a period to the poseidon commitment for the sync committee.
+	/// Maps from a source chain domain and a period to the poseidon commitment for the sync committee.
 	#[pallet::storage]
 	#[pallet::getter(fn sync_committee_poseidons)]
-	pub type SyncCommitteePoseidons<T> = StorageMap<_, Identity, u64, U256, ValueQuery>;
+	pub type SyncCommitteePoseidons<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, u64, U256, ValueQuery>;
 
-	/// Storage for a config of finality threshold and slots per period.
+	/// Storage for a config of finality threshold and slots per period, per source chain domain.
 	#[pallet::storage]
-	pub type ConfigurationStorage<T: Config> = StorageValue<_, Configuration, ValueQuery>;
+	pub type ConfigurationStorage<T: Config> =
+		StorageMap<_, Identity, u32, Configuration, ValueQuery>;
 
 	/// Maps status of the message to the message root.
 	#[pallet::storage]
@@ -250,60 +474,133 @@ pub mod pallet {
 	/// The order of storage is (step_function_id, rotate_function_id)
 	#[pallet::storage]
 	#[pallet::getter(fn function_ids)]
-	pub type FunctionIds<T: Config> = StorageValue<_, Option<(H256, H256)>, ValueQuery>;
+	pub type FunctionIds<T: Config> = StorageMap<_, Identity, u32, (H256, H256)>;
 
-	/// Step verification key storage.
+	/// Step verification key storage, per source chain domain.
 	#[pallet::storage]
 	#[pallet::getter(fn step_verification_key)]
 	pub type StepVerificationKey<T: Config> =
-		StorageValue<_, Option<BoundedVec<u8, ConstU32<10_000>>>, ValueQuery>;
+		StorageMap<_, Identity, u32, BoundedVec<u8, ConstU32<10_000>>>;
 
-	/// Rotate verification key storage.
+	/// Rotate verification key storage, per source chain domain.
 	#[pallet::storage]
 	#[pallet::getter(fn rotate_verification_key)]
 	pub type RotateVerificationKey<T: Config> =
-		StorageValue<_, Option<BoundedVec<u8, ConstU32<10_000>>>, ValueQuery>;
+		StorageMap<_, Identity, u32, BoundedVec<u8, ConstU32<10_000>>>;
 
 	/// Genesis validator root, used to check initialization.
 	#[pallet::storage]
 	#[pallet::getter(fn genesis_validator_root)]
-	pub type GenesisValidatorRoot<T: Config> = StorageValue<_, H256, ValueQuery>;
+	pub type GenesisValidatorRoot<T: Config> = StorageMap<_, Identity, u32, H256, ValueQuery>;
 
 	/// Genesis timestamp, used to check initialization.
 	#[pallet::storage]
 	#[pallet::getter(fn genesis_timestamp)]
-	pub type GenesisTimestamp<T: Config> = StorageValue<_, u64, ValueQuery>;
+	pub type GenesisTimestamp<T: Config> = StorageMap<_, Identity, u32, u64, ValueQuery>;
 
 	/// Seconds per slot, used to check initialization.
 	#[pallet::storage]
 	#[pallet::getter(fn seconds_per_slot)]
-	pub type SecondsPerSlot<T: Config> = StorageValue<_, u64, ValueQuery>;
+	pub type SecondsPerSlot<T: Config> = StorageMap<_, Identity, u32, u64, ValueQuery>;
 
-	/// Source chain id, used to check initialization.
+	/// Source chain id of a domain, used to check initialization.
 	#[pallet::storage]
 	#[pallet::getter(fn source_chain_id)]
-	pub type SourceChainId<T: Config> = StorageValue<_, u64, ValueQuery>;
+	pub type SourceChainId<T: Config> = StorageMap<_, Identity, u32, u64, ValueQuery>;
+
+	/// Updaters that can submit updates, per source chain domain.
+	#[pallet::storage]
+	#[pallet::getter(fn updaters)]
+	pub type Updaters<T: Config> =
+		StorageMap<_, Identity, u32, BoundedVec<H256, T::MaxUpdaters>, ValueQuery>;
+
+	/// How the updaters of a source chain domain take turns.
+	#[pallet::storage]
+	pub type UpdaterRotations<T: Config> =
+		StorageMap<_, Identity, u32, UpdaterRotation, ValueQuery>;
 
-	/// Updater that can submit updates
+	/// Amount paid from the reward account to the updater of each successful update.
+	/// See [`Pallet::reward_account_id`].
 	#[pallet::storage]
-	#[pallet::getter(fn updater)]
-	pub type Updater<T: Config> = StorageValue<_, H256, ValueQuery>;
+	pub type UpdaterReward<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
-	/// Maps from a period to the the sync committee hash.
+	/// Maps from a source chain domain and a period to the the sync committee hash.
 	#[pallet::storage]
 	#[pallet::getter(fn sync_committee_hashes)]
-	pub type SyncCommitteeHashes<T> = StorageMap<_, Identity, u64, H256, ValueQuery>;
+	pub type SyncCommitteeHashes<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, u64, H256, ValueQuery>;
 
-	/// SP1 program verification key.
+	/// SP1 program verification key, per source chain domain.
 	#[pallet::storage]
 	#[pallet::getter(fn sp1_verification_key)]
-	pub type SP1VerificationKey<T: Config> = StorageValue<_, H256, ValueQuery>;
+	pub type SP1VerificationKey<T: Config> = StorageMap<_, Identity, u32, H256, ValueQuery>;
 
 	/// Enable mock functions
 	#[pallet::storage]
 	#[pallet::getter(fn verification_disabled)]
 	pub type MockEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// Maps bridge asset ids, other than the native one, to local fungible assets.
+	#[pallet::storage]
+	pub type BridgedAssets<T: Config> = StorageMap<_, Identity, H256, BridgedAssetOf<T>>;
+
+	/// Transfer limits of bridge asset ids per scope.
+	#[pallet::storage]
+	pub type AssetTransferLimits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TransferLimitScope,
+		Identity,
+		H256,
+		TransferLimitsOf<T>,
+	>;
+
+	/// Amounts of bridge asset ids transferred per scope during the latest window.
+	#[pallet::storage]
+	pub type AssetTransferUsage<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TransferLimitScope,
+		Identity,
+		H256,
+		TransferUsageOf<T>,
+		ValueQuery,
+	>;
+
+	/// Verified messages delayed by the challenge window or the inflow limits, keyed by message root.
+	#[pallet::storage]
+	pub type PendingMessages<T: Config> = StorageMap<_, Identity, H256, PendingMessageOf<T>>;
+
+	/// Number of blocks verified messages stay pending before they can be executed.
+	#[pallet::storage]
+	pub type ChallengeWindow<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Proof verifiers of function ids.
+	/// Function ids without a verifier are verified with Groth16 and the step / rotate verification keys
+	/// in `fulfill_call`, and with SP1 and the SP1 verification key in `fulfill`.
+	#[pallet::storage]
+	pub type FunctionVerifiers<T: Config> = StorageMap<_, Identity, H256, FunctionVerifier>;
+
+	/// Number of slots behind the head for which headers, timestamps and execution state roots
+	/// are kept. Zero keeps them forever.
+	#[pallet::storage]
+	pub type RetentionWindow<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Oldest slot of a domain whose roots are still stored.
+	#[pallet::storage]
+	pub type OldestSlot<T: Config> = StorageMap<_, Identity, u32, u64>;
+
+	/// Maps from a source chain domain and a stored slot to the next stored slot.
+	/// Together with `OldestSlot` it lists the stored slots of a domain from oldest to newest.
+	#[pallet::storage]
+	pub type NextSlot<T: Config> = StorageDoubleMap<_, Identity, u32, Identity, u64, u64>;
+
+	/// Number of pending messages proven against the roots of a slot.
+	/// Pinned slots are not pruned until their messages are executed or cancelled.
+	#[pallet::storage]
+	pub type PinnedSlots<T: Config> =
+		StorageDoubleMap<_, Identity, u32, Identity, u64, u32, ValueQuery>;
+
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
 		use super::*;
@@ -334,6 +631,8 @@ pub mod pallet {
 			#[inject_runtime_type]
 			type RuntimeCall = ();
 			type PalletId = BridgePalletId;
+			type TransferLimitWindow = ConstU32<100>;
+			type MaxUpdaters = ConstU32<8>;
 		}
 	}
 
@@ -352,6 +651,12 @@ pub mod pallet {
 		/// Currency type for this pallet.
 		#[pallet::no_default]
 		type Currency: LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
+		/// Origin allowed to cancel pending messages.
+		#[pallet::no_default]
+		type GuardianOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Fungible assets that can be bridged besides the native currency.
+		#[pallet::no_default]
+		type Assets: fungibles::Mutate<Self::AccountId, Balance = BalanceOf<Self>>;
 		/// Dependency that can provide current time.
 		#[pallet::no_default]
 		type TimeProvider: UnixTime;
@@ -365,6 +670,12 @@ pub mod pallet {
 		/// Unique value associated with Avail Network. Used to distinguish messages between Avail and non-Avail networks.
 		#[pallet::constant]
 		type AvailDomain: Get<u32>;
+		/// Length, in blocks, of the window that transfer limits apply to.
+		#[pallet::constant]
+		type TransferLimitWindow: Get<u32>;
+		/// Maximum number of updaters of a source chain domain.
+		#[pallet::constant]
+		type MaxUpdaters: Get<u32>;
 	}
 
 	#[pallet::genesis_config]
@@ -390,11 +701,17 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
+			// The light client tracks the source chain of the broadcaster.
+			let domain = self.broadcaster_domain;
+
 			// Preconfigure init data
-			<ConfigurationStorage<T>>::put(Configuration {
-				slots_per_period: self.slots_per_period,
-				finality_threshold: self.finality_threshold,
-			});
+			<ConfigurationStorage<T>>::insert(
+				domain,
+				Configuration {
+					slots_per_period: self.slots_per_period,
+					finality_threshold: self.finality_threshold,
+				},
+			);
 
 			let mut domains = self.whitelisted_domains.clone();
 
@@ -407,30 +724,33 @@ pub mod pallet {
 
 			Broadcasters::<T>::set(self.broadcaster_domain, self.broadcaster);
 
-			FunctionIds::<T>::set(Some(self.function_ids));
+			FunctionIds::<T>::insert(domain, self.function_ids);
 
 			let step_verification_key = BoundedVec::try_from(self.step_verification_key.clone())
 				.expect("Step verification key should be valid at genesis.");
-			StepVerificationKey::<T>::set(Some(step_verification_key));
+			StepVerificationKey::<T>::insert(domain, step_verification_key);
 
 			let rotate_verification_key =
 				BoundedVec::try_from(self.rotate_verification_key.clone())
 					.expect("Rotate verification key should be valid at genesis.");
-			RotateVerificationKey::<T>::set(Some(rotate_verification_key));
+			RotateVerificationKey::<T>::insert(domain, rotate_verification_key);
 
-			SyncCommitteePoseidons::<T>::insert(self.period, self.sync_committee_poseidon);
+			SyncCommitteePoseidons::<T>::insert(domain, self.period, self.sync_committee_poseidon);
 
-			GenesisValidatorRoot::<T>::set(self.genesis_validator_root);
+			GenesisValidatorRoot::<T>::insert(domain, self.genesis_validator_root);
 
-			GenesisTimestamp::<T>::set(self.genesis_time);
+			GenesisTimestamp::<T>::insert(domain, self.genesis_time);
 
-			SecondsPerSlot::<T>::set(self.seconds_per_slot);
+			SecondsPerSlot::<T>::insert(domain, self.seconds_per_slot);
 
-			SourceChainId::<T>::set(self.source_chain_id);
+			SourceChainId::<T>::insert(domain, self.source_chain_id);
 		}
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -444,6 +764,28 @@ pub mod pallet {
 
 			Weight::zero()
 		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			if meter.try_consume(T::DbWeight::get().reads(1)).is_err() {
+				return meter.consumed();
+			}
+
+			let window = RetentionWindow::<T>::get();
+			if window == 0 {
+				return meter.consumed();
+			}
+
+			for domain in OldestSlot::<T>::iter_keys() {
+				// `OldestSlot` key and `Head`.
+				if meter.try_consume(T::DbWeight::get().reads(2)).is_err() {
+					break;
+				}
+				Self::prune_slots(domain, window, u32::MAX, &mut meter);
+			}
+
+			meter.consumed()
+		}
 	}
 
 	#[pallet::call]
@@ -452,15 +794,17 @@ pub mod pallet {
 		[u8; 32]: From<T::AccountId>,
 	{
 		/// The entrypoint for fulfilling a call.
+		/// domain Source chain domain.
 		/// function_id Function identifier.
 		/// input Function input.
 		/// output Function output.
 		/// proof  Function proof.
 		/// slot  Function slot to update.
 		#[pallet::call_index(0)]
-		#[pallet::weight(weight_helper::fulfill_call::<T>(* function_id))]
+		#[pallet::weight(weight_helper::fulfill_call::<T>(*domain, *function_id))]
 		pub fn fulfill_call(
 			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
 			function_id: H256,
 			input: FunctionInput,
 			output: FunctionOutput,
@@ -468,22 +812,18 @@ pub mod pallet {
 			#[pallet::compact] slot: u64,
 		) -> DispatchResultWithPostInfo {
 			let sender: [u8; 32] = ensure_signed(origin)?.into();
-			let updater = Updater::<T>::get();
-			// ensure sender is preconfigured
-			ensure!(H256(sender) == updater, Error::<T>::UpdaterMisMatch);
+			let updater = H256(sender);
+			Self::ensure_updater(domain, updater)?;
 
-			let config = ConfigurationStorage::<T>::get();
+			let config = ConfigurationStorage::<T>::get(domain);
 			let input_hash = H256(sha2_256(input.as_slice()));
 			let output_hash = H256(sha2_256(output.as_slice()));
-			let (step_function_id, rotate_function_id) = Self::get_function_ids()?;
-			let verifier = Self::get_verifier(function_id, step_function_id, rotate_function_id)?;
+			let (step_function_id, rotate_function_id) = Self::get_function_ids(domain)?;
+			let verifier =
+				Self::get_verifier(domain, function_id, step_function_id, rotate_function_id)?;
 
-			let is_success = verifier
-				.verify(input_hash, output_hash, proof.to_vec())
-				.map_err(|_| Error::<T>::VerificationError)?;
-
-			// make sure that verification call is valid
-			ensure!(is_success, Error::<T>::VerificationFailed);
+			let public_inputs = [input_hash.as_bytes(), output_hash.as_bytes()].concat();
+			Self::verify_proof(&verifier, &public_inputs, &proof)?;
 
 			// verification is success and, we can safely parse and validate output
 			if function_id == step_function_id {
@@ -492,8 +832,9 @@ pub mod pallet {
 
 				let vs = VerifiedStep::new(function_id, input_hash, step_output);
 
-				if Self::step_into(slot, &config, &vs, step_function_id)? {
+				if Self::step_into(domain, slot, &config, &vs, step_function_id)? {
 					Self::deposit_event(Event::HeadUpdated {
+						domain,
 						slot: vs.verified_output.finalized_slot,
 						finalization_root: vs.verified_output.finalized_header_root,
 						execution_state_root: vs.verified_output.execution_state_root,
@@ -505,8 +846,9 @@ pub mod pallet {
 
 				let vr = VerifiedRotate::new(function_id, input_hash, rotate_output);
 
-				let period = Self::rotate_into(slot, &config, &vr, rotate_function_id)?;
+				let period = Self::rotate_into(domain, slot, &config, &vr, rotate_function_id)?;
 				Self::deposit_event(Event::SyncCommitteeUpdated {
+					domain,
 					period,
 					root: vr.sync_committee_poseidon,
 				});
@@ -514,17 +856,13 @@ pub mod pallet {
 				return Err(Error::<T>::FunctionIdNotKnown.into());
 			}
 
-			Ok(().into())
+			Self::reward_updater(domain, updater);
+			Ok(Pays::No.into())
 		}
 
 		/// Executes message if a valid proofs are provided for the supported message type, assets and domains.
 		#[pallet::call_index(1)]
-		#[pallet::weight({
-			match addr_message.message {
-				Message::ArbitraryMessage(ref data) => T::WeightInfo::execute_arbitrary_message(data.len() as u32),
-				Message::FungibleToken {..} => T::WeightInfo::execute_fungible_token(),
-			}
-		})]
+		#[pallet::weight(weight_helper::execute::<T>(addr_message))]
 		pub fn execute(
 			origin: OriginFor<T>,
 			#[pallet::compact] slot: u64,
@@ -533,68 +871,11 @@ pub mod pallet {
 			storage_proof: ValidProof,
 		) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
-			let encoded_data = addr_message.clone().abi_encode();
-			let message_root = H256(keccak_256(encoded_data.as_slice()));
-
-			Self::check_preconditions(&addr_message, message_root)?;
-
-			ensure!(
-				!SourceChainFrozen::<T>::get(addr_message.origin_domain),
-				Error::<T>::SourceChainFrozen
-			);
-			let root = ExecutionStateRoots::<T>::get(slot);
-			let broadcaster = Broadcasters::<T>::get(addr_message.origin_domain);
-
-			// extract contract address
-			let contract_broadcaster_address = H160::from_slice(broadcaster[..20].as_ref());
-			let account_proof_vec = account_proof
-				.iter()
-				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
-				.collect();
-
+			let message_root = Self::check_message(&addr_message)?;
 			let storage_root =
-				get_storage_root(account_proof_vec, contract_broadcaster_address, root)
-					.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
-
-			let message_id = Uint(U256::from(addr_message.id));
-			let mm_idx = Uint(U256::from(T::MessageMappingStorageIndex::get()));
-			let slot_key = H256(keccak_256(ethabi::encode(&[message_id, mm_idx]).as_slice()));
-
-			let storage_proof_vec = storage_proof
-				.iter()
-				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
-				.collect();
-
-			let slot_value = get_storage_value(slot_key, storage_root, storage_proof_vec)
-				.map_err(|_| Error::<T>::CannotGetStorageValue)?;
-
-			ensure!(slot_value == message_root, Error::<T>::InvalidMessageHash);
-
-			if let Message::FungibleToken { asset_id, amount } = &addr_message.message {
-				ensure!(
-					SUPPORTED_ASSET_ID == *asset_id,
-					Error::<T>::AssetNotSupported
-				);
-
-				let destination_account_id =
-					T::AccountId::decode(&mut &addr_message.to.encode()[..])
-						.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId)?;
-
-				T::Currency::transfer(
-					&Self::account_id(),
-					&destination_account_id,
-					(*amount).saturated_into(),
-					ExistenceRequirement::AllowDeath,
-				)?;
-			}
-
-			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
-			Self::deposit_event(Event::<T>::MessageExecuted {
-				from: addr_message.from,
-				to: addr_message.to,
-				message_id: addr_message.id,
-				message_root,
-			});
+				Self::broadcaster_storage_root(slot, addr_message.origin_domain, account_proof)?;
+			Self::verify_message(storage_root, &addr_message, message_root, storage_proof)?;
+			Self::apply_message(message_root, addr_message, slot)?;
 
 			Ok(().into())
 		}
@@ -631,7 +912,9 @@ pub mod pallet {
 		#[pallet::weight({
 			match message {
 				Message::ArbitraryMessage(ref data) => T::WeightInfo::send_message_arbitrary_message(data.len() as u32),
-				Message::FungibleToken{..} => T::WeightInfo::send_message_fungible_token(),
+				Message::FungibleToken { ref asset_id, .. } => T::WeightInfo::send_message_fungible_token()
+					.saturating_add(weight_helper::bridged_asset_transfer::<T>(asset_id))
+					.saturating_add(weight_helper::transfer_limits::<T>()),
 			}
 		})]
 		pub fn send_message(
@@ -669,6 +952,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::set_poseidon_hash())]
 		pub fn set_poseidon_hash(
 			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
 			#[pallet::compact] period: u64,
 			poseidon_hash: BoundedVec<u8, ConstU32<200>>,
 		) -> DispatchResultWithPostInfo {
@@ -682,8 +966,12 @@ pub mod pallet {
 
 			let hash = U256::from(poseidon_hash.to_vec().as_slice());
 
-			SyncCommitteePoseidons::<T>::insert(period, hash);
-			Self::deposit_event(Event::SyncCommitteeUpdated { period, root: hash });
+			SyncCommitteePoseidons::<T>::insert(domain, period, hash);
+			Self::deposit_event(Event::SyncCommitteeUpdated {
+				domain,
+				period,
+				root: hash,
+			});
 			Ok(().into())
 		}
 
@@ -735,11 +1023,16 @@ pub mod pallet {
 		// Test names: set_configuration_works_with_root(), set_configuration_does_not_work_with_non_root()
 		#[pallet::call_index(7)]
 		#[pallet::weight(T::WeightInfo::set_configuration())]
-		pub fn set_configuration(origin: OriginFor<T>, value: Configuration) -> DispatchResult {
+		pub fn set_configuration(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			value: Configuration,
+		) -> DispatchResult {
 			ensure_root(origin)?;
-			ConfigurationStorage::<T>::put(value);
+			ConfigurationStorage::<T>::insert(domain, value);
 
 			Self::deposit_event(Event::ConfigurationUpdated {
+				domain,
 				slots_per_period: value.slots_per_period,
 				finality_threshold: value.finality_threshold,
 			});
@@ -751,12 +1044,13 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::set_function_ids())]
 		pub fn set_function_ids(
 			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
 			value: Option<(H256, H256)>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			FunctionIds::<T>::put(value);
+			FunctionIds::<T>::set(domain, value);
 
-			Self::deposit_event(Event::FunctionIdsUpdated { value });
+			Self::deposit_event(Event::FunctionIdsUpdated { domain, value });
 
 			Ok(())
 		}
@@ -765,6 +1059,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::set_step_verification_key())]
 		pub fn set_step_verification_key(
 			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
 			value: Option<BoundedVec<u8, ConstU32<10_000>>>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
@@ -773,9 +1068,9 @@ pub mod pallet {
 					.map_err(|_| Error::<T>::MalformedVerificationKey)?;
 			}
 
-			StepVerificationKey::<T>::put(value.clone());
+			StepVerificationKey::<T>::set(domain, value.clone());
 
-			Self::deposit_event(Event::StepVerificationKeyUpdated { value });
+			Self::deposit_event(Event::StepVerificationKeyUpdated { domain, value });
 
 			Ok(())
 		}
@@ -784,6 +1079,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::set_rotate_verification_key())]
 		pub fn set_rotate_verification_key(
 			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
 			value: Option<BoundedVec<u8, ConstU32<10_000>>>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
@@ -792,13 +1088,15 @@ pub mod pallet {
 					.map_err(|_| Error::<T>::MalformedVerificationKey)?;
 			}
 
-			RotateVerificationKey::<T>::put(value.clone());
+			RotateVerificationKey::<T>::set(domain, value.clone());
 
-			Self::deposit_event(Event::RotateVerificationKeyUpdated { value });
+			Self::deposit_event(Event::RotateVerificationKeyUpdated { domain, value });
 
 			Ok(())
 		}
 
+		/// Lists the `send_message` extrinsics of the block whose dispatch failed.
+		/// Failed dispatches are rolled back, so their transfers to the bridge pot never happen.
 		#[pallet::call_index(11)]
 		#[pallet::weight((
 			T::WeightInfo::failed_tx_index(0u32),
@@ -820,77 +1118,84 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Replaces the updaters of `domain` with `updater`.
 		#[pallet::call_index(12)]
 		#[pallet::weight(T::WeightInfo::set_updater())]
-		pub fn set_updater(origin: OriginFor<T>, updater: H256) -> DispatchResult {
+		pub fn set_updater(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			updater: H256,
+		) -> DispatchResult {
 			ensure_root(origin)?;
-			let old = Updater::<T>::get();
-			Updater::<T>::set(updater);
+			let old = Updaters::<T>::get(domain);
+			Updaters::<T>::insert(domain, BoundedVec::truncate_from(vec![updater]));
 
-			Self::deposit_event(Event::<T>::NewUpdater { old, new: updater });
+			Self::deposit_event(Event::<T>::NewUpdater {
+				domain,
+				old,
+				new: updater,
+			});
 			Ok(())
 		}
 
 		/// The entrypoint for fulfill a call.
+		/// The SP1 verification key identifies the function whose verifier checks the proof.
+		/// domain Source chain domain.
 		/// proof Input proof value.
 		/// public_values Input public values.
 		#[pallet::call_index(13)]
-		#[pallet::weight(T::WeightInfo::fulfill())]
+		#[pallet::weight(T::WeightInfo::fulfill().saturating_add(weight_helper::updater::<T>()))]
 		pub fn fulfill(
 			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
 			proof: ProofInput,
 			public_values: PublicValuesInput,
 		) -> DispatchResultWithPostInfo {
 			let sender: [u8; 32] = ensure_signed(origin)?.into();
-			let updater = Updater::<T>::get();
-			// ensure sender is preconfigured
-			ensure!(H256(sender) == updater, Error::<T>::UpdaterMisMatch);
+			let updater = H256(sender);
+			Self::ensure_updater(domain, updater)?;
 
 			let proof_outputs: ProofOutputs = SolValue::abi_decode(&public_values, true)
 				.map_err(|_| Error::<T>::CannotDecodePublicValue)?;
 
-			let head = Head::<T>::get();
+			let head = Head::<T>::get(domain);
 			let new_head: u64 = proof_outputs.newHead.to();
 			ensure!(new_head > head, Error::<T>::SlotBehindHead);
-			let config = ConfigurationStorage::<T>::get();
+			let config = ConfigurationStorage::<T>::get(domain);
 
 			let current_period = head
 				.checked_div(config.slots_per_period)
 				.ok_or(Error::<T>::ConfigurationNotSet)?;
 
-			let current_sync_committee_hash = SyncCommitteeHashes::<T>::get(current_period);
+			let current_sync_committee_hash = SyncCommitteeHashes::<T>::get(domain, current_period);
 			// The "start" sync committee hash is the hash of the sync committee that should sign the next update.
 			ensure!(
 				current_sync_committee_hash == H256::from(proof_outputs.startSyncCommitteeHash.0),
 				Error::<T>::SyncCommitteeStartMismatch
 			);
 
-			let sp1_vk = SP1VerificationKey::<T>::get();
-			let is_valid = Groth16Verifier::verify(
-				&proof,
-				&public_values,
-				&format!("{:?}", sp1_vk),
-				&GROTH16_VK_BYTES,
-			);
-			ensure!(is_valid.is_ok(), Error::<T>::VerificationFailed);
+			let verifier = Self::get_sp1_verifier(SP1VerificationKey::<T>::get(domain));
+			Self::verify_proof(&verifier, &public_values, &proof)?;
 
-			Head::<T>::set(new_head);
-			let header = Headers::<T>::get(new_head);
+			Head::<T>::insert(domain, new_head);
+			let header = Headers::<T>::get(domain, new_head);
 			ensure!(header == H256::zero(), Error::<T>::HeaderRootAlreadySet);
 
 			let new_header = H256::from(proof_outputs.newHeader.0);
 
-			let execution_state_root = ExecutionStateRoots::<T>::get(new_head);
+			let execution_state_root = ExecutionStateRoots::<T>::get(domain, new_head);
 			ensure!(
 				execution_state_root == H256::zero(),
 				Error::<T>::StateRootAlreadySet
 			);
 			let new_execution_state_root = H256::from(proof_outputs.executionStateRoot.0);
 
-			Headers::<T>::insert(new_head, new_header);
-			ExecutionStateRoots::<T>::insert(new_head, new_execution_state_root);
+			Headers::<T>::insert(domain, new_head, new_header);
+			ExecutionStateRoots::<T>::insert(domain, new_head, new_execution_state_root);
+			Self::track_slot(domain, head, new_head);
 
 			Self::deposit_event(Event::HeadUpdated {
+				domain,
 				slot: new_head,
 				finalization_root: new_header,
 				execution_state_root: new_execution_state_root,
@@ -903,10 +1208,11 @@ pub mod pallet {
 			// If the sync committee for the period is not set, set it.
 			// This can happen if the light client was very behind and had a lot of updates
 			// and only the last sync committee is stored, not the intermediate ones for every period and may have gaps in periods.
-			if SyncCommitteeHashes::<T>::get(period) == H256::zero() {
+			if SyncCommitteeHashes::<T>::get(domain, period) == H256::zero() {
 				let sync_committee_hash = H256::from(proof_outputs.syncCommitteeHash.0);
-				SyncCommitteeHashes::<T>::set(period, sync_committee_hash);
+				SyncCommitteeHashes::<T>::set(domain, period, sync_committee_hash);
 				Self::deposit_event(Event::SyncCommitteeHashUpdated {
+					domain,
 					period,
 					hash: sync_committee_hash,
 				});
@@ -917,32 +1223,39 @@ pub mod pallet {
 			if next_sync_committee_hash != H256::zero() {
 				let next_period = period + 1;
 
-				let sync_committee_hash = SyncCommitteeHashes::<T>::get(next_period);
+				let sync_committee_hash = SyncCommitteeHashes::<T>::get(domain, next_period);
 				if sync_committee_hash != next_sync_committee_hash {
 					ensure!(
 						sync_committee_hash == H256::zero(),
 						Error::<T>::SyncCommitteeHashAlreadySet
 					);
-					SyncCommitteeHashes::<T>::set(next_period, next_sync_committee_hash);
+					SyncCommitteeHashes::<T>::set(domain, next_period, next_sync_committee_hash);
 					Self::deposit_event(Event::SyncCommitteeHashUpdated {
+						domain,
 						period: next_period,
 						hash: next_sync_committee_hash,
 					});
 				}
 			}
-			Timestamps::<T>::insert(new_head, T::TimeProvider::now().as_secs());
+			Timestamps::<T>::insert(domain, new_head, T::TimeProvider::now().as_secs());
 
-			Ok(().into())
+			Self::reward_updater(domain, updater);
+			Ok(Pays::No.into())
 		}
 
 		#[pallet::call_index(14)]
 		#[pallet::weight(T::WeightInfo::set_sp1_verification_key())]
-		pub fn set_sp1_verification_key(origin: OriginFor<T>, sp1_vk: H256) -> DispatchResult {
+		pub fn set_sp1_verification_key(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			sp1_vk: H256,
+		) -> DispatchResult {
 			ensure_root(origin)?;
-			let old_vk = SP1VerificationKey::<T>::get();
-			SP1VerificationKey::<T>::put(sp1_vk);
+			let old_vk = SP1VerificationKey::<T>::get(domain);
+			SP1VerificationKey::<T>::insert(domain, sp1_vk);
 
 			Self::deposit_event(Event::NewSP1VerificationKey {
+				domain,
 				old: old_vk,
 				new: sp1_vk,
 			});
@@ -954,13 +1267,18 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::set_sync_committee_hash())]
 		pub fn set_sync_committee_hash(
 			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
 			period: u64,
 			hash: H256,
 		) -> DispatchResult {
 			ensure_root(origin)?;
 
-			SyncCommitteeHashes::<T>::insert(period, hash);
-			Self::deposit_event(Event::SyncCommitteeHashUpdated { period, hash });
+			SyncCommitteeHashes::<T>::insert(domain, period, hash);
+			Self::deposit_event(Event::SyncCommitteeHashUpdated {
+				domain,
+				period,
+				hash,
+			});
 
 			Ok(())
 		}
@@ -977,11 +1295,13 @@ pub mod pallet {
 		}
 
 		/// The entrypoint for mock_fulfill call.
+		/// domain Source chain domain.
 		/// public_values Input public values.
 		#[pallet::call_index(17)]
-		#[pallet::weight(T::WeightInfo::fulfill())]
+		#[pallet::weight(T::WeightInfo::fulfill().saturating_add(weight_helper::updater::<T>()))]
 		pub fn mock_fulfill(
 			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
 			public_values: PublicValuesInput,
 		) -> DispatchResultWithPostInfo {
 			ensure!(
@@ -990,46 +1310,47 @@ pub mod pallet {
 			);
 
 			let sender: [u8; 32] = ensure_signed(origin)?.into();
-			let updater = Updater::<T>::get();
-			// ensure sender is preconfigured
-			ensure!(H256(sender) == updater, Error::<T>::UpdaterMisMatch);
+			let updater = H256(sender);
+			Self::ensure_updater(domain, updater)?;
 
 			let proof_outputs: ProofOutputs = SolValue::abi_decode(&public_values, true)
 				.map_err(|_| Error::<T>::CannotDecodePublicValue)?;
 
-			let head = Head::<T>::get();
+			let head = Head::<T>::get(domain);
 			let new_head: u64 = proof_outputs.newHead.to();
 			ensure!(new_head > head, Error::<T>::SlotBehindHead);
-			let config = ConfigurationStorage::<T>::get();
+			let config = ConfigurationStorage::<T>::get(domain);
 
 			let current_period = head
 				.checked_div(config.slots_per_period)
 				.ok_or(Error::<T>::ConfigurationNotSet)?;
 
-			let current_sync_committee_hash = SyncCommitteeHashes::<T>::get(current_period);
+			let current_sync_committee_hash = SyncCommitteeHashes::<T>::get(domain, current_period);
 			// The "start" sync committee hash is the hash of the sync committee that should sign the next update.
 			ensure!(
 				current_sync_committee_hash == H256::from(proof_outputs.startSyncCommitteeHash.0),
 				Error::<T>::SyncCommitteeStartMismatch
 			);
 
-			Head::<T>::set(new_head);
-			let header = Headers::<T>::get(new_head);
+			Head::<T>::insert(domain, new_head);
+			let header = Headers::<T>::get(domain, new_head);
 			ensure!(header == H256::zero(), Error::<T>::HeaderRootAlreadySet);
 
 			let new_header = H256::from(proof_outputs.newHeader.0);
 
-			let execution_state_root = ExecutionStateRoots::<T>::get(new_head);
+			let execution_state_root = ExecutionStateRoots::<T>::get(domain, new_head);
 			ensure!(
 				execution_state_root == H256::zero(),
 				Error::<T>::StateRootAlreadySet
 			);
 			let new_execution_state_root = H256::from(proof_outputs.executionStateRoot.0);
 
-			Headers::<T>::insert(new_head, new_header);
-			ExecutionStateRoots::<T>::insert(new_head, new_execution_state_root);
+			Headers::<T>::insert(domain, new_head, new_header);
+			ExecutionStateRoots::<T>::insert(domain, new_head, new_execution_state_root);
+			Self::track_slot(domain, head, new_head);
 
 			Self::deposit_event(Event::HeadUpdated {
+				domain,
 				slot: new_head,
 				finalization_root: new_header,
 				execution_state_root: new_execution_state_root,
@@ -1042,10 +1363,11 @@ pub mod pallet {
 			// If the sync committee for the period is not set, set it.
 			// This can happen if the light client was very behind and had a lot of updates
 			// and only the last sync committee is stored, not the intermediate ones for every period and may have gaps in periods.
-			if SyncCommitteeHashes::<T>::get(period) == H256::zero() {
+			if SyncCommitteeHashes::<T>::get(domain, period) == H256::zero() {
 				let sync_committee_hash = H256::from(proof_outputs.syncCommitteeHash.0);
-				SyncCommitteeHashes::<T>::set(period, sync_committee_hash);
+				SyncCommitteeHashes::<T>::set(domain, period, sync_committee_hash);
 				Self::deposit_event(Event::SyncCommitteeHashUpdated {
+					domain,
 					period,
 					hash: sync_committee_hash,
 				});
@@ -1056,126 +1378,730 @@ pub mod pallet {
 			if next_sync_committee_hash != H256::zero() {
 				let next_period = period + 1;
 
-				let sync_committee_hash = SyncCommitteeHashes::<T>::get(next_period);
+				let sync_committee_hash = SyncCommitteeHashes::<T>::get(domain, next_period);
 				if sync_committee_hash != next_sync_committee_hash {
 					ensure!(
 						sync_committee_hash == H256::zero(),
 						Error::<T>::SyncCommitteeHashAlreadySet
 					);
-					SyncCommitteeHashes::<T>::set(next_period, next_sync_committee_hash);
+					SyncCommitteeHashes::<T>::set(domain, next_period, next_sync_committee_hash);
 					Self::deposit_event(Event::SyncCommitteeHashUpdated {
+						domain,
 						period: next_period,
 						hash: next_sync_committee_hash,
 					});
 				}
 			}
-			Timestamps::<T>::insert(new_head, T::TimeProvider::now().as_secs());
+			Timestamps::<T>::insert(domain, new_head, T::TimeProvider::now().as_secs());
 
 			Ok(().into())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		fn do_send_message(
-			who: T::AccountId,
-			message: Message,
-			to: H256,
-			domain: u32,
-		) -> DispatchResultWithPostInfo {
-			// Ensure the domain is currently supported
+		/// Associates a bridge asset id with a local fungible asset.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::register_asset())]
+		pub fn register_asset(
+			origin: OriginFor<T>,
+			asset_id: H256,
+			asset: BridgedAssetOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
 			ensure!(
-				Self::is_domain_valid(domain),
-				Error::<T>::DomainNotSupported
+				asset_id != SUPPORTED_ASSET_ID,
+				Error::<T>::NativeAssetCannotBeRegistered
+			);
+			ensure!(
+				!BridgedAssets::<T>::contains_key(asset_id),
+				Error::<T>::AssetAlreadyRegistered
 			);
-			// Check MessageType and enforce the rules
-			let message_type = message.r#type();
-			match message {
-				Message::FungibleToken { asset_id, amount } => {
-					ensure!(
-						SUPPORTED_ASSET_ID == asset_id,
-						Error::<T>::AssetNotSupported
-					);
-					ensure!(
-						amount.saturated_into::<u128>() > 0,
-						Error::<T>::InvalidBridgeInputs
-					);
-					T::Currency::transfer(
-						&who,
-						&Self::account_id(),
-						amount.saturated_into(),
-						ExistenceRequirement::KeepAlive,
-					)?;
-				},
-				Message::ArbitraryMessage(data) => {
-					ensure!(!data.is_empty(), Error::<T>::InvalidBridgeInputs)
-				},
-			};
 
-			let message_id = Self::fetch_curr_message_id()?;
+			BridgedAssets::<T>::insert(asset_id, asset);
+			Self::deposit_event(Event::AssetRegistered { asset_id, asset });
 
-			Self::deposit_event(Event::MessageSubmitted {
-				from: who,
-				to,
-				message_type,
-				destination_domain: domain,
-				message_id,
-			});
+			Ok(())
+		}
 
-			Ok(().into())
+		/// Removes a bridge asset id from the supported assets.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::deregister_asset())]
+		pub fn deregister_asset(origin: OriginFor<T>, asset_id: H256) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				BridgedAssets::<T>::take(asset_id).is_some(),
+				Error::<T>::AssetNotSupported
+			);
+
+			Self::deposit_event(Event::AssetDeregistered { asset_id });
+
+			Ok(())
 		}
 
-		fn fetch_curr_message_id() -> Result<u64, DispatchError> {
-			let number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
-			let tx_index_option = <frame_system::Pallet<T>>::extrinsic_index();
+		/// Sets the transfer limits of a bridge asset id for the given scope.
+		/// Passing `None` removes the limits.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_transfer_limits())]
+		pub fn set_transfer_limits(
+			origin: OriginFor<T>,
+			scope: TransferLimitScope,
+			asset_id: H256,
+			limits: Option<TransferLimitsOf<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
 
-			match tx_index_option {
-				Some(tx_index) => Ok(tx_uid(number, tx_index)),
-				None => Err(Error::<T>::CurrentMessageIdNotFound.into()),
-			}
+			AssetTransferLimits::<T>::set(scope, asset_id, limits);
+			Self::deposit_event(Event::TransferLimitsUpdated {
+				scope,
+				asset_id,
+				limits,
+			});
+
+			Ok(())
 		}
 
-		fn check_preconditions(
-			message: &AddressedMessage,
+		/// Executes a verified message once its challenge window or transfer limit delay is over.
+		#[pallet::call_index(21)]
+		#[pallet::weight(weight_helper::execute_pending_message::<T>(*message_root))]
+		pub fn execute_pending_message(
+			origin: OriginFor<T>,
 			message_root: H256,
-		) -> Result<(), DispatchError> {
-			let message_status = MessageStatus::<T>::get(message_root);
-			// Message must not be executed
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let pending = Self::take_pending_message(message_root)
+				.ok_or(Error::<T>::PendingMessageNotFound)?;
 			ensure!(
-				message_status == MessageStatusEnum::NotExecuted,
-				Error::<T>::MessageAlreadyExecuted
+				<frame_system::Pallet<T>>::block_number() >= pending.executable_at,
+				Error::<T>::PendingMessageNotReady
 			);
-
 			ensure!(
-				message.destination_domain == T::AvailDomain::get(),
-				Error::<T>::WrongDestinationChain
+				!SourceChainFrozen::<T>::get(pending.origin_domain),
+				Error::<T>::SourceChainFrozen
 			);
+			if let Some((asset_id, amount)) = pending.transfer {
+				ensure!(
+					Self::try_record_transfer(
+						pending.origin_domain,
+						asset_id,
+						amount,
+						TransferDirection::Inflow,
+					),
+					Error::<T>::TransferLimitExceeded
+				);
+			}
 
-			ensure!(
-				WhitelistedDomains::<T>::get().contains(&message.origin_domain),
-				Error::<T>::UnsupportedOriginChain
-			);
+			Self::settle_message(message_root, pending)?;
 
-			let source_chain = Broadcasters::<T>::get(message.origin_domain);
+			Ok(().into())
+		}
+
+		/// Cancels a pending message. Cancelled messages can never be executed.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::cancel_pending_message())]
+		pub fn cancel_pending_message(origin: OriginFor<T>, message_root: H256) -> DispatchResult {
+			T::GuardianOrigin::ensure_origin(origin)?;
 			ensure!(
-				source_chain != H256::zero(),
-				Error::<T>::BroadcasterSourceChainNotSet
+				Self::take_pending_message(message_root).is_some(),
+				Error::<T>::PendingMessageNotFound
 			);
 
-			Ok(())
-		}
+			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionCancelled);
+			Self::deposit_event(Event::MessageCancelled { message_root });
 
-		/// The account ID of the bridge's pot.
-		pub fn account_id() -> T::AccountId {
-			T::PalletId::get().into_account_truncating()
+			Ok(())
+		}
+
+		/// Executes a batch of messages from the same origin domain and slot.
+		/// The broadcaster storage root is derived once from `account_proof`, and each message
+		/// is executed independently of the outcome of the others.
+		#[pallet::call_index(24)]
+		#[pallet::weight(weight_helper::execute_batch::<T>(messages))]
+		pub fn execute_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			account_proof: ValidProof,
+			messages: MessageBatch,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let origin_domain = messages
+				.first()
+				.map(|(message, _)| message.origin_domain)
+				.ok_or(Error::<T>::EmptyMessageBatch)?;
+			let storage_root = Self::broadcaster_storage_root(slot, origin_domain, account_proof)?;
+
+			let (mut executed, mut failed) = (0u32, 0u32);
+			for (index, (message, storage_proof)) in messages.into_iter().enumerate() {
+				let result = with_storage_layer::<(), DispatchError, _>(|| {
+					ensure!(
+						message.origin_domain == origin_domain,
+						Error::<T>::OriginDomainMismatch
+					);
+					let message_root = Self::check_message(&message)?;
+					Self::verify_message(storage_root, &message, message_root, storage_proof)?;
+					Self::apply_message(message_root, message, slot)
+				});
+
+				match result {
+					Ok(()) => executed.saturating_inc(),
+					Err(error) => {
+						failed.saturating_inc();
+						Self::deposit_event(Event::BatchMessageFailed {
+							index: index as u32,
+							error,
+						});
+					},
+				}
+			}
+
+			Self::deposit_event(Event::BatchExecuted { executed, failed });
+
+			Ok(().into())
+		}
+
+		/// Sets the number of blocks verified messages stay pending before they can be executed.
+		/// Zero executes messages immediately.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::set_challenge_window())]
+		pub fn set_challenge_window(origin: OriginFor<T>, blocks: u32) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ChallengeWindow::<T>::put(blocks);
+			Self::deposit_event(Event::ChallengeWindowUpdated { blocks });
+
+			Ok(())
+		}
+
+		/// Sets the proof verifier of a function id, or reverts it to the default verifier if `None`.
+		/// This allows moving a function to another proving system without a new call.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::set_function_verifier())]
+		pub fn set_function_verifier(
+			origin: OriginFor<T>,
+			function_id: H256,
+			verifier: Option<FunctionVerifier>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let Some(verifier) = &verifier {
+				verifier
+					.system
+					.check_verification_key(&verifier.verification_key)
+					.map_err(|_| Error::<T>::MalformedVerificationKey)?;
+			}
+
+			FunctionVerifiers::<T>::set(function_id, verifier.clone());
+			Self::deposit_event(Event::FunctionVerifierUpdated {
+				function_id,
+				verifier,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the number of slots behind the head for which the light client roots are kept.
+		/// Older roots are pruned when blocks have spare weight. Zero keeps them forever.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_retention_window())]
+		pub fn set_retention_window(origin: OriginFor<T>, slots: u64) -> DispatchResult {
+			ensure_root(origin)?;
+
+			RetentionWindow::<T>::put(slots);
+			Self::deposit_event(Event::RetentionWindowUpdated { slots });
+
+			Ok(())
+		}
+
+		/// Prunes the roots of the slots of `domain` that are out of the retention window, visiting
+		/// up to `limit` slots. Slots pinned by pending messages are skipped.
+		#[pallet::call_index(27)]
+		#[pallet::weight(weight_helper::force_prune::<T>(*limit))]
+		pub fn force_prune(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			#[pallet::compact] limit: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let window = RetentionWindow::<T>::get();
+			ensure!(window > 0, Error::<T>::RetentionWindowNotSet);
+
+			let visited = Self::prune_slots(domain, window, limit, &mut WeightMeter::new());
+
+			Ok(Some(weight_helper::force_prune::<T>(visited)).into())
+		}
+
+		/// Authorizes `updater` to submit light client updates of `domain`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::add_updater())]
+		pub fn add_updater(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			updater: H256,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Updaters::<T>::try_mutate(domain, |updaters| {
+				ensure!(
+					!updaters.contains(&updater),
+					Error::<T>::UpdaterAlreadyAdded
+				);
+				updaters
+					.try_push(updater)
+					.map_err(|_| Error::<T>::TooManyUpdaters)
+			})?;
+
+			Self::deposit_event(Event::UpdaterAdded { domain, updater });
+			Ok(())
+		}
+
+		/// Revokes the authorization of `updater` to submit light client updates of `domain`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::remove_updater())]
+		pub fn remove_updater(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			updater: H256,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Updaters::<T>::try_mutate(domain, |updaters| {
+				let index = updaters
+					.iter()
+					.position(|u| *u == updater)
+					.ok_or(Error::<T>::UpdaterNotFound)?;
+				updaters.remove(index);
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::UpdaterRemoved { domain, updater });
+			Ok(())
+		}
+
+		/// Sets how the updaters of `domain` take turns to submit updates.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::set_updater_rotation())]
+		pub fn set_updater_rotation(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			rotation: UpdaterRotation,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			UpdaterRotations::<T>::insert(domain, rotation);
+			Self::deposit_event(Event::UpdaterRotationUpdated { domain, rotation });
+
+			Ok(())
+		}
+
+		/// Sets the amount paid from the reward account to the updater of each successful update.
+		/// Successful updates are free of fees regardless of the reward.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::set_updater_reward())]
+		pub fn set_updater_reward(origin: OriginFor<T>, reward: BalanceOf<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			UpdaterReward::<T>::put(reward);
+			Self::deposit_event(Event::UpdaterRewardUpdated { reward });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Moves `amount` of `asset_id` from `who` out of Avail.
+		fn transfer_out(
+			asset_id: H256,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<(), DispatchError> {
+			if asset_id == SUPPORTED_ASSET_ID {
+				return T::Currency::transfer(
+					who,
+					&Self::account_id(),
+					amount,
+					ExistenceRequirement::KeepAlive,
+				);
+			}
+
+			let asset = BridgedAssets::<T>::get(asset_id).ok_or(Error::<T>::AssetNotSupported)?;
+			match asset.mode {
+				AssetTransferMode::MintBurn => {
+					T::Assets::burn_from(
+						asset.id,
+						who,
+						amount,
+						Precision::Exact,
+						Fortitude::Polite,
+					)?;
+				},
+				AssetTransferMode::LockUnlock => {
+					T::Assets::transfer(
+						asset.id,
+						who,
+						&Self::account_id(),
+						amount,
+						Preservation::Preserve,
+					)?;
+				},
+			}
+
+			Ok(())
+		}
+
+		/// Moves `amount` of `asset_id` into Avail, to `who`.
+		fn transfer_in(
+			asset_id: H256,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<(), DispatchError> {
+			if asset_id == SUPPORTED_ASSET_ID {
+				return T::Currency::transfer(
+					&Self::account_id(),
+					who,
+					amount,
+					ExistenceRequirement::AllowDeath,
+				);
+			}
+
+			let asset = BridgedAssets::<T>::get(asset_id).ok_or(Error::<T>::AssetNotSupported)?;
+			match asset.mode {
+				AssetTransferMode::MintBurn => {
+					T::Assets::mint_into(asset.id, who, amount)?;
+				},
+				AssetTransferMode::LockUnlock => {
+					T::Assets::transfer(
+						asset.id,
+						&Self::account_id(),
+						who,
+						amount,
+						Preservation::Expendable,
+					)?;
+				},
+			}
+
+			Ok(())
+		}
+
+		/// Checks that `message` can be executed and returns its root.
+		fn check_message(message: &AddressedMessage) -> Result<H256, DispatchError> {
+			let encoded_data = message.clone().abi_encode();
+			let message_root = H256(keccak_256(encoded_data.as_slice()));
+
+			Self::check_preconditions(message, message_root)?;
+
+			ensure!(
+				!SourceChainFrozen::<T>::get(message.origin_domain),
+				Error::<T>::SourceChainFrozen
+			);
+
+			Ok(message_root)
+		}
+
+		/// Derives the storage root of the `origin_domain` broadcaster at `slot` from `account_proof`.
+		pub(crate) fn broadcaster_storage_root(
+			slot: u64,
+			origin_domain: u32,
+			account_proof: ValidProof,
+		) -> Result<H256, DispatchError> {
+			let root = ExecutionStateRoots::<T>::get(origin_domain, slot);
+			let broadcaster = Broadcasters::<T>::get(origin_domain);
+
+			// extract contract address
+			let contract_broadcaster_address = H160::from_slice(broadcaster[..20].as_ref());
+			let account_proof_vec = account_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+
+			let storage_root =
+				get_storage_root(account_proof_vec, contract_broadcaster_address, root)
+					.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
+
+			Ok(storage_root)
+		}
+
+		/// Verifies that `message_root` is stored by the broadcaster under the id of `message`.
+		fn verify_message(
+			storage_root: H256,
+			message: &AddressedMessage,
+			message_root: H256,
+			storage_proof: ValidProof,
+		) -> Result<(), DispatchError> {
+			let message_id = Uint(U256::from(message.id));
+			let mm_idx = Uint(U256::from(T::MessageMappingStorageIndex::get()));
+			let slot_key = H256(keccak_256(ethabi::encode(&[message_id, mm_idx]).as_slice()));
+
+			let storage_proof_vec = storage_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+
+			let slot_value = get_storage_value(slot_key, storage_root, storage_proof_vec)
+				.map_err(|_| Error::<T>::CannotGetStorageValue)?;
+
+			ensure!(slot_value == message_root, Error::<T>::InvalidMessageHash);
+
+			Ok(())
+		}
+
+		/// Executes a verified message, or queues it if its execution has to be delayed.
+		fn apply_message(
+			message_root: H256,
+			addr_message: AddressedMessage,
+			slot: u64,
+		) -> Result<(), DispatchError> {
+			let transfer: Option<(H256, BalanceOf<T>)> = match &addr_message.message {
+				Message::FungibleToken { asset_id, amount } => {
+					// Reject undeliverable transfers before they get queued.
+					Self::destination_account_id(addr_message.to)?;
+					Some((*asset_id, (*amount).saturated_into()))
+				},
+				Message::ArbitraryMessage(_) => None,
+			};
+			let now = <frame_system::Pallet<T>>::block_number();
+			let challenge_window = ChallengeWindow::<T>::get();
+			let delayed_until = if challenge_window > 0 {
+				Some(now.saturating_add(challenge_window.into()))
+			} else if let Some((asset_id, amount)) = transfer {
+				let within_limits = Self::try_record_transfer(
+					addr_message.origin_domain,
+					asset_id,
+					amount,
+					TransferDirection::Inflow,
+				);
+				(!within_limits).then(Self::next_transfer_window_start)
+			} else {
+				None
+			};
+
+			let message = PendingMessage {
+				from: addr_message.from,
+				to: addr_message.to,
+				origin_domain: addr_message.origin_domain,
+				slot,
+				message_id: addr_message.id,
+				transfer,
+				executable_at: delayed_until.unwrap_or(now),
+			};
+			if let Some(executable_at) = delayed_until {
+				// Keep the roots the message was proven against until it is settled.
+				PinnedSlots::<T>::mutate(message.origin_domain, slot, |refs| refs.saturating_inc());
+				PendingMessages::<T>::insert(message_root, message);
+				MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionPending);
+				Self::deposit_event(Event::<T>::MessageQueued {
+					message_root,
+					executable_at,
+				});
+
+				return Ok(());
+			}
+
+			Self::settle_message(message_root, message)
+		}
+
+		/// Applies the effects of a verified message and marks it as executed.
+		fn settle_message(
+			message_root: H256,
+			message: PendingMessageOf<T>,
+		) -> Result<(), DispatchError> {
+			if let Some((asset_id, amount)) = message.transfer {
+				let destination_account_id = Self::destination_account_id(message.to)?;
+				Self::transfer_in(asset_id, &destination_account_id, amount)?;
+			}
+
+			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
+			Self::deposit_event(Event::<T>::MessageExecuted {
+				from: message.from,
+				to: message.to,
+				message_id: message.message_id,
+				message_root,
+			});
+
+			Ok(())
+		}
+
+		fn destination_account_id(to: H256) -> Result<T::AccountId, DispatchError> {
+			T::AccountId::decode(&mut &to.encode()[..])
+				.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId.into())
+		}
+
+		/// Index of the current transfer limit window.
+		fn current_transfer_window() -> u32 {
+			let block_number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			block_number / T::TransferLimitWindow::get().max(1)
+		}
+
+		/// First block of the next transfer limit window.
+		fn next_transfer_window_start() -> BlockNumberFor<T> {
+			let window = Self::current_transfer_window().saturating_add(1);
+			window
+				.saturating_mul(T::TransferLimitWindow::get().max(1))
+				.into()
+		}
+
+		/// Records `amount` of `asset_id` bridged from or to `domain` against the global and
+		/// domain limits of the current window.
+		/// Nothing is recorded if any limit would be exceeded, in which case `false` is returned.
+		fn try_record_transfer(
+			domain: u32,
+			asset_id: H256,
+			amount: BalanceOf<T>,
+			direction: TransferDirection,
+		) -> bool {
+			let window = Self::current_transfer_window();
+			let mut updates = Vec::new();
+
+			for scope in [
+				TransferLimitScope::Global,
+				TransferLimitScope::Domain(domain),
+			] {
+				let Some(limits) = AssetTransferLimits::<T>::get(scope, asset_id) else {
+					continue;
+				};
+
+				let mut usage = AssetTransferUsage::<T>::get(scope, asset_id);
+				if usage.window != window {
+					usage = TransferUsage {
+						window,
+						..Default::default()
+					};
+				}
+
+				let (used, limit) = match direction {
+					TransferDirection::Inflow => (&mut usage.inflow, limits.inflow),
+					TransferDirection::Outflow => (&mut usage.outflow, limits.outflow),
+				};
+				*used = used.saturating_add(amount);
+				if limit.is_some_and(|limit| *used > limit) {
+					return false;
+				}
+
+				updates.push((scope, usage));
+			}
+
+			for (scope, usage) in updates {
+				AssetTransferUsage::<T>::insert(scope, asset_id, usage);
+			}
+
+			true
+		}
+
+		fn do_send_message(
+			who: T::AccountId,
+			message: Message,
+			to: H256,
+			domain: u32,
+		) -> DispatchResultWithPostInfo {
+			// Ensure the domain is currently supported
+			ensure!(
+				Self::is_domain_valid(domain),
+				Error::<T>::DomainNotSupported
+			);
+			// Check MessageType and enforce the rules
+			let message_type = message.r#type();
+			match message {
+				Message::FungibleToken { asset_id, amount } => {
+					ensure!(
+						amount.saturated_into::<u128>() > 0,
+						Error::<T>::InvalidBridgeInputs
+					);
+					ensure!(
+						Self::try_record_transfer(
+							domain,
+							asset_id,
+							amount.saturated_into(),
+							TransferDirection::Outflow,
+						),
+						Error::<T>::TransferLimitExceeded
+					);
+					Self::transfer_out(asset_id, &who, amount.saturated_into())?;
+				},
+				Message::ArbitraryMessage(data) => {
+					ensure!(!data.is_empty(), Error::<T>::InvalidBridgeInputs)
+				},
+			};
+
+			let message_id = Self::fetch_curr_message_id()?;
+
+			Self::deposit_event(Event::MessageSubmitted {
+				from: who,
+				to,
+				message_type,
+				destination_domain: domain,
+				message_id,
+			});
+
+			Ok(().into())
+		}
+
+		fn fetch_curr_message_id() -> Result<u64, DispatchError> {
+			let number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let tx_index_option = <frame_system::Pallet<T>>::extrinsic_index();
+
+			match tx_index_option {
+				Some(tx_index) => Ok(tx_uid(number, tx_index)),
+				None => Err(Error::<T>::CurrentMessageIdNotFound.into()),
+			}
+		}
+
+		fn check_preconditions(
+			message: &AddressedMessage,
+			message_root: H256,
+		) -> Result<(), DispatchError> {
+			let message_status = MessageStatus::<T>::get(message_root);
+			ensure!(
+				message_status != MessageStatusEnum::ExecutionPending,
+				Error::<T>::MessagePending
+			);
+			ensure!(
+				message_status != MessageStatusEnum::ExecutionCancelled,
+				Error::<T>::MessageCancelled
+			);
+			// Message must not be executed
+			ensure!(
+				message_status == MessageStatusEnum::NotExecuted,
+				Error::<T>::MessageAlreadyExecuted
+			);
+
+			ensure!(
+				message.destination_domain == T::AvailDomain::get(),
+				Error::<T>::WrongDestinationChain
+			);
+
+			ensure!(
+				WhitelistedDomains::<T>::get().contains(&message.origin_domain),
+				Error::<T>::UnsupportedOriginChain
+			);
+
+			let source_chain = Broadcasters::<T>::get(message.origin_domain);
+			ensure!(
+				source_chain != H256::zero(),
+				Error::<T>::BroadcasterSourceChainNotSet
+			);
+
+			Ok(())
+		}
+
+		/// The account ID of the bridge's pot.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// The account ID paying the updater rewards, kept apart from the bridge's pot so rewards
+		/// never spend bridged funds. It has to be funded, e.g. by the treasury.
+		pub fn reward_account_id() -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(b"rewards")
+		}
+
+		/// Returns the period of the current head of `domain` and the hash of its sync committee.
+		pub fn current_sync_committee_hash(domain: u32) -> Option<(u64, H256)> {
+			let config = ConfigurationStorage::<T>::get(domain);
+			let period = Head::<T>::get(domain).checked_div(config.slots_per_period)?;
+			Some((period, SyncCommitteeHashes::<T>::get(domain, period)))
 		}
 
 		fn rotate_into(
+			domain: u32,
 			finalized_slot: u64,
 			cfg: &Configuration,
 			verified_rotate_call: &VerifiedRotate,
 			rotate_function_id: H256,
 		) -> Result<u64, DispatchError> {
-			let finalized_header_root = Headers::<T>::get(finalized_slot);
+			let finalized_header_root = Headers::<T>::get(domain, finalized_slot);
 			ensure!(
 				finalized_header_root != H256::zero(),
 				Error::<T>::HeaderRootNotSet
@@ -1190,12 +2116,13 @@ pub mod pallet {
 				.ok_or(Error::<T>::ConfigurationNotSet)?;
 			let next_period = period + 1;
 
-			Self::set_sync_committee_poseidon(next_period, sync_committee_poseidon)?;
+			Self::set_sync_committee_poseidon(domain, next_period, sync_committee_poseidon)?;
 
 			Ok(next_period)
 		}
 
 		fn step_into(
+			domain: u32,
 			attested_slot: u64,
 			cfg: &Configuration,
 			verified_step_call: &VerifiedStep,
@@ -1205,7 +2132,7 @@ pub mod pallet {
 				.checked_div(cfg.slots_per_period)
 				.ok_or(Error::<T>::ConfigurationNotSet)?;
 
-			let sc_poseidon = SyncCommitteePoseidons::<T>::get(period);
+			let sc_poseidon = SyncCommitteePoseidons::<T>::get(domain, period);
 			ensure!(sc_poseidon != U256::zero(), Error::<T>::SyncCommitteeNotSet);
 
 			let input = encode_packed(sc_poseidon, attested_slot);
@@ -1215,10 +2142,10 @@ pub mod pallet {
 				Error::<T>::NotEnoughParticipants
 			);
 
-			let head = Head::<T>::get();
+			let head = Head::<T>::get(domain);
 			ensure!(result.finalized_slot > head, Error::<T>::SlotBehindHead);
 
-			let updated = Self::set_slot_roots(result)?;
+			let updated = Self::set_slot_roots(domain, result)?;
 
 			Ok(updated)
 		}
@@ -1226,77 +2153,235 @@ pub mod pallet {
 		///  Sets the current slot for the chain the light client is reflecting.
 		/// checks is the roots exists for the slot already. If there is
 		/// an existing header but no conflict, do nothing. Avoids timestamp renewal DoS attacks.
-		fn set_slot_roots(step_output: VerifiedStepOutput) -> Result<bool, DispatchError> {
-			let header = Headers::<T>::get(step_output.finalized_slot);
+		fn set_slot_roots(
+			domain: u32,
+			step_output: VerifiedStepOutput,
+		) -> Result<bool, DispatchError> {
+			let slot = step_output.finalized_slot;
+			let header = Headers::<T>::get(domain, slot);
 			ensure!(header == H256::zero(), Error::<T>::HeaderRootAlreadySet);
 
-			let execution_state_root = ExecutionStateRoots::<T>::get(step_output.finalized_slot);
+			let execution_state_root = ExecutionStateRoots::<T>::get(domain, slot);
 			ensure!(
 				execution_state_root == H256::zero(),
 				Error::<T>::StateRootAlreadySet
 			);
 
-			Head::<T>::set(step_output.finalized_slot);
-			Headers::<T>::insert(
-				step_output.finalized_slot,
-				step_output.finalized_header_root,
-			);
-			ExecutionStateRoots::<T>::insert(
-				step_output.finalized_slot,
-				step_output.execution_state_root,
-			);
+			let previous_head = Head::<T>::get(domain);
+			Head::<T>::insert(domain, slot);
+			Headers::<T>::insert(domain, slot, step_output.finalized_header_root);
+			ExecutionStateRoots::<T>::insert(domain, slot, step_output.execution_state_root);
+			Self::track_slot(domain, previous_head, slot);
 
-			Timestamps::<T>::insert(step_output.finalized_slot, T::TimeProvider::now().as_secs());
+			Timestamps::<T>::insert(domain, slot, T::TimeProvider::now().as_secs());
 
 			Ok(true)
 		}
 
+		/// Ensures that `updater` is an updater of `domain` and that it is its turn.
+		fn ensure_updater(domain: u32, updater: H256) -> DispatchResult {
+			let updaters = Updaters::<T>::get(domain);
+			let index = updaters
+				.iter()
+				.position(|u| *u == updater)
+				.ok_or(Error::<T>::UpdaterMisMatch)?;
+
+			if let UpdaterRotation::RoundRobin { blocks } = UpdaterRotations::<T>::get(domain) {
+				let block_number =
+					<frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+				let turn = (block_number / blocks.max(1)) as usize % updaters.len();
+				ensure!(turn == index, Error::<T>::NotUpdaterTurn);
+			}
+
+			Ok(())
+		}
+
+		/// Pays the updater reward to `updater` for a successful update of `domain`.
+		/// A failed payment, e.g. because of an empty reward account, does not revert the update.
+		fn reward_updater(domain: u32, updater: H256) {
+			let amount = UpdaterReward::<T>::get();
+			if amount.is_zero() {
+				return;
+			}
+
+			let paid = Self::destination_account_id(updater).and_then(|who| {
+				T::Currency::transfer(
+					&Self::reward_account_id(),
+					&who,
+					amount,
+					ExistenceRequirement::KeepAlive,
+				)
+			});
+			match paid {
+				Ok(()) => Self::deposit_event(Event::UpdaterRewarded {
+					domain,
+					updater,
+					amount,
+				}),
+				Err(e) => {
+					log::warn!(target: LOG_TARGET, "Cannot reward updater {updater:?}: {e:?}")
+				},
+			}
+		}
+
+		/// Appends `slot`, the new head of `domain`, to its list of stored slots.
+		fn track_slot(domain: u32, previous_head: u64, slot: u64) {
+			if OldestSlot::<T>::contains_key(domain) {
+				NextSlot::<T>::insert(domain, previous_head, slot);
+			} else {
+				OldestSlot::<T>::insert(domain, slot);
+			}
+		}
+
+		/// Prunes the roots of the oldest slots of `domain` which are more than `window` slots
+		/// behind its head, visiting up to `limit` slots as long as `meter` allows it.
+		/// Slots pinned by a pending message are skipped and stay listed.
+		/// Returns the number of visited slots.
+		pub(crate) fn prune_slots(
+			domain: u32,
+			window: u64,
+			limit: u32,
+			meter: &mut WeightMeter,
+		) -> u32 {
+			let horizon = Head::<T>::get(domain).saturating_sub(window);
+			let mut oldest_slot = OldestSlot::<T>::get(domain);
+			// Newest skipped slot, which stays linked to the next stored slot.
+			let mut pinned_slot = None;
+			let mut cursor = oldest_slot;
+			let (mut visited, mut pruned) = (0u32, 0u32);
+
+			while let Some(slot) = cursor {
+				// Relinking the pinned slot costs one more write.
+				let prune_weight = match pinned_slot {
+					Some(_) => {
+						T::WeightInfo::prune_slot().saturating_add(T::DbWeight::get().writes(1))
+					},
+					None => T::WeightInfo::prune_slot(),
+				};
+				if visited >= limit || slot >= horizon || !meter.can_consume(prune_weight) {
+					break;
+				}
+				visited.saturating_inc();
+
+				if PinnedSlots::<T>::contains_key(domain, slot) {
+					// `PinnedSlots` and `NextSlot`.
+					meter.consume(T::DbWeight::get().reads(2));
+					pinned_slot = Some(slot);
+					cursor = NextSlot::<T>::get(domain, slot);
+					continue;
+				}
+
+				meter.consume(prune_weight);
+				// The head has no next slot and is never pruned.
+				let Some(next) = NextSlot::<T>::take(domain, slot) else {
+					break;
+				};
+				match pinned_slot {
+					Some(pinned) => NextSlot::<T>::insert(domain, pinned, next),
+					None => oldest_slot = Some(next),
+				}
+
+				Headers::<T>::remove(domain, slot);
+				Timestamps::<T>::remove(domain, slot);
+				ExecutionStateRoots::<T>::remove(domain, slot);
+				cursor = Some(next);
+				pruned.saturating_inc();
+			}
+
+			if pruned > 0 {
+				OldestSlot::<T>::set(domain, oldest_slot);
+				Self::deposit_event(Event::SlotsPruned {
+					domain,
+					count: pruned,
+					oldest_slot,
+				});
+			}
+
+			visited
+		}
+
+		/// Removes a pending message and releases the slot it pins.
+		fn take_pending_message(message_root: H256) -> Option<PendingMessageOf<T>> {
+			let pending = PendingMessages::<T>::take(message_root)?;
+			PinnedSlots::<T>::mutate_exists(pending.origin_domain, pending.slot, |refs| {
+				*refs = refs
+					.and_then(|refs| refs.checked_sub(1))
+					.filter(|refs| *refs > 0);
+			});
+
+			Some(pending)
+		}
+
 		/// Sets the sync committee poseidon for a given period.
-		fn set_sync_committee_poseidon(period: u64, poseidon: U256) -> Result<(), DispatchError> {
-			let sync_committee_poseidons = SyncCommitteePoseidons::<T>::get(period);
+		fn set_sync_committee_poseidon(
+			domain: u32,
+			period: u64,
+			poseidon: U256,
+		) -> Result<(), DispatchError> {
+			let sync_committee_poseidons = SyncCommitteePoseidons::<T>::get(domain, period);
 			ensure!(
 				sync_committee_poseidons == U256::zero(),
 				Error::<T>::SyncCommitteeAlreadySet
 			);
 
-			SyncCommitteePoseidons::<T>::set(period, poseidon);
+			SyncCommitteePoseidons::<T>::set(domain, period, poseidon);
 
 			Ok(())
 		}
 
 		/// get_verifier returns verifier based on the provided function id.
+		/// Falls back to Groth16 with the step / rotate verification key if the function id has no verifier.
 		fn get_verifier(
+			domain: u32,
 			function_id: H256,
 			step_function_id: H256,
 			rotate_function_id: H256,
-		) -> Result<Verifier, Error<T>> {
-			if function_id == step_function_id {
-				Self::get_step_verifier()
+		) -> Result<FunctionVerifier, Error<T>> {
+			let vk = if function_id == step_function_id {
+				StepVerificationKey::<T>::get(domain)
 			} else if function_id == rotate_function_id {
-				Self::get_rotate_verifier()
+				RotateVerificationKey::<T>::get(domain)
 			} else {
-				Err(Error::<T>::FunctionIdNotKnown)
+				return Err(Error::<T>::FunctionIdNotKnown);
+			};
+
+			if let Some(verifier) = FunctionVerifiers::<T>::get(function_id) {
+				return Ok(verifier);
 			}
+
+			let verification_key = vk.ok_or(Error::<T>::VerificationKeyIsNotSet)?;
+			Ok(FunctionVerifier {
+				system: ProofSystem::Groth16Bn254,
+				verification_key,
+			})
 		}
 
-		fn get_step_verifier() -> Result<Verifier, Error<T>> {
-			if let Some(vk) = StepVerificationKey::<T>::get() {
-				let deserialized_vk = Verifier::from_json_u8_slice(vk.as_slice())
-					.map_err(|_| Error::<T>::MalformedVerificationKey)?;
-				Ok(deserialized_vk)
-			} else {
-				Err(Error::<T>::VerificationKeyIsNotSet)
-			}
+		/// Returns the verifier of the SP1 program identified by `sp1_vk`.
+		/// Falls back to SP1 with `sp1_vk` as verification key if the program has no verifier.
+		fn get_sp1_verifier(sp1_vk: H256) -> FunctionVerifier {
+			FunctionVerifiers::<T>::get(sp1_vk).unwrap_or_else(|| FunctionVerifier {
+				system: ProofSystem::SP1,
+				verification_key: BoundedVec::truncate_from(sp1_vk.as_bytes().to_vec()),
+			})
 		}
 
-		fn get_rotate_verifier() -> Result<Verifier, Error<T>> {
-			if let Some(vk) = RotateVerificationKey::<T>::get() {
-				let deserialized_vk = Verifier::from_json_u8_slice(vk.as_slice())
-					.map_err(|_| Error::<T>::MalformedVerificationKey)?;
-				Ok(deserialized_vk)
-			} else {
-				Err(Error::<T>::VerificationKeyIsNotSet)
-			}
+		/// Verifies `proof` of `public_inputs` with the given verifier.
+		fn verify_proof(
+			verifier: &FunctionVerifier,
+			public_inputs: &[u8],
+			proof: &[u8],
+		) -> DispatchResult {
+			verifier
+				.system
+				.verify(&verifier.verification_key, public_inputs, proof)
+				.map_err(|e| match e {
+					VerificationError::InvalidProof => Error::<T>::VerificationFailed,
+					VerificationError::InvalidVK => Error::<T>::MalformedVerificationKey,
+					VerificationError::MalformedProof | VerificationError::InvalidPublicInputs => {
+						Error::<T>::VerificationError
+					},
+				})?;
+			Ok(())
 		}
 
 		fn verified_step_call(
@@ -1337,8 +2422,8 @@ pub mod pallet {
 			WhitelistedDomains::<T>::get().contains(&domain)
 		}
 
-		fn get_function_ids() -> Result<(H256, H256), DispatchError> {
-			if let Some(function_ids) = FunctionIds::<T>::get() {
+		fn get_function_ids(domain: u32) -> Result<(H256, H256), DispatchError> {
+			if let Some(function_ids) = FunctionIds::<T>::get(domain) {
 				Ok(function_ids)
 			} else {
 				Err(Error::<T>::FunctionIdsAreNotSet.into())
@@ -1366,6 +2451,10 @@ where
 		matches!(call, Call::failed_send_message_txs { .. })
 	}
 
+	fn is_required() -> bool {
+		true
+	}
+
 	fn check_inherent(call: &Self::Call) -> Result<(), Self::Error> {
 		if let Call::failed_send_message_txs { failed_txs } = call {
 			let local_failed_txs =
@@ -1381,12 +2470,82 @@ pub mod weight_helper {
 	use super::*;
 
 	/// Weight for `dataAvailability::submit_data`.
-	pub fn fulfill_call<T: Config>(function_id: H256) -> (Weight, DispatchClass) {
-		if let Some((step_function_id, _)) = FunctionIds::<T>::get() {
-			if step_function_id == function_id {
-				return (T::WeightInfo::fulfill_call_step(), DispatchClass::Normal);
-			}
+	pub fn fulfill_call<T: Config>(domain: u32, function_id: H256) -> (Weight, DispatchClass) {
+		let weight = match FunctionIds::<T>::get(domain) {
+			Some((step_function_id, _)) if step_function_id == function_id => {
+				T::WeightInfo::fulfill_call_step()
+			},
+			_ => T::WeightInfo::fulfill_call_rotate(),
+		};
+		(weight.saturating_add(updater::<T>()), DispatchClass::Normal)
+	}
+
+	/// Extra weight of checking the turn of an updater and rewarding it.
+	pub fn updater<T: Config>() -> Weight {
+		// `UpdaterRotations`, `UpdaterReward` and the accounts paying and receiving the reward.
+		T::DbWeight::get().reads_writes(4, 2)
+	}
+
+	/// Weight for `vector::execute`.
+	pub fn execute<T: Config>(message: &AddressedMessage) -> Weight {
+		match message.message {
+			Message::ArbitraryMessage(ref data) => {
+				T::WeightInfo::execute_arbitrary_message(data.len() as u32)
+			},
+			Message::FungibleToken { ref asset_id, .. } => T::WeightInfo::execute_fungible_token()
+				.saturating_add(bridged_asset_transfer::<T>(asset_id))
+				.saturating_add(transfer_limits::<T>()),
 		}
-		(T::WeightInfo::fulfill_call_rotate(), DispatchClass::Normal)
+		.saturating_add(message_queue::<T>())
+	}
+
+	/// Weight for `vector::execute_batch`.
+	/// The account proof is only verified once for the whole batch.
+	pub fn execute_batch<T: Config>(messages: &MessageBatch) -> Weight {
+		let account_proof = T::WeightInfo::verify_account_proof();
+		messages.iter().fold(account_proof, |weight, (message, _)| {
+			weight.saturating_add(execute::<T>(message).saturating_sub(account_proof))
+		})
+	}
+
+	/// Extra weight of moving a bridged asset instead of the native currency.
+	pub fn bridged_asset_transfer<T: Config>(asset_id: &H256) -> Weight {
+		if *asset_id == SUPPORTED_ASSET_ID {
+			return Weight::zero();
+		}
+
+		// `BridgedAssets`, the asset details and the accounts of both parties.
+		T::DbWeight::get().reads_writes(4, 3)
+	}
+
+	/// Extra weight of checking a transfer against the transfer limits.
+	pub fn transfer_limits<T: Config>() -> Weight {
+		// Global and domain `AssetTransferLimits` and `AssetTransferUsage`.
+		T::DbWeight::get().reads_writes(4, 2)
+	}
+
+	/// Extra weight of delaying the execution of a verified message.
+	pub fn message_queue<T: Config>() -> Weight {
+		// `ChallengeWindow`, `PendingMessages` and `PinnedSlots`.
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+
+	/// Weight for `vector::force_prune`.
+	pub fn force_prune<T: Config>(limit: u32) -> Weight {
+		// `RetentionWindow`, `Head` and `OldestSlot`, then each visited slot is pruned and may
+		// relink the pinned slot before it.
+		let prune_slot = T::WeightInfo::prune_slot().saturating_add(T::DbWeight::get().writes(1));
+		T::DbWeight::get()
+			.reads_writes(3, 1)
+			.saturating_add(prune_slot.saturating_mul(limit.into()))
+	}
+
+	/// Weight for `vector::execute_pending_message`.
+	pub fn execute_pending_message<T: Config>(message_root: H256) -> Weight {
+		let asset_id = PendingMessages::<T>::get(message_root)
+			.and_then(|message| message.transfer)
+			.map_or(SUPPORTED_ASSET_ID, |(asset_id, _)| asset_id);
+		T::WeightInfo::execute_pending_message()
+			.saturating_add(bridged_asset_transfer::<T>(&asset_id))
 	}
 }
@@ -0,0 +1,358 @@
+use crate::{
+	Config, ConfigurationStorage, ExecutionStateRoots, FunctionIds, GenesisTimestamp,
+	GenesisValidatorRoot, Head, Headers, NextSlot, OldestSlot, Pallet, PendingMessage,
+	PendingMessages, RotateVerificationKey, SP1VerificationKey, SecondsPerSlot, SourceChainId,
+	StepVerificationKey, SyncCommitteeHashes, SyncCommitteePoseidons, Timestamps, Updaters,
+	LOG_TARGET,
+};
+
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+#[cfg(feature = "try-runtime")]
+use {
+	codec::{Decode, Encode},
+	frame_support::ensure,
+	sp_runtime::TryRuntimeError,
+};
+
+pub mod v1 {
+	use super::*;
+	use crate::state::Configuration;
+	use frame_support::{storage_alias, traits::ConstU32, BoundedVec, Identity};
+	use sp_core::{H256, U256};
+
+	/// Single updater of a source chain domain, replaced by `Updaters` in v3.
+	#[storage_alias]
+	pub type Updater<T: Config> = StorageMap<Pallet<T>, Identity, u32, H256>;
+
+	/// Single source chain layout of the light client storage.
+	mod v0 {
+		use super::*;
+
+		#[storage_alias]
+		pub type Head<T: Config> = StorageValue<Pallet<T>, u64>;
+		#[storage_alias]
+		pub type Headers<T: Config> = StorageMap<Pallet<T>, Identity, u64, H256>;
+		#[storage_alias]
+		pub type Timestamps<T: Config> = StorageMap<Pallet<T>, Identity, u64, u64>;
+		#[storage_alias]
+		pub type ExecutionStateRoots<T: Config> = StorageMap<Pallet<T>, Identity, u64, H256>;
+		#[storage_alias]
+		pub type SyncCommitteePoseidons<T: Config> = StorageMap<Pallet<T>, Identity, u64, U256>;
+		#[storage_alias]
+		pub type SyncCommitteeHashes<T: Config> = StorageMap<Pallet<T>, Identity, u64, H256>;
+		#[storage_alias]
+		pub type ConfigurationStorage<T: Config> = StorageValue<Pallet<T>, Configuration>;
+		#[storage_alias]
+		pub type FunctionIds<T: Config> = StorageValue<Pallet<T>, Option<(H256, H256)>>;
+		#[storage_alias]
+		pub type StepVerificationKey<T: Config> =
+			StorageValue<Pallet<T>, Option<BoundedVec<u8, ConstU32<10_000>>>>;
+		#[storage_alias]
+		pub type RotateVerificationKey<T: Config> =
+			StorageValue<Pallet<T>, Option<BoundedVec<u8, ConstU32<10_000>>>>;
+		#[storage_alias]
+		pub type GenesisValidatorRoot<T: Config> = StorageValue<Pallet<T>, H256>;
+		#[storage_alias]
+		pub type GenesisTimestamp<T: Config> = StorageValue<Pallet<T>, u64>;
+		#[storage_alias]
+		pub type SecondsPerSlot<T: Config> = StorageValue<Pallet<T>, u64>;
+		#[storage_alias]
+		pub type SourceChainId<T: Config> = StorageValue<Pallet<T>, u64>;
+		#[storage_alias]
+		pub type Updater<T: Config> = StorageValue<Pallet<T>, H256>;
+		#[storage_alias]
+		pub type SP1VerificationKey<T: Config> = StorageValue<Pallet<T>, H256>;
+	}
+
+	/// Inserts the value of a single source chain storage item, returning the number of moved values.
+	fn migrate_value<V>(old: Option<V>, insert: impl FnOnce(V)) -> u64 {
+		match old {
+			Some(value) => {
+				insert(value);
+				1
+			},
+			None => 0,
+		}
+	}
+
+	/// Moves the light client storage of the single source chain under the `Domain` key.
+	pub struct MigrateToV1<T, Domain>(PhantomData<(T, Domain)>);
+
+	impl<T: Config, Domain: Get<u32>> OnRuntimeUpgrade for MigrateToV1<T, Domain> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping source chain migration, on-chain storage version is {on_chain:?}"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let domain = Domain::get();
+			let mut migrated = 0u64;
+
+			// Entries are collected before being re-inserted, as the new keys share the prefix
+			// of the old ones.
+			let headers = v0::Headers::<T>::drain().collect::<Vec<_>>();
+			let timestamps = v0::Timestamps::<T>::drain().collect::<Vec<_>>();
+			let state_roots = v0::ExecutionStateRoots::<T>::drain().collect::<Vec<_>>();
+			let poseidons = v0::SyncCommitteePoseidons::<T>::drain().collect::<Vec<_>>();
+			let sync_committees = v0::SyncCommitteeHashes::<T>::drain().collect::<Vec<_>>();
+			migrated = migrated
+				.saturating_add(headers.len() as u64)
+				.saturating_add(timestamps.len() as u64)
+				.saturating_add(state_roots.len() as u64)
+				.saturating_add(poseidons.len() as u64)
+				.saturating_add(sync_committees.len() as u64);
+
+			for (slot, root) in headers {
+				Headers::<T>::insert(domain, slot, root);
+			}
+			for (slot, timestamp) in timestamps {
+				Timestamps::<T>::insert(domain, slot, timestamp);
+			}
+			for (slot, root) in state_roots {
+				ExecutionStateRoots::<T>::insert(domain, slot, root);
+			}
+			for (period, poseidon) in poseidons {
+				SyncCommitteePoseidons::<T>::insert(domain, period, poseidon);
+			}
+			for (period, hash) in sync_committees {
+				SyncCommitteeHashes::<T>::insert(domain, period, hash);
+			}
+
+			let values = [
+				migrate_value(v0::Head::<T>::take(), |v| Head::<T>::insert(domain, v)),
+				migrate_value(v0::ConfigurationStorage::<T>::take(), |v| {
+					ConfigurationStorage::<T>::insert(domain, v)
+				}),
+				migrate_value(v0::FunctionIds::<T>::take().flatten(), |v| {
+					FunctionIds::<T>::insert(domain, v)
+				}),
+				migrate_value(v0::StepVerificationKey::<T>::take().flatten(), |v| {
+					StepVerificationKey::<T>::insert(domain, v)
+				}),
+				migrate_value(v0::RotateVerificationKey::<T>::take().flatten(), |v| {
+					RotateVerificationKey::<T>::insert(domain, v)
+				}),
+				migrate_value(v0::GenesisValidatorRoot::<T>::take(), |v| {
+					GenesisValidatorRoot::<T>::insert(domain, v)
+				}),
+				migrate_value(v0::GenesisTimestamp::<T>::take(), |v| {
+					GenesisTimestamp::<T>::insert(domain, v)
+				}),
+				migrate_value(v0::SecondsPerSlot::<T>::take(), |v| {
+					SecondsPerSlot::<T>::insert(domain, v)
+				}),
+				migrate_value(v0::SourceChainId::<T>::take(), |v| {
+					SourceChainId::<T>::insert(domain, v)
+				}),
+				migrate_value(v0::Updater::<T>::take(), |v| {
+					Updater::<T>::insert(domain, v)
+				}),
+				migrate_value(v0::SP1VerificationKey::<T>::take(), |v| {
+					SP1VerificationKey::<T>::insert(domain, v)
+				}),
+			];
+			migrated = values
+				.iter()
+				.fold(migrated, |acc, v| acc.saturating_add(*v));
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Migrated {migrated} light client entries to source chain domain {domain}"
+			);
+			// Every value is read, every migrated entry is removed and re-inserted.
+			T::DbWeight::get().reads_writes(
+				migrated.saturating_add(12),
+				migrated.saturating_mul(2).saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let head = v0::Head::<T>::get().unwrap_or_default();
+			let headers = v0::Headers::<T>::iter().count() as u32;
+			Ok((head, headers).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (head, headers) = <(u64, u32)>::decode(&mut &state[..])
+				.map_err(|_| "Invalid light client state in pre-upgrade state")?;
+			let domain = Domain::get();
+			ensure!(
+				Head::<T>::get(domain) == head,
+				"Head was not migrated to the source chain domain"
+			);
+			ensure!(
+				Headers::<T>::iter_prefix(domain).count() as u32 == headers,
+				"Headers were not migrated to the source chain domain"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"Vector storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+	use crate::BalanceOf;
+	use codec::{Decode, Encode};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_core::H256;
+	use sp_runtime::Saturating;
+
+	/// Pending message before it recorded the slot it was proven against.
+	#[derive(Encode, Decode)]
+	struct OldPendingMessage<Balance, BlockNumber> {
+		from: H256,
+		to: H256,
+		origin_domain: u32,
+		message_id: u64,
+		transfer: Option<(H256, Balance)>,
+		executable_at: BlockNumber,
+	}
+
+	type OldPendingMessageOf<T> = OldPendingMessage<BalanceOf<T>, BlockNumberFor<T>>;
+
+	/// Lists the stored slots of every domain for pruning, and records the slot of pending
+	/// messages. Messages queued before the upgrade do not pin any slot.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping slot pruning migration, on-chain storage version is {on_chain:?}"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads = 1u64;
+			let mut writes = 1u64;
+
+			PendingMessages::<T>::translate::<OldPendingMessageOf<T>, _>(|_, old| {
+				reads.saturating_inc();
+				writes.saturating_inc();
+				Some(PendingMessage {
+					from: old.from,
+					to: old.to,
+					origin_domain: old.origin_domain,
+					slot: 0,
+					message_id: old.message_id,
+					transfer: old.transfer,
+					executable_at: old.executable_at,
+				})
+			});
+
+			for domain in Head::<T>::iter_keys().collect::<Vec<_>>() {
+				let mut slots = Headers::<T>::iter_key_prefix(domain).collect::<Vec<_>>();
+				slots.sort_unstable();
+				reads.saturating_accrue(slots.len() as u64 + 1);
+
+				let Some(oldest) = slots.first() else {
+					continue;
+				};
+				OldestSlot::<T>::insert(domain, oldest);
+				for pair in slots.windows(2) {
+					NextSlot::<T>::insert(domain, pair[0], pair[1]);
+				}
+				writes.saturating_accrue(slots.len() as u64);
+			}
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "Listed the stored slots of the light clients");
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			for (domain, head) in Head::<T>::iter() {
+				if Headers::<T>::contains_key(domain, head) {
+					ensure!(
+						OldestSlot::<T>::contains_key(domain),
+						"Stored slots of a domain were not listed"
+					);
+				}
+			}
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"Vector storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+	use frame_support::BoundedVec;
+	use sp_std::vec;
+
+	/// Turns the single updater of every domain into a set of updaters.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 2 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping updaters migration, on-chain storage version is {on_chain:?}"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let updaters = v1::Updater::<T>::drain().collect::<Vec<_>>();
+			let migrated = updaters.len() as u64;
+			for (domain, updater) in updaters {
+				Updaters::<T>::insert(domain, BoundedVec::truncate_from(vec![updater]));
+			}
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "Migrated {migrated} updaters");
+			T::DbWeight::get().reads_writes(
+				migrated.saturating_add(1),
+				migrated.saturating_mul(2).saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let updaters = v1::Updater::<T>::iter().collect::<Vec<_>>();
+			Ok(updaters.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let updaters = <Vec<(u32, sp_core::H256)>>::decode(&mut &state[..])
+				.map_err(|_| "Invalid updaters in pre-upgrade state")?;
+			for (domain, updater) in updaters {
+				ensure!(
+					Updaters::<T>::get(domain).into_inner() == vec![updater],
+					"Updater was not migrated"
+				);
+			}
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 3,
+				"Vector storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
@@ -1,5 +1,11 @@
-use frame_support::{derive_impl, parameter_types, traits::ConstU64, PalletId};
-use frame_system::{native::hosted_header_builder::da, test_utils::TestRandomness};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64},
+	PalletId,
+};
+use frame_system::{
+	native::hosted_header_builder::da, test_utils::TestRandomness, EnsureRoot, EnsureSigned,
+};
 use hex_literal::hex;
 use primitive_types::H256;
 use sp_runtime::{
@@ -14,6 +20,8 @@ type Extrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockDaBlock<Test>;
 type Header = <Block as BlockT>::Header;
 
+/// Source chain domain tracked by the light client at genesis.
+pub const SOURCE_DOMAIN: u32 = 2;
 pub const STEP_FUNCTION_ID: H256 = H256(hex!(
 	"af44af6890508b3b7f6910d4a4570a0d524769a23ce340b2c7400e140ad168ab"
 ));
@@ -167,6 +175,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Timestamp: pallet_timestamp,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		Bridge: vector_bridge,
 	}
 );
@@ -201,6 +210,29 @@ impl pallet_balances::Config for Test {
 	type ExistentialDeposit = ExistentialDeposit;
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+	type ForceOrigin = EnsureRoot<AccountId32>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
@@ -216,6 +248,8 @@ parameter_types! {
 impl vector_bridge::Config for Test {
 	type TimeProvider = Timestamp;
 	type Currency = Balances;
+	type Assets = Assets;
+	type GuardianOrigin = EnsureRoot<AccountId32>;
 }
 
 /// Create new externalities for `Vector` module tests.
@@ -226,7 +260,10 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		.expect("Genesis build should work");
 
 	pallet_balances::GenesisConfig::<Test> {
-		balances: vec![(Bridge::account_id(), 2_000 * 1000000000000000000)],
+		balances: vec![
+			(Bridge::account_id(), 2_000 * 1000000000000000000),
+			(Bridge::reward_account_id(), 1_000 * 1000000000000000000),
+		],
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
@@ -235,6 +272,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		function_ids: (STEP_FUNCTION_ID, ROTATE_FUNCTION_ID),
 		step_verification_key: STEP_VK.as_bytes().to_vec(),
 		rotate_verification_key: ROTATE_VK.as_bytes().to_vec(),
+		broadcaster_domain: SOURCE_DOMAIN,
 		whitelisted_domains: vec![2],
 		..Default::default()
 	}
@@ -1,4 +1,6 @@
 use codec::{Decode, Encode, MaxEncodedLen};
+#[cfg(feature = "std")]
+use frame_support::{Deserialize, Serialize};
 use patricia_merkle_trie::{keccak256, EIP1186Layout, StorageProof};
 use primitive_types::{H160, H256};
 use rlp::Rlp;
@@ -8,10 +10,13 @@ use sp_std::vec::Vec;
 use trie_db::{Trie, TrieDBBuilder};
 
 #[derive(Clone, Copy, Default, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum MessageStatusEnum {
 	#[default]
 	NotExecuted,
 	ExecutionSucceeded,
+	ExecutionPending,
+	ExecutionCancelled,
 }
 
 #[derive(Debug, PartialEq)]
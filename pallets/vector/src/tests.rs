@@ -1,17 +1,20 @@
 use crate::{
 	mock::{
-		new_test_ext, Balances, Bridge, RuntimeEvent, RuntimeOrigin, System, Test,
-		ROTATE_FUNCTION_ID, ROTATE_VK, STEP_FUNCTION_ID, STEP_VK,
+		new_test_ext, Assets, Balances, Bridge, RuntimeEvent, RuntimeOrigin, System, Test,
+		ROTATE_FUNCTION_ID, ROTATE_VK, SOURCE_DOMAIN, STEP_FUNCTION_ID, STEP_VK,
 	},
 	state::Configuration,
 	storage_utils::MessageStatusEnum,
-	Broadcasters, ConfigurationStorage, Error, Event, ExecutionStateRoots, FunctionIds,
-	FunctionInput, FunctionOutput, FunctionProof, Head, Headers, MessageStatus, MockEnabled,
-	ProofOutputs, RotateVerificationKey, SP1VerificationKey, SourceChainFrozen,
-	StepVerificationKey, SyncCommitteeHashes, SyncCommitteePoseidons, Updater, ValidProof,
-	WhitelistedDomains,
+	AssetTransferLimits, AssetTransferMode, AssetTransferUsage, BridgedAsset, BridgedAssets,
+	Broadcasters, ChallengeWindow, ConfigurationStorage, Error, Event, ExecutionStateRoots,
+	FunctionIds, FunctionInput, FunctionOutput, FunctionProof, FunctionVerifier, FunctionVerifiers,
+	Head, Headers, MessageStatus, MockEnabled, NextSlot, OldestSlot, PendingMessages, PinnedSlots,
+	ProofOutputs, ProofSystem, RetentionWindow, RotateVerificationKey, SP1VerificationKey,
+	SourceChainFrozen, StepVerificationKey, SyncCommitteeHashes, SyncCommitteePoseidons,
+	Timestamps, TransferLimitScope, TransferLimits, TransferUsage, UpdaterRotation, Updaters,
+	ValidProof, WhitelistedDomains,
 };
-use alloy_sol_types::private::primitives::hex::ToHex;
+use alloy_sol_types::private::primitives::{hex::ToHex, FixedBytes, U256 as AlloyU256};
 use alloy_sol_types::SolValue;
 use avail_core::data_proof::Message::FungibleToken;
 use avail_core::data_proof::{tx_uid, AddressedMessage, Message};
@@ -19,10 +22,16 @@ use avail_core::fail;
 use codec::Encode;
 use frame_support::{
 	assert_err, assert_ok,
-	traits::{fungible::Inspect, DefensiveTruncateFrom},
+	dispatch::{DispatchResultWithPostInfo, Pays},
+	traits::{
+		fungible::Inspect,
+		fungibles::{Inspect as _, Mutate as _},
+		DefensiveTruncateFrom,
+	},
+	weights::Weight,
 	BoundedVec,
 };
-use frame_system::RawOrigin;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use hex_literal::hex;
 use primitive_types::U256;
 use sp1_sdk::SP1ProofWithPublicValues;
@@ -195,14 +204,18 @@ fn get_valid_amb_message() -> AddressedMessage {
 fn test_fulfill_step_call_proof_not_valid() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
 			STEP_FUNCTION_ID,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -218,16 +231,20 @@ fn test_fulfill_step_call_proof_not_valid() {
 fn test_fulfill_step_call_not_valid_function_id() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 		let invalid_function_id: H256 = H256(hex!(
 			"bf44af6890508b3b7f6910d4a4570a0d524769a23ce340b2c7400e140ad168ab"
 		));
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
 			invalid_function_id,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -243,20 +260,25 @@ fn test_fulfill_step_call_not_valid_function_id() {
 fn test_fulfill_step_call_finality_not_met() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 		SyncCommitteePoseidons::<Test>::insert(
+			SOURCE_DOMAIN,
 			931,
 			U256::from(hex!(
 				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
 			)),
 		);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 512, // max finality
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 512, // max finality
+			},
+		);
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
 			STEP_FUNCTION_ID,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -272,11 +294,14 @@ fn test_fulfill_step_call_finality_not_met() {
 fn test_fulfill_step_call_wrong_updater_address() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 		let invalid_function_id: H256 = H256(hex!(
 			"bf44af6890508b3b7f6910d4a4570a0d524769a23ce340b2c7400e140ad168ab"
 		));
@@ -285,6 +310,7 @@ fn test_fulfill_step_call_wrong_updater_address() {
 
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(wrong_updater),
+			SOURCE_DOMAIN,
 			invalid_function_id,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -308,7 +334,8 @@ fn test_execute_fungible_token_via_storage() {
 		);
 
 		let slot = 8581263;
-		ExecutionStateRoots::<Test>::set(
+		ExecutionStateRoots::<Test>::insert(
+			SOURCE_DOMAIN,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -359,7 +386,8 @@ fn test_execute_fungible_token_via_storage_with_trimmed_storage_value() {
 		);
 
 		let slot = 4965568;
-		ExecutionStateRoots::<Test>::set(
+		ExecutionStateRoots::<Test>::insert(
+			SOURCE_DOMAIN,
 			slot,
 			H256(hex!(
 				"5e3fe0dd03c7ce3f89524cfa65545232bbf52645b52ac0a3939f766540a6ed69"
@@ -425,7 +453,8 @@ fn test_execute_message_with_frozen_chain() {
 		);
 
 		let slot = 8581263;
-		ExecutionStateRoots::<Test>::set(
+		ExecutionStateRoots::<Test>::insert(
+			SOURCE_DOMAIN,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -461,7 +490,8 @@ fn test_execute_message_with_faulty_account_proof() {
 		);
 
 		let slot = 8581263;
-		ExecutionStateRoots::<Test>::set(
+		ExecutionStateRoots::<Test>::insert(
+			SOURCE_DOMAIN,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -496,7 +526,8 @@ fn test_execute_message_with_faulty_storage_proof() {
 		);
 
 		let slot = 8581263;
-		ExecutionStateRoots::<Test>::set(
+		ExecutionStateRoots::<Test>::insert(
+			SOURCE_DOMAIN,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -532,7 +563,8 @@ fn test_execute_message_with_already_executed_message() {
 		);
 
 		let slot = 8581263;
-		ExecutionStateRoots::<Test>::set(
+		ExecutionStateRoots::<Test>::insert(
+			SOURCE_DOMAIN,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -587,7 +619,8 @@ fn test_execute_message_with_unsupported_domain() {
 		);
 
 		let slot = 8581263;
-		ExecutionStateRoots::<Test>::set(
+		ExecutionStateRoots::<Test>::insert(
+			SOURCE_DOMAIN,
 			slot,
 			H256(hex!(
 				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
@@ -617,22 +650,27 @@ fn test_execute_message_with_unsupported_domain() {
 fn test_fulfill_step_call() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
 		SyncCommitteePoseidons::<Test>::insert(
+			SOURCE_DOMAIN,
 			931,
 			U256::from(hex!(
 				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
 			)),
 		);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
 			STEP_FUNCTION_ID,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -644,6 +682,7 @@ fn test_fulfill_step_call() {
 		let finalized_slot = 7634848;
 		// ensure that event is fired
 		let expected_event = RuntimeEvent::Bridge(Event::HeadUpdated {
+			domain: SOURCE_DOMAIN,
 			slot: finalized_slot,
 			finalization_root: H256(hex!(
 				"e4566e0cf4edb171a3eedd59f9943bbcd0b1f6b648f1a6e26d5264b668ab41ec"
@@ -655,9 +694,9 @@ fn test_fulfill_step_call() {
 
 		let finalized_slot = 7634848;
 
-		let header = Headers::<Test>::get(finalized_slot);
-		let head = Head::<Test>::get();
-		let ex_state_root = ExecutionStateRoots::<Test>::get(finalized_slot);
+		let header = Headers::<Test>::get(SOURCE_DOMAIN, finalized_slot);
+		let head = Head::<Test>::get(SOURCE_DOMAIN);
+		let ex_state_root = ExecutionStateRoots::<Test>::get(SOURCE_DOMAIN, finalized_slot);
 
 		assert_eq!(
 			header,
@@ -680,23 +719,28 @@ fn test_fulfill_step_call() {
 fn test_fulfill_step_call_wrong_poseidon() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
 		// current poseidon is not the same as the one in the valid proof
 		SyncCommitteePoseidons::<Test>::insert(
+			SOURCE_DOMAIN,
 			931,
 			U256::from(hex!(
 				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332da"
 			)),
 		);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
 			STEP_FUNCTION_ID,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -712,8 +756,9 @@ fn test_fulfill_step_call_wrong_poseidon() {
 fn test_fulfill_step_call_slot_behind_head() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 		SyncCommitteePoseidons::<Test>::insert(
+			SOURCE_DOMAIN,
 			931,
 			U256::from(hex!(
 				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
@@ -721,15 +766,19 @@ fn test_fulfill_step_call_slot_behind_head() {
 		);
 
 		// move head forward
-		Head::<Test>::set(8634942);
+		Head::<Test>::insert(SOURCE_DOMAIN, 8634942);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
 			STEP_FUNCTION_ID,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -745,14 +794,18 @@ fn test_fulfill_step_call_slot_behind_head() {
 fn test_fulfill_rotate_call() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 342,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 342,
+			},
+		);
 
-		Headers::<Test>::set(
+		Headers::<Test>::insert(
+			SOURCE_DOMAIN,
 			slot,
 			H256(hex!(
 				"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57856"
@@ -761,6 +814,7 @@ fn test_fulfill_rotate_call() {
 
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
 			ROTATE_FUNCTION_ID,
 			get_valid_rotate_input(),
 			get_valid_rotate_output(),
@@ -777,11 +831,12 @@ fn test_fulfill_rotate_call() {
 
 		let current_period = 931;
 		let expected_event = RuntimeEvent::Bridge(Event::SyncCommitteeUpdated {
+			domain: SOURCE_DOMAIN,
 			period: current_period + 1,
 			root: expected_poseidon,
 		});
 
-		let poseidon = SyncCommitteePoseidons::<Test>::get(current_period + 1);
+		let poseidon = SyncCommitteePoseidons::<Test>::get(SOURCE_DOMAIN, current_period + 1);
 
 		assert_eq!(expected_event, System::events()[0].event);
 		assert_eq!(poseidon, expected_poseidon);
@@ -792,14 +847,18 @@ fn test_fulfill_rotate_call() {
 fn test_fulfill_rotate_call_wrong_header() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 342,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 342,
+			},
+		);
 		// set current wrong header for valid rotate call
-		Headers::<Test>::set(
+		Headers::<Test>::insert(
+			SOURCE_DOMAIN,
 			slot,
 			H256(hex!(
 				"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57855"
@@ -808,6 +867,7 @@ fn test_fulfill_rotate_call_wrong_header() {
 
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
 			ROTATE_FUNCTION_ID,
 			get_valid_rotate_input(),
 			get_valid_rotate_output(),
@@ -822,12 +882,13 @@ fn test_fulfill_rotate_call_wrong_header() {
 #[test]
 fn test_fulfill_call_function_ids_not_set() {
 	new_test_ext().execute_with(|| {
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
-		Bridge::set_function_ids(RawOrigin::Root.into(), None).unwrap();
+		Bridge::set_function_ids(RawOrigin::Root.into(), SOURCE_DOMAIN, None).unwrap();
 		let slot = 7634942;
 		let err = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
 			STEP_FUNCTION_ID,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -841,25 +902,30 @@ fn test_fulfill_call_function_ids_not_set() {
 #[test]
 fn test_fulfill_step_call_verification_key_is_not_set() {
 	new_test_ext().execute_with(|| {
-		Bridge::set_step_verification_key(RawOrigin::Root.into(), None).unwrap();
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Bridge::set_step_verification_key(RawOrigin::Root.into(), SOURCE_DOMAIN, None).unwrap();
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
 		let slot = 7634942;
 
 		SyncCommitteePoseidons::<Test>::insert(
+			SOURCE_DOMAIN,
 			931,
 			U256::from(hex!(
 				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
 			)),
 		);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 461,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
 
 		let err = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
 			STEP_FUNCTION_ID,
 			get_valid_step_input(),
 			get_valid_step_output(),
@@ -870,19 +936,97 @@ fn test_fulfill_step_call_verification_key_is_not_set() {
 	});
 }
 
+#[test]
+fn test_fulfill_step_call_with_function_verifier() {
+	new_test_ext().execute_with(|| {
+		Bridge::set_step_verification_key(RawOrigin::Root.into(), SOURCE_DOMAIN, None).unwrap();
+		let verifier = FunctionVerifier {
+			system: ProofSystem::Groth16Bn254,
+			verification_key: BoundedVec::truncate_from(STEP_VK.as_bytes().to_vec()),
+		};
+		FunctionVerifiers::<Test>::insert(STEP_FUNCTION_ID, verifier);
+
+		let slot = 7634942;
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
+		SyncCommitteePoseidons::<Test>::insert(
+			SOURCE_DOMAIN,
+			931,
+			U256::from(hex!(
+				"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
+			)),
+		);
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
+
+		let ok = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
+			STEP_FUNCTION_ID,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+		);
+		assert_ok!(ok);
+		assert_eq!(Head::<Test>::get(SOURCE_DOMAIN), 7634848);
+	});
+}
+
+#[test]
+fn test_fulfill_step_call_with_other_proof_system() {
+	new_test_ext().execute_with(|| {
+		let verifier = FunctionVerifier {
+			system: ProofSystem::SP1,
+			verification_key: BoundedVec::truncate_from(H256::random().as_bytes().to_vec()),
+		};
+		FunctionVerifiers::<Test>::insert(STEP_FUNCTION_ID, verifier);
+
+		let slot = 7634942;
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 461,
+			},
+		);
+
+		// Groth16 proof is rejected by the SP1 verifier of the function id
+		let err = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
+			STEP_FUNCTION_ID,
+			get_valid_step_input(),
+			get_valid_step_output(),
+			get_valid_step_proof(),
+			slot,
+		);
+		assert_err!(err, Error::<Test>::VerificationFailed);
+	});
+}
+
 #[test]
 fn test_fulfill_rotate_call_verification_key_is_not_set() {
 	new_test_ext().execute_with(|| {
-		Bridge::set_rotate_verification_key(RawOrigin::Root.into(), None).unwrap();
+		Bridge::set_rotate_verification_key(RawOrigin::Root.into(), SOURCE_DOMAIN, None).unwrap();
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 8192,
-			finality_threshold: 342,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 342,
+			},
+		);
 
-		Headers::<Test>::set(
+		Headers::<Test>::insert(
+			SOURCE_DOMAIN,
 			slot,
 			H256(hex!(
 				"e882fe800bed07205bf2cbf17f30148b335d143a91811ff65280c221c9f57856"
@@ -891,6 +1035,7 @@ fn test_fulfill_rotate_call_verification_key_is_not_set() {
 
 		let err = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			SOURCE_DOMAIN,
 			ROTATE_FUNCTION_ID,
 			get_valid_rotate_input(),
 			get_valid_rotate_output(),
@@ -932,13 +1077,14 @@ fn set_configuration_works_with_root() {
 			slots_per_period: 1,
 			finality_threshold: 69,
 		};
-		assert_ne!(ConfigurationStorage::<Test>::get(), conf);
+		assert_ne!(ConfigurationStorage::<Test>::get(SOURCE_DOMAIN), conf);
 
-		let ok = Bridge::set_configuration(RawOrigin::Root.into(), conf);
+		let ok = Bridge::set_configuration(RawOrigin::Root.into(), SOURCE_DOMAIN, conf);
 		assert_ok!(ok);
-		assert_eq!(ConfigurationStorage::<Test>::get(), conf);
+		assert_eq!(ConfigurationStorage::<Test>::get(SOURCE_DOMAIN), conf);
 
 		let expected_event = RuntimeEvent::Bridge(Event::ConfigurationUpdated {
+			domain: SOURCE_DOMAIN,
 			slots_per_period: conf.slots_per_period,
 			finality_threshold: conf.finality_threshold,
 		});
@@ -946,6 +1092,88 @@ fn set_configuration_works_with_root() {
 	});
 }
 
+#[test]
+fn light_clients_are_tracked_per_domain() {
+	new_test_ext().execute_with(|| {
+		let other_domain = SOURCE_DOMAIN + 1;
+		let source_conf = ConfigurationStorage::<Test>::get(SOURCE_DOMAIN);
+		let conf = Configuration {
+			slots_per_period: 32,
+			finality_threshold: 21,
+		};
+
+		assert_ok!(Bridge::set_configuration(
+			RawOrigin::Root.into(),
+			other_domain,
+			conf
+		));
+		assert_ok!(Bridge::set_updater(
+			RawOrigin::Root.into(),
+			other_domain,
+			H256(TEST_SENDER_VEC)
+		));
+
+		assert_eq!(ConfigurationStorage::<Test>::get(other_domain), conf);
+		assert_eq!(
+			ConfigurationStorage::<Test>::get(SOURCE_DOMAIN),
+			source_conf
+		);
+		assert_eq!(
+			Updaters::<Test>::get(other_domain).into_inner(),
+			vec![H256(TEST_SENDER_VEC)]
+		);
+		assert!(Updaters::<Test>::get(SOURCE_DOMAIN).is_empty());
+		assert_eq!(FunctionIds::<Test>::get(other_domain), None);
+		assert_eq!(
+			FunctionIds::<Test>::get(SOURCE_DOMAIN),
+			Some((STEP_FUNCTION_ID, ROTATE_FUNCTION_ID))
+		);
+	});
+}
+
+#[test]
+fn migration_rekeys_light_client_storage_by_domain() {
+	use crate::{migrations::v1::MigrateToV1, Pallet};
+	use frame_support::{
+		storage::{unhashed, StoragePrefixedMap},
+		traits::{ConstU32, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	new_test_ext().execute_with(|| {
+		let header = H256::random();
+		let conf = Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 342,
+		};
+
+		// Clear the genesis state and write the single source chain layout.
+		let _ = ConfigurationStorage::<Test>::clear(u32::MAX, None);
+		let _ = FunctionIds::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(0).put::<Pallet<Test>>();
+		unhashed::put(&Head::<Test>::final_prefix(), &7u64);
+		unhashed::put(&ConfigurationStorage::<Test>::final_prefix(), &conf);
+		unhashed::put(
+			&FunctionIds::<Test>::final_prefix(),
+			&Some((STEP_FUNCTION_ID, ROTATE_FUNCTION_ID)),
+		);
+		let mut header_key = Headers::<Test>::final_prefix().to_vec();
+		header_key.extend(7u64.encode());
+		unhashed::put(&header_key, &header);
+
+		MigrateToV1::<Test, ConstU32<SOURCE_DOMAIN>>::on_runtime_upgrade();
+
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+		assert_eq!(Head::<Test>::get(SOURCE_DOMAIN), 7);
+		assert_eq!(Headers::<Test>::get(SOURCE_DOMAIN, 7), header);
+		assert_eq!(ConfigurationStorage::<Test>::get(SOURCE_DOMAIN), conf);
+		assert_eq!(
+			FunctionIds::<Test>::get(SOURCE_DOMAIN),
+			Some((STEP_FUNCTION_ID, ROTATE_FUNCTION_ID))
+		);
+		assert!(unhashed::get_raw(&header_key).is_none());
+	});
+}
+
 #[test]
 fn set_configuration_does_not_work_with_non_root() {
 	new_test_ext().execute_with(|| {
@@ -955,7 +1183,7 @@ fn set_configuration_does_not_work_with_non_root() {
 		};
 
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let ok = Bridge::set_configuration(origin, conf);
+		let ok = Bridge::set_configuration(origin, SOURCE_DOMAIN, conf);
 		assert_err!(ok, BadOrigin);
 	});
 }
@@ -1005,13 +1233,24 @@ fn set_poseidon_hash_works_with_root() {
 			"1780731860627700044960722568376592200742329637303199754547598369979440671",
 		)
 		.unwrap();
-		assert_ne!(SyncCommitteePoseidons::<Test>::get(period), root);
+		assert_ne!(
+			SyncCommitteePoseidons::<Test>::get(SOURCE_DOMAIN, period),
+			root
+		);
 
-		let ok = Bridge::set_poseidon_hash(RawOrigin::Root.into(), period, poseidon_hash);
+		let ok =
+			Bridge::set_poseidon_hash(RawOrigin::Root.into(), SOURCE_DOMAIN, period, poseidon_hash);
 		assert_ok!(ok);
-		assert_eq!(SyncCommitteePoseidons::<Test>::get(period), root);
+		assert_eq!(
+			SyncCommitteePoseidons::<Test>::get(SOURCE_DOMAIN, period),
+			root
+		);
 
-		let expected_event = RuntimeEvent::Bridge(Event::SyncCommitteeUpdated { period, root });
+		let expected_event = RuntimeEvent::Bridge(Event::SyncCommitteeUpdated {
+			domain: SOURCE_DOMAIN,
+			period,
+			root,
+		});
 		System::assert_last_event(expected_event);
 	});
 }
@@ -1029,9 +1268,13 @@ fn set_poseidon_hash_wrong_hash_length() {
 		)
 		.unwrap();
 
-		let error = Bridge::set_poseidon_hash(RawOrigin::Root.into(), period, poseidon_hash);
+		let error =
+			Bridge::set_poseidon_hash(RawOrigin::Root.into(), SOURCE_DOMAIN, period, poseidon_hash);
 		assert_err!(error, Error::<Test>::CannotParseOutputData);
-		assert_eq!(SyncCommitteePoseidons::<Test>::get(period), U256::zero());
+		assert_eq!(
+			SyncCommitteePoseidons::<Test>::get(SOURCE_DOMAIN, period),
+			U256::zero()
+		);
 	});
 }
 
@@ -1041,7 +1284,7 @@ fn set_poseidon_hash_does_not_work_with_non_root() {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
 		let root = BoundedVec::try_from([0, 1, 2, 3, 4].to_vec()).unwrap();
 
-		let ok = Bridge::set_poseidon_hash(origin, 2, root);
+		let ok = Bridge::set_poseidon_hash(origin, SOURCE_DOMAIN, 2, root);
 		assert_err!(ok, BadOrigin);
 	});
 }
@@ -1155,103 +1398,895 @@ fn send_message_fungible_token_does_not_accept_zero_amount() {
 	});
 }
 
+const BRIDGED_ASSET_ID: H256 = H256::repeat_byte(1);
+const LOCAL_ASSET_ID: u32 = 7;
+
+fn register_bridged_asset(mode: AssetTransferMode) {
+	assert_ok!(Assets::force_create(
+		RawOrigin::Root.into(),
+		LOCAL_ASSET_ID.into(),
+		Bridge::account_id(),
+		true,
+		1
+	));
+	assert_ok!(Bridge::register_asset(
+		RawOrigin::Root.into(),
+		BRIDGED_ASSET_ID,
+		BridgedAsset {
+			id: LOCAL_ASSET_ID,
+			mode
+		}
+	));
+	assert_ok!(Assets::mint_into(
+		LOCAL_ASSET_ID,
+		&TEST_SENDER_ACCOUNT,
+		1_000
+	));
+}
+
 #[test]
-fn execute_arbitrary_message_works() {
+fn send_message_bridged_asset_burns() {
 	new_test_ext().execute_with(|| {
-		use crate::BalanceOf;
-		use frame_support::traits::Currency;
+		register_bridged_asset(AssetTransferMode::MintBurn);
 
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		Balances::make_free_balance_be(
-			&TEST_SENDER_VEC.into(),
-			BalanceOf::<Test>::max_value() / 2u128,
-		);
+		let message = Message::FungibleToken {
+			asset_id: BRIDGED_ASSET_ID,
+			amount: 100,
+		};
+		assert_ok!(Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2));
 
-		Broadcasters::<Test>::set(
-			2,
-			H256(hex!(
-				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
-			)),
-		);
+		assert_eq!(Assets::balance(LOCAL_ASSET_ID, &TEST_SENDER_ACCOUNT), 900);
+		assert_eq!(Assets::balance(LOCAL_ASSET_ID, &Bridge::account_id()), 0);
+		assert_eq!(Assets::total_issuance(LOCAL_ASSET_ID), 900);
+	});
+}
 
-		let slot = 5085118;
-		ExecutionStateRoots::<Test>::set(
-			slot,
-			H256(hex!(
-				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
-			)),
-		);
+#[test]
+fn send_message_bridged_asset_locks() {
+	new_test_ext().execute_with(|| {
+		register_bridged_asset(AssetTransferMode::LockUnlock);
 
-		let message = get_valid_amb_message();
-		let account_proof = get_valid_amb_account_proof();
-		let storage_proof = get_valid_amb_storage_proof();
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::FungibleToken {
+			asset_id: BRIDGED_ASSET_ID,
+			amount: 100,
+		};
+		assert_ok!(Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2));
 
-		let ok = Bridge::execute(
-			origin,
-			slot,
-			message.clone(),
-			account_proof.clone(),
-			storage_proof.clone(),
-		);
-		assert_ok!(ok);
-		let encoded_data = message.clone().abi_encode();
-		let message_root = H256(keccak_256(encoded_data.as_slice()));
+		assert_eq!(Assets::balance(LOCAL_ASSET_ID, &TEST_SENDER_ACCOUNT), 900);
+		assert_eq!(Assets::balance(LOCAL_ASSET_ID, &Bridge::account_id()), 100);
+		assert_eq!(Assets::total_issuance(LOCAL_ASSET_ID), 1_000);
+	});
+}
 
-		let expected_event = RuntimeEvent::Bridge(Event::MessageExecuted {
-			from: message.from,
-			to: message.to,
-			message_id: message.id,
-			message_root,
-		});
-		System::assert_last_event(expected_event);
-		assert_eq!(
-			MessageStatus::<Test>::get(message_root),
-			MessageStatusEnum::ExecutionSucceeded
-		)
+#[test]
+fn send_message_unregistered_asset_is_not_supported() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::FungibleToken {
+			asset_id: BRIDGED_ASSET_ID,
+			amount: 100,
+		};
+
+		let err = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_err!(err, Error::<Test>::AssetNotSupported);
 	});
 }
 
 #[test]
-fn test_double_execute_arbitrary_message() {
+fn register_asset_works_with_root() {
 	new_test_ext().execute_with(|| {
-		use crate::BalanceOf;
-		use frame_support::traits::Currency;
+		let asset = BridgedAsset {
+			id: LOCAL_ASSET_ID,
+			mode: AssetTransferMode::LockUnlock,
+		};
+		assert_ok!(Bridge::register_asset(
+			RawOrigin::Root.into(),
+			BRIDGED_ASSET_ID,
+			asset
+		));
+		assert_eq!(BridgedAssets::<Test>::get(BRIDGED_ASSET_ID), Some(asset));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::AssetRegistered {
+			asset_id: BRIDGED_ASSET_ID,
+			asset,
+		}));
+
+		let err = Bridge::register_asset(RawOrigin::Root.into(), BRIDGED_ASSET_ID, asset);
+		assert_err!(err, Error::<Test>::AssetAlreadyRegistered);
+	});
+}
 
-		let origin1 = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let origin2 = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		Balances::make_free_balance_be(
-			&TEST_SENDER_VEC.into(),
-			BalanceOf::<Test>::max_value() / 2u128,
-		);
+#[test]
+fn register_asset_does_not_accept_native_asset() {
+	new_test_ext().execute_with(|| {
+		let asset = BridgedAsset {
+			id: LOCAL_ASSET_ID,
+			mode: AssetTransferMode::MintBurn,
+		};
 
-		Broadcasters::<Test>::set(
-			2,
-			H256(hex!(
-				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
-			)),
-		);
+		let err = Bridge::register_asset(RawOrigin::Root.into(), H256::zero(), asset);
+		assert_err!(err, Error::<Test>::NativeAssetCannotBeRegistered);
+	});
+}
 
-		let slot = 5085118;
-		ExecutionStateRoots::<Test>::set(
-			slot,
-			H256(hex!(
-				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
-			)),
-		);
+#[test]
+fn register_asset_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let asset = BridgedAsset {
+			id: LOCAL_ASSET_ID,
+			mode: AssetTransferMode::MintBurn,
+		};
 
-		let message = get_valid_amb_message();
+		let err = Bridge::register_asset(origin, BRIDGED_ASSET_ID, asset);
+		assert_err!(err, BadOrigin);
+	});
+}
 
-		let account_proof = get_valid_amb_account_proof();
-		let storage_proof = get_valid_amb_storage_proof();
+#[test]
+fn deregister_asset_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let err = Bridge::deregister_asset(RawOrigin::Root.into(), BRIDGED_ASSET_ID);
+		assert_err!(err, Error::<Test>::AssetNotSupported);
 
-		let ok = Bridge::execute(
-			origin1,
-			slot,
-			message.clone(),
-			account_proof.clone(),
-			storage_proof.clone(),
-		);
-		assert_ok!(ok);
+		register_bridged_asset(AssetTransferMode::MintBurn);
+		assert_ok!(Bridge::deregister_asset(
+			RawOrigin::Root.into(),
+			BRIDGED_ASSET_ID
+		));
+		assert!(!BridgedAssets::<Test>::contains_key(BRIDGED_ASSET_ID));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::AssetDeregistered {
+			asset_id: BRIDGED_ASSET_ID,
+		}));
+	});
+}
+
+const TRANSFER_AMOUNT: u128 = 1_000_000_000_000_000_000;
+
+fn set_transfer_limits(scope: TransferLimitScope, inflow: Option<u128>, outflow: Option<u128>) {
+	assert_ok!(Bridge::set_transfer_limits(
+		RawOrigin::Root.into(),
+		scope,
+		H256::zero(),
+		Some(TransferLimits { inflow, outflow })
+	));
+}
+
+fn execute_valid_message() -> (H256, DispatchResultWithPostInfo) {
+	let slot = setup_valid_message_slot();
+
+	let message = get_valid_message();
+	let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+	let result = Bridge::execute(
+		RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+		slot,
+		message,
+		get_valid_account_proof(),
+		get_valid_storage_proof(),
+	);
+
+	(message_root, result)
+}
+
+#[test]
+fn set_transfer_limits_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let scope = TransferLimitScope::Domain(2);
+		let limits = TransferLimits {
+			inflow: Some(10),
+			outflow: None,
+		};
+		assert_ok!(Bridge::set_transfer_limits(
+			RawOrigin::Root.into(),
+			scope,
+			H256::zero(),
+			Some(limits)
+		));
+		assert_eq!(
+			AssetTransferLimits::<Test>::get(scope, H256::zero()),
+			Some(limits)
+		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::TransferLimitsUpdated {
+			scope,
+			asset_id: H256::zero(),
+			limits: Some(limits),
+		}));
+
+		assert_ok!(Bridge::set_transfer_limits(
+			RawOrigin::Root.into(),
+			scope,
+			H256::zero(),
+			None
+		));
+		assert_eq!(AssetTransferLimits::<Test>::get(scope, H256::zero()), None);
+	});
+}
+
+#[test]
+fn set_transfer_limits_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let err = Bridge::set_transfer_limits(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			TransferLimitScope::Global,
+			H256::zero(),
+			None,
+		);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn send_message_fungible_token_respects_outflow_limits() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::Currency;
+
+		set_transfer_limits(TransferLimitScope::Global, None, Some(300));
+		set_transfer_limits(TransferLimitScope::Domain(2), None, Some(150));
+		Balances::make_free_balance_be(&TEST_SENDER_ACCOUNT, 1_000_000);
+
+		let send = || {
+			Bridge::send_message(
+				RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+				Message::FungibleToken {
+					asset_id: H256::zero(),
+					amount: 100,
+				},
+				ROTATE_FUNCTION_ID,
+				2,
+			)
+		};
+
+		assert_ok!(send());
+		assert_err!(send(), Error::<Test>::TransferLimitExceeded);
+		assert_eq!(
+			AssetTransferUsage::<Test>::get(TransferLimitScope::Global, H256::zero()).outflow,
+			100
+		);
+
+		// Usage is reset once the window is over.
+		System::set_block_number(100);
+		assert_ok!(send());
+		assert_eq!(
+			AssetTransferUsage::<Test>::get(TransferLimitScope::Domain(2), H256::zero()),
+			TransferUsage {
+				window: 1,
+				inflow: 0,
+				outflow: 100,
+			}
+		);
+	});
+}
+
+#[test]
+fn execute_fungible_token_over_inflow_limit_is_queued() {
+	new_test_ext().execute_with(|| {
+		set_transfer_limits(TransferLimitScope::Global, Some(TRANSFER_AMOUNT - 1), None);
+		let balance_before = Balances::balance(&Bridge::account_id());
+
+		let (message_root, result) = execute_valid_message();
+		assert_ok!(result);
+
+		assert_eq!(Balances::balance(&Bridge::account_id()), balance_before);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionPending
+		);
+		let pending = PendingMessages::<Test>::get(message_root).unwrap();
+		assert_eq!(pending.transfer, Some((H256::zero(), TRANSFER_AMOUNT)));
+		assert_eq!(pending.executable_at, 100);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::MessageQueued {
+			message_root,
+			executable_at: 100,
+		}));
+
+		let (_, result) = execute_valid_message();
+		assert_err!(result, Error::<Test>::MessagePending);
+	});
+}
+
+#[test]
+fn execute_pending_message_works() {
+	new_test_ext().execute_with(|| {
+		set_transfer_limits(
+			TransferLimitScope::Domain(2),
+			Some(TRANSFER_AMOUNT - 1),
+			None,
+		);
+		let balance_before = Balances::balance(&Bridge::account_id());
+		let (message_root, result) = execute_valid_message();
+		assert_ok!(result);
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_ACCOUNT);
+		let err = Bridge::execute_pending_message(origin.clone(), message_root);
+		assert_err!(err, Error::<Test>::PendingMessageNotReady);
+
+		// The limit still applies in the next window.
+		System::set_block_number(100);
+		let err = Bridge::execute_pending_message(origin.clone(), message_root);
+		assert_err!(err, Error::<Test>::TransferLimitExceeded);
+
+		set_transfer_limits(TransferLimitScope::Domain(2), Some(TRANSFER_AMOUNT), None);
+		assert_ok!(Bridge::execute_pending_message(
+			origin.clone(),
+			message_root
+		));
+
+		assert_eq!(
+			Balances::balance(&Bridge::account_id()),
+			balance_before - TRANSFER_AMOUNT
+		);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+		assert!(!PendingMessages::<Test>::contains_key(message_root));
+
+		let err = Bridge::execute_pending_message(origin, message_root);
+		assert_err!(err, Error::<Test>::PendingMessageNotFound);
+	});
+}
+
+fn setup_valid_message_slot() -> u64 {
+	Broadcasters::<Test>::set(
+		2,
+		H256(hex!(
+			"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+		)),
+	);
+	let slot = 8581263;
+	ExecutionStateRoots::<Test>::insert(
+		SOURCE_DOMAIN,
+		slot,
+		H256(hex!(
+			"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+		)),
+	);
+	slot
+}
+
+#[test]
+fn execute_batch_reports_per_message_outcomes() {
+	new_test_ext().execute_with(|| {
+		let slot = setup_valid_message_slot();
+		let balance_before = Balances::balance(&Bridge::account_id());
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		let other_domain = AddressedMessage {
+			origin_domain: 3,
+			..message.clone()
+		};
+		let messages = BoundedVec::truncate_from(vec![
+			(message.clone(), get_valid_storage_proof()),
+			(message, get_valid_storage_proof()),
+			(other_domain, get_valid_storage_proof()),
+		]);
+
+		assert_ok!(Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_account_proof(),
+			messages
+		));
+
+		assert_eq!(
+			Balances::balance(&Bridge::account_id()),
+			balance_before - TRANSFER_AMOUNT
+		);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+		System::assert_has_event(RuntimeEvent::Bridge(Event::BatchMessageFailed {
+			index: 1,
+			error: Error::<Test>::MessageAlreadyExecuted.into(),
+		}));
+		System::assert_has_event(RuntimeEvent::Bridge(Event::BatchMessageFailed {
+			index: 2,
+			error: Error::<Test>::OriginDomainMismatch.into(),
+		}));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::BatchExecuted {
+			executed: 1,
+			failed: 2,
+		}));
+	});
+}
+
+#[test]
+fn execute_batch_rejects_invalid_batches() {
+	new_test_ext().execute_with(|| {
+		let slot = setup_valid_message_slot();
+		let origin = RuntimeOrigin::signed(TEST_SENDER_ACCOUNT);
+
+		let err = Bridge::execute_batch(
+			origin.clone(),
+			slot,
+			get_valid_account_proof(),
+			BoundedVec::new(),
+		);
+		assert_err!(err, Error::<Test>::EmptyMessageBatch);
+
+		let messages =
+			BoundedVec::truncate_from(vec![(get_valid_message(), get_valid_storage_proof())]);
+		let err = Bridge::execute_batch(origin, slot, get_invalid_account_proof(), messages);
+		assert_err!(err, Error::<Test>::CannotGetStorageRoot);
+	});
+}
+
+#[test]
+fn set_challenge_window_works_with_root() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::set_challenge_window(RawOrigin::Root.into(), 10));
+		assert_eq!(ChallengeWindow::<Test>::get(), 10);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ChallengeWindowUpdated {
+			blocks: 10,
+		}));
+	});
+}
+
+#[test]
+fn set_challenge_window_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let err = Bridge::set_challenge_window(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), 10);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_with_challenge_window_is_delayed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::set_challenge_window(RawOrigin::Root.into(), 10));
+		let balance_before = Balances::balance(&Bridge::account_id());
+
+		let (message_root, result) = execute_valid_message();
+		assert_ok!(result);
+		assert_eq!(Balances::balance(&Bridge::account_id()), balance_before);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionPending
+		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::MessageQueued {
+			message_root,
+			executable_at: 11,
+		}));
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_ACCOUNT);
+		System::set_block_number(10);
+		let err = Bridge::execute_pending_message(origin.clone(), message_root);
+		assert_err!(err, Error::<Test>::PendingMessageNotReady);
+
+		System::set_block_number(11);
+		assert_ok!(Bridge::execute_pending_message(origin, message_root));
+		assert_eq!(
+			Balances::balance(&Bridge::account_id()),
+			balance_before - TRANSFER_AMOUNT
+		);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+	});
+}
+
+#[test]
+fn cancel_pending_message_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::set_challenge_window(RawOrigin::Root.into(), 10));
+		let (message_root, result) = execute_valid_message();
+		assert_ok!(result);
+
+		let err = Bridge::cancel_pending_message(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			message_root,
+		);
+		assert_err!(err, BadOrigin);
+
+		assert_ok!(Bridge::cancel_pending_message(
+			RawOrigin::Root.into(),
+			message_root
+		));
+		assert!(!PendingMessages::<Test>::contains_key(message_root));
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionCancelled
+		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::MessageCancelled {
+			message_root,
+		}));
+
+		// Cancelled messages can neither be claimed nor executed again.
+		System::set_block_number(11);
+		let err = Bridge::execute_pending_message(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			message_root,
+		);
+		assert_err!(err, Error::<Test>::PendingMessageNotFound);
+		let (_, result) = execute_valid_message();
+		assert_err!(result, Error::<Test>::MessageCancelled);
+	});
+}
+
+fn set_updaters(updaters: &[H256]) {
+	Updaters::<Test>::insert(SOURCE_DOMAIN, BoundedVec::truncate_from(updaters.to_vec()));
+}
+
+/// Moves the head of the light client of the source domain to `slot` with a mocked proof.
+fn mock_head_update(slot: u64) -> DispatchResultWithPostInfo {
+	let outputs = ProofOutputs {
+		executionStateRoot: FixedBytes(H256::repeat_byte(2).0),
+		newHeader: FixedBytes(H256::repeat_byte(1).0),
+		nextSyncCommitteeHash: FixedBytes::ZERO,
+		newHead: AlloyU256::from(slot),
+		prevHeader: FixedBytes::ZERO,
+		prevHead: AlloyU256::ZERO,
+		syncCommitteeHash: FixedBytes::ZERO,
+		startSyncCommitteeHash: FixedBytes::ZERO,
+	};
+
+	Bridge::mock_fulfill(
+		RuntimeOrigin::signed(TEST_SENDER_VEC.into()),
+		SOURCE_DOMAIN,
+		BoundedVec::truncate_from(outputs.abi_encode()),
+	)
+}
+
+fn setup_slots(slots: &[u64]) {
+	MockEnabled::<Test>::set(true);
+	set_updaters(&[H256(TEST_SENDER_VEC)]);
+	ConfigurationStorage::<Test>::insert(
+		SOURCE_DOMAIN,
+		Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 342,
+		},
+	);
+	for slot in slots {
+		assert_ok!(mock_head_update(*slot));
+	}
+}
+
+#[test]
+fn head_updates_are_listed_for_pruning() {
+	new_test_ext().execute_with(|| {
+		setup_slots(&[10, 20, 30]);
+
+		assert_eq!(OldestSlot::<Test>::get(SOURCE_DOMAIN), Some(10));
+		assert_eq!(NextSlot::<Test>::get(SOURCE_DOMAIN, 10), Some(20));
+		assert_eq!(NextSlot::<Test>::get(SOURCE_DOMAIN, 20), Some(30));
+		assert_eq!(NextSlot::<Test>::get(SOURCE_DOMAIN, 30), None);
+	});
+}
+
+#[test]
+fn slots_out_of_retention_window_are_pruned_on_idle() {
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		setup_slots(&[10, 20, 30, 40]);
+
+		// Nothing is pruned without a retention window.
+		Bridge::on_idle(1, Weight::MAX);
+		assert!(Headers::<Test>::contains_key(SOURCE_DOMAIN, 10));
+
+		assert_ok!(Bridge::set_retention_window(RawOrigin::Root.into(), 15));
+		Bridge::on_idle(1, Weight::MAX);
+
+		for slot in [10, 20] {
+			assert!(!Headers::<Test>::contains_key(SOURCE_DOMAIN, slot));
+			assert!(!Timestamps::<Test>::contains_key(SOURCE_DOMAIN, slot));
+			assert!(!ExecutionStateRoots::<Test>::contains_key(
+				SOURCE_DOMAIN,
+				slot
+			));
+		}
+		for slot in [30, 40] {
+			assert!(Headers::<Test>::contains_key(SOURCE_DOMAIN, slot));
+			assert!(ExecutionStateRoots::<Test>::contains_key(
+				SOURCE_DOMAIN,
+				slot
+			));
+		}
+		assert_eq!(OldestSlot::<Test>::get(SOURCE_DOMAIN), Some(30));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::SlotsPruned {
+			domain: SOURCE_DOMAIN,
+			count: 2,
+			oldest_slot: Some(30),
+		}));
+	});
+}
+
+#[test]
+fn pruning_on_idle_is_weight_bounded() {
+	use crate::weights::WeightInfo;
+	use frame_support::traits::{Get, Hooks};
+
+	new_test_ext().execute_with(|| {
+		setup_slots(&[10, 20, 30, 40]);
+		assert_ok!(Bridge::set_retention_window(RawOrigin::Root.into(), 1));
+
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		let prune_slot = <Test as crate::Config>::WeightInfo::prune_slot();
+		let limit = db_weight.reads(3).saturating_add(prune_slot);
+		assert_eq!(Bridge::on_idle(1, limit), limit);
+
+		assert!(!Headers::<Test>::contains_key(SOURCE_DOMAIN, 10));
+		assert!(Headers::<Test>::contains_key(SOURCE_DOMAIN, 20));
+		assert_eq!(OldestSlot::<Test>::get(SOURCE_DOMAIN), Some(20));
+	});
+}
+
+#[test]
+fn force_prune_skips_pinned_slots() {
+	new_test_ext().execute_with(|| {
+		setup_slots(&[10, 20, 30, 40]);
+
+		let err = Bridge::force_prune(RawOrigin::Root.into(), SOURCE_DOMAIN, 10);
+		assert_err!(err, Error::<Test>::RetentionWindowNotSet);
+
+		assert_ok!(Bridge::set_retention_window(RawOrigin::Root.into(), 1));
+		PinnedSlots::<Test>::insert(SOURCE_DOMAIN, 20, 1);
+		assert_ok!(Bridge::force_prune(
+			RawOrigin::Root.into(),
+			SOURCE_DOMAIN,
+			10
+		));
+
+		for slot in [10, 30] {
+			assert!(!Headers::<Test>::contains_key(SOURCE_DOMAIN, slot));
+			assert!(!NextSlot::<Test>::contains_key(SOURCE_DOMAIN, slot));
+		}
+		assert!(Headers::<Test>::contains_key(SOURCE_DOMAIN, 20));
+		assert_eq!(OldestSlot::<Test>::get(SOURCE_DOMAIN), Some(20));
+		assert_eq!(NextSlot::<Test>::get(SOURCE_DOMAIN, 20), Some(40));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::SlotsPruned {
+			domain: SOURCE_DOMAIN,
+			count: 2,
+			oldest_slot: Some(20),
+		}));
+
+		// The head is never pruned.
+		PinnedSlots::<Test>::remove(SOURCE_DOMAIN, 20);
+		assert_ok!(Bridge::force_prune(
+			RawOrigin::Root.into(),
+			SOURCE_DOMAIN,
+			10
+		));
+		assert_eq!(OldestSlot::<Test>::get(SOURCE_DOMAIN), Some(40));
+		assert!(Headers::<Test>::contains_key(SOURCE_DOMAIN, 40));
+	});
+}
+
+#[test]
+fn pruning_on_idle_skips_pinned_slots_cheaply() {
+	use crate::weights::WeightInfo;
+	use frame_support::traits::{Get, Hooks};
+
+	new_test_ext().execute_with(|| {
+		setup_slots(&[10, 20, 30, 40]);
+		assert_ok!(Bridge::set_retention_window(RawOrigin::Root.into(), 1));
+		PinnedSlots::<Test>::insert(SOURCE_DOMAIN, 10, 1);
+
+		// Skipping the pinned slot does not use up the weight of pruning the next one.
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		let prune_slot = <Test as crate::Config>::WeightInfo::prune_slot();
+		let limit = db_weight.reads_writes(5, 1).saturating_add(prune_slot);
+		assert_eq!(Bridge::on_idle(1, limit), limit);
+
+		assert!(Headers::<Test>::contains_key(SOURCE_DOMAIN, 10));
+		assert!(!Headers::<Test>::contains_key(SOURCE_DOMAIN, 20));
+		assert!(Headers::<Test>::contains_key(SOURCE_DOMAIN, 30));
+		assert_eq!(OldestSlot::<Test>::get(SOURCE_DOMAIN), Some(10));
+		assert_eq!(NextSlot::<Test>::get(SOURCE_DOMAIN, 10), Some(30));
+	});
+}
+
+#[test]
+fn force_prune_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_ACCOUNT);
+		let err = Bridge::force_prune(origin, SOURCE_DOMAIN, 10);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn set_retention_window_works_with_root() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::set_retention_window(RawOrigin::Root.into(), 10));
+		assert_eq!(RetentionWindow::<Test>::get(), 10);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::RetentionWindowUpdated {
+			slots: 10,
+		}));
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_ACCOUNT);
+		let err = Bridge::set_retention_window(origin, 10);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn pending_messages_pin_their_slot() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::set_challenge_window(RawOrigin::Root.into(), 10));
+		let (message_root, result) = execute_valid_message();
+		assert_ok!(result);
+
+		let slot = PendingMessages::<Test>::get(message_root).unwrap().slot;
+		assert_eq!(PinnedSlots::<Test>::get(SOURCE_DOMAIN, slot), 1);
+
+		System::set_block_number(11);
+		assert_ok!(Bridge::execute_pending_message(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			message_root
+		));
+		assert!(!PinnedSlots::<Test>::contains_key(SOURCE_DOMAIN, slot));
+	});
+}
+
+#[test]
+fn migration_lists_stored_slots() {
+	use crate::{migrations::v2::MigrateToV2, Pallet};
+	use frame_support::{
+		storage::unhashed,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<Pallet<Test>>();
+		Head::<Test>::insert(SOURCE_DOMAIN, 30);
+		for slot in [30, 10, 20] {
+			Headers::<Test>::insert(SOURCE_DOMAIN, slot, H256::repeat_byte(1));
+		}
+		let message_root = H256::repeat_byte(3);
+		let old_message = (
+			H256::repeat_byte(4),
+			H256::repeat_byte(5),
+			SOURCE_DOMAIN,
+			7u64,
+			None::<(H256, u128)>,
+			BlockNumberFor::<Test>::from(5u32),
+		);
+		unhashed::put(
+			&PendingMessages::<Test>::hashed_key_for(message_root),
+			&old_message,
+		);
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 2);
+		assert_eq!(OldestSlot::<Test>::get(SOURCE_DOMAIN), Some(10));
+		assert_eq!(NextSlot::<Test>::get(SOURCE_DOMAIN, 10), Some(20));
+		assert_eq!(NextSlot::<Test>::get(SOURCE_DOMAIN, 20), Some(30));
+		let pending = PendingMessages::<Test>::get(message_root).unwrap();
+		assert_eq!(
+			(pending.message_id, pending.slot, pending.executable_at),
+			(7, 0, 5)
+		);
+	});
+}
+
+#[test]
+fn migration_turns_updater_into_updaters() {
+	use crate::{
+		migrations::{v1, v3::MigrateToV3},
+		Pallet,
+	};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<Pallet<Test>>();
+		v1::Updater::<Test>::insert(SOURCE_DOMAIN, H256(TEST_SENDER_VEC));
+
+		MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 3);
+		assert_eq!(v1::Updater::<Test>::get(SOURCE_DOMAIN), None);
+		assert_eq!(
+			Updaters::<Test>::get(SOURCE_DOMAIN).into_inner(),
+			vec![H256(TEST_SENDER_VEC)]
+		);
+	});
+}
+
+#[test]
+fn execute_arbitrary_message_works() {
+	new_test_ext().execute_with(|| {
+		use crate::BalanceOf;
+		use frame_support::traits::Currency;
+
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		Balances::make_free_balance_be(
+			&TEST_SENDER_VEC.into(),
+			BalanceOf::<Test>::max_value() / 2u128,
+		);
+
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::insert(
+			SOURCE_DOMAIN,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let message = get_valid_amb_message();
+		let account_proof = get_valid_amb_account_proof();
+		let storage_proof = get_valid_amb_storage_proof();
+
+		let ok = Bridge::execute(
+			origin,
+			slot,
+			message.clone(),
+			account_proof.clone(),
+			storage_proof.clone(),
+		);
+		assert_ok!(ok);
+		let encoded_data = message.clone().abi_encode();
+		let message_root = H256(keccak_256(encoded_data.as_slice()));
+
+		let expected_event = RuntimeEvent::Bridge(Event::MessageExecuted {
+			from: message.from,
+			to: message.to,
+			message_id: message.id,
+			message_root,
+		});
+		System::assert_last_event(expected_event);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		)
+	});
+}
+
+#[test]
+fn test_double_execute_arbitrary_message() {
+	new_test_ext().execute_with(|| {
+		use crate::BalanceOf;
+		use frame_support::traits::Currency;
+
+		let origin1 = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let origin2 = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		Balances::make_free_balance_be(
+			&TEST_SENDER_VEC.into(),
+			BalanceOf::<Test>::max_value() / 2u128,
+		);
+
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::insert(
+			SOURCE_DOMAIN,
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+
+		let message = get_valid_amb_message();
+
+		let account_proof = get_valid_amb_account_proof();
+		let storage_proof = get_valid_amb_storage_proof();
+
+		let ok = Bridge::execute(
+			origin1,
+			slot,
+			message.clone(),
+			account_proof.clone(),
+			storage_proof.clone(),
+		);
+		assert_ok!(ok);
 
 		let err = Bridge::execute(
 			origin2,
@@ -1278,16 +2313,19 @@ fn test_double_execute_arbitrary_message() {
 #[test]
 fn set_function_ids_works_with_root() {
 	new_test_ext().execute_with(|| {
-		let ok = Bridge::set_function_ids(RawOrigin::Root.into(), None);
+		let ok = Bridge::set_function_ids(RawOrigin::Root.into(), SOURCE_DOMAIN, None);
 		assert_ok!(ok);
-		assert_eq!(FunctionIds::<Test>::get(), None);
+		assert_eq!(FunctionIds::<Test>::get(SOURCE_DOMAIN), None);
 
 		let value = Some((STEP_FUNCTION_ID, ROTATE_FUNCTION_ID));
-		let ok = Bridge::set_function_ids(RawOrigin::Root.into(), value);
+		let ok = Bridge::set_function_ids(RawOrigin::Root.into(), SOURCE_DOMAIN, value);
 		assert_ok!(ok);
-		assert_eq!(FunctionIds::<Test>::get(), value);
+		assert_eq!(FunctionIds::<Test>::get(SOURCE_DOMAIN), value);
 
-		let expected_event = RuntimeEvent::Bridge(Event::FunctionIdsUpdated { value });
+		let expected_event = RuntimeEvent::Bridge(Event::FunctionIdsUpdated {
+			domain: SOURCE_DOMAIN,
+			value,
+		});
 		System::assert_last_event(expected_event);
 	});
 }
@@ -1296,7 +2334,7 @@ fn set_function_ids_works_with_root() {
 fn set_function_ids_does_not_work_with_non_root() {
 	new_test_ext().execute_with(|| {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let ok = Bridge::set_function_ids(origin, None);
+		let ok = Bridge::set_function_ids(origin, SOURCE_DOMAIN, None);
 		assert_err!(ok, BadOrigin);
 	});
 }
@@ -1304,16 +2342,23 @@ fn set_function_ids_does_not_work_with_non_root() {
 #[test]
 fn set_step_verification_key_works_with_root() {
 	new_test_ext().execute_with(|| {
-		let ok = Bridge::set_step_verification_key(RawOrigin::Root.into(), None);
+		let ok = Bridge::set_step_verification_key(RawOrigin::Root.into(), SOURCE_DOMAIN, None);
 		assert_ok!(ok);
-		assert_eq!(StepVerificationKey::<Test>::get(), None);
+		assert_eq!(StepVerificationKey::<Test>::get(SOURCE_DOMAIN), None);
 
 		let value = Some(BoundedVec::try_from(STEP_VK.as_bytes().to_vec()).unwrap());
-		let ok = Bridge::set_step_verification_key(RawOrigin::Root.into(), value.clone());
+		let ok =
+			Bridge::set_step_verification_key(RawOrigin::Root.into(), SOURCE_DOMAIN, value.clone());
 		assert_ok!(ok);
-		assert_eq!(StepVerificationKey::<Test>::get(), value.clone());
+		assert_eq!(
+			StepVerificationKey::<Test>::get(SOURCE_DOMAIN),
+			value.clone()
+		);
 
-		let expected_event = RuntimeEvent::Bridge(Event::StepVerificationKeyUpdated { value });
+		let expected_event = RuntimeEvent::Bridge(Event::StepVerificationKeyUpdated {
+			domain: SOURCE_DOMAIN,
+			value,
+		});
 		System::assert_last_event(expected_event);
 	});
 }
@@ -1322,24 +2367,92 @@ fn set_step_verification_key_works_with_root() {
 fn set_step_verification_key_does_not_work_with_non_root() {
 	new_test_ext().execute_with(|| {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let ok = Bridge::set_step_verification_key(origin, None);
+		let ok = Bridge::set_step_verification_key(origin, SOURCE_DOMAIN, None);
 		assert_err!(ok, BadOrigin);
 	});
 }
 
+#[test]
+fn set_function_verifier_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let verifier = Some(FunctionVerifier {
+			system: ProofSystem::Plonk,
+			verification_key: BoundedVec::truncate_from(H256::random().as_bytes().to_vec()),
+		});
+		let ok = Bridge::set_function_verifier(
+			RawOrigin::Root.into(),
+			STEP_FUNCTION_ID,
+			verifier.clone(),
+		);
+		assert_ok!(ok);
+		assert_eq!(FunctionVerifiers::<Test>::get(STEP_FUNCTION_ID), verifier);
+
+		let expected_event = RuntimeEvent::Bridge(Event::FunctionVerifierUpdated {
+			function_id: STEP_FUNCTION_ID,
+			verifier,
+		});
+		System::assert_last_event(expected_event);
+
+		let ok = Bridge::set_function_verifier(RawOrigin::Root.into(), STEP_FUNCTION_ID, None);
+		assert_ok!(ok);
+		assert_eq!(FunctionVerifiers::<Test>::get(STEP_FUNCTION_ID), None);
+	});
+}
+
+#[test]
+fn set_function_verifier_malformed_verification_key() {
+	new_test_ext().execute_with(|| {
+		let groth16 = FunctionVerifier {
+			system: ProofSystem::Groth16Bn254,
+			verification_key: BoundedVec::truncate_from(b"not a json key".to_vec()),
+		};
+		let err =
+			Bridge::set_function_verifier(RawOrigin::Root.into(), STEP_FUNCTION_ID, Some(groth16));
+		assert_err!(err, Error::<Test>::MalformedVerificationKey);
+
+		let sp1 = FunctionVerifier {
+			system: ProofSystem::SP1,
+			verification_key: BoundedVec::truncate_from(STEP_VK.as_bytes().to_vec()),
+		};
+		let err =
+			Bridge::set_function_verifier(RawOrigin::Root.into(), STEP_FUNCTION_ID, Some(sp1));
+		assert_err!(err, Error::<Test>::MalformedVerificationKey);
+		assert_eq!(FunctionVerifiers::<Test>::get(STEP_FUNCTION_ID), None);
+	});
+}
+
+#[test]
+fn set_function_verifier_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let err = Bridge::set_function_verifier(origin, STEP_FUNCTION_ID, None);
+		assert_err!(err, BadOrigin);
+	});
+}
+
 #[test]
 fn set_rotate_verification_key_works_with_root() {
 	new_test_ext().execute_with(|| {
-		let ok = Bridge::set_rotate_verification_key(RawOrigin::Root.into(), None);
+		let ok = Bridge::set_rotate_verification_key(RawOrigin::Root.into(), SOURCE_DOMAIN, None);
 		assert_ok!(ok);
-		assert_eq!(RotateVerificationKey::<Test>::get(), None);
+		assert_eq!(RotateVerificationKey::<Test>::get(SOURCE_DOMAIN), None);
 
 		let value = Some(BoundedVec::try_from(ROTATE_VK.as_bytes().to_vec()).unwrap());
-		let ok = Bridge::set_rotate_verification_key(RawOrigin::Root.into(), value.clone());
+		let ok = Bridge::set_rotate_verification_key(
+			RawOrigin::Root.into(),
+			SOURCE_DOMAIN,
+			value.clone(),
+		);
 		assert_ok!(ok);
-		assert_eq!(RotateVerificationKey::<Test>::get(), value.clone());
+		assert_eq!(
+			RotateVerificationKey::<Test>::get(SOURCE_DOMAIN),
+			value.clone()
+		);
 
-		let expected_event = RuntimeEvent::Bridge(Event::RotateVerificationKeyUpdated { value });
+		let expected_event = RuntimeEvent::Bridge(Event::RotateVerificationKeyUpdated {
+			domain: SOURCE_DOMAIN,
+			value,
+		});
 		System::assert_last_event(expected_event);
 	});
 }
@@ -1348,7 +2461,7 @@ fn set_rotate_verification_key_works_with_root() {
 fn set_rotate_verification_key_does_not_work_with_non_root() {
 	new_test_ext().execute_with(|| {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let ok = Bridge::set_rotate_verification_key(origin, None);
+		let ok = Bridge::set_rotate_verification_key(origin, SOURCE_DOMAIN, None);
 		assert_err!(ok, BadOrigin);
 	});
 }
@@ -1357,14 +2470,19 @@ fn set_rotate_verification_key_does_not_work_with_non_root() {
 fn update_updater() {
 	new_test_ext().execute_with(|| {
 		let old_updater = H256(TEST_SENDER_VEC);
-		Updater::<Test>::set(old_updater);
+		set_updaters(&[old_updater, H256([3u8; 32])]);
 
 		let new_updater = H256([2u8; 32]);
-		let ok = Bridge::set_updater(RawOrigin::Root.into(), new_updater);
+		let ok = Bridge::set_updater(RawOrigin::Root.into(), SOURCE_DOMAIN, new_updater);
 		assert_ok!(ok);
+		assert_eq!(
+			Updaters::<Test>::get(SOURCE_DOMAIN).into_inner(),
+			vec![new_updater]
+		);
 
 		let expected_event = RuntimeEvent::Bridge(Event::NewUpdater {
-			old: old_updater,
+			domain: SOURCE_DOMAIN,
+			old: BoundedVec::truncate_from(vec![old_updater, H256([3u8; 32])]),
 			new: new_updater,
 		});
 		System::assert_last_event(expected_event);
@@ -1376,12 +2494,201 @@ fn update_updater_non_root() {
 	new_test_ext().execute_with(|| {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
 		let old_updater = H256(TEST_SENDER_VEC);
-		Updater::<Test>::set(old_updater);
+		set_updaters(&[old_updater]);
 		let new_updater = H256([2u8; 32]);
 
-		let err = Bridge::set_updater(origin, new_updater);
+		let err = Bridge::set_updater(origin, SOURCE_DOMAIN, new_updater);
+		assert_err!(err, BadOrigin);
+		assert_eq!(
+			Updaters::<Test>::get(SOURCE_DOMAIN).into_inner(),
+			vec![old_updater]
+		);
+	});
+}
+
+#[test]
+fn add_and_remove_updaters() {
+	new_test_ext().execute_with(|| {
+		let updater = H256([2u8; 32]);
+		assert_ok!(Bridge::add_updater(
+			RawOrigin::Root.into(),
+			SOURCE_DOMAIN,
+			updater
+		));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::UpdaterAdded {
+			domain: SOURCE_DOMAIN,
+			updater,
+		}));
+		let err = Bridge::add_updater(RawOrigin::Root.into(), SOURCE_DOMAIN, updater);
+		assert_err!(err, Error::<Test>::UpdaterAlreadyAdded);
+
+		assert_ok!(Bridge::remove_updater(
+			RawOrigin::Root.into(),
+			SOURCE_DOMAIN,
+			updater
+		));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::UpdaterRemoved {
+			domain: SOURCE_DOMAIN,
+			updater,
+		}));
+		assert!(Updaters::<Test>::get(SOURCE_DOMAIN).is_empty());
+		let err = Bridge::remove_updater(RawOrigin::Root.into(), SOURCE_DOMAIN, updater);
+		assert_err!(err, Error::<Test>::UpdaterNotFound);
+	});
+}
+
+#[test]
+fn add_updater_is_bounded() {
+	new_test_ext().execute_with(|| {
+		let max = <Test as crate::Config>::MaxUpdaters::get();
+		for i in 0..max {
+			assert_ok!(Bridge::add_updater(
+				RawOrigin::Root.into(),
+				SOURCE_DOMAIN,
+				H256::from_low_u64_be(i.into())
+			));
+		}
+
+		let err = Bridge::add_updater(RawOrigin::Root.into(), SOURCE_DOMAIN, H256([2u8; 32]));
+		assert_err!(err, Error::<Test>::TooManyUpdaters);
+	});
+}
+
+#[test]
+fn updaters_management_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_ACCOUNT);
+		let updater = H256(TEST_SENDER_VEC);
+
+		let err = Bridge::add_updater(origin.clone(), SOURCE_DOMAIN, updater);
+		assert_err!(err, BadOrigin);
+		let err = Bridge::remove_updater(origin.clone(), SOURCE_DOMAIN, updater);
 		assert_err!(err, BadOrigin);
-		assert_eq!(old_updater, Updater::<Test>::get());
+		let rotation = UpdaterRotation::RoundRobin { blocks: 10 };
+		let err = Bridge::set_updater_rotation(origin.clone(), SOURCE_DOMAIN, rotation);
+		assert_err!(err, BadOrigin);
+		let err = Bridge::set_updater_reward(origin, 1);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn round_robin_updaters_take_turns() {
+	new_test_ext().execute_with(|| {
+		let other_updater = AccountId32::new([3u8; 32]);
+		setup_slots(&[]);
+		set_updaters(&[H256(TEST_SENDER_VEC), H256([3u8; 32])]);
+		let rotation = UpdaterRotation::RoundRobin { blocks: 10 };
+		assert_ok!(Bridge::set_updater_rotation(
+			RawOrigin::Root.into(),
+			SOURCE_DOMAIN,
+			rotation
+		));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::UpdaterRotationUpdated {
+			domain: SOURCE_DOMAIN,
+			rotation,
+		}));
+
+		// Block 1 is in the turn of the first updater.
+		assert_ok!(mock_head_update(10));
+		let public_values = BoundedVec::truncate_from(vec![]);
+		let err = Bridge::mock_fulfill(
+			RuntimeOrigin::signed(other_updater.clone()),
+			SOURCE_DOMAIN,
+			public_values,
+		);
+		assert_err!(err, Error::<Test>::NotUpdaterTurn);
+
+		System::set_block_number(10);
+		assert_err!(mock_head_update(20), Error::<Test>::NotUpdaterTurn);
+
+		// Any updater can submit updates on a first come basis.
+		assert_ok!(Bridge::set_updater_rotation(
+			RawOrigin::Root.into(),
+			SOURCE_DOMAIN,
+			UpdaterRotation::FirstCome
+		));
+		assert_ok!(mock_head_update(20));
+	});
+}
+
+fn setup_step_update() {
+	set_updaters(&[H256(TEST_SENDER_VEC)]);
+	SyncCommitteePoseidons::<Test>::insert(
+		SOURCE_DOMAIN,
+		931,
+		U256::from(hex!(
+			"0ab2afdc05c8b6ae1f2ab20874fb4159e25d5c1d4faa41aee232d6ab331332df"
+		)),
+	);
+	ConfigurationStorage::<Test>::insert(
+		SOURCE_DOMAIN,
+		Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 461,
+		},
+	);
+}
+
+fn fulfill_step_update() -> DispatchResultWithPostInfo {
+	Bridge::fulfill_call(
+		RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+		SOURCE_DOMAIN,
+		STEP_FUNCTION_ID,
+		get_valid_step_input(),
+		get_valid_step_output(),
+		get_valid_step_proof(),
+		7634942,
+	)
+}
+
+#[test]
+fn successful_updates_are_rewarded_and_free() {
+	new_test_ext().execute_with(|| {
+		let reward = 1_000;
+		setup_step_update();
+		assert_ok!(Bridge::set_updater_reward(RawOrigin::Root.into(), reward));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::UpdaterRewardUpdated { reward }));
+		let balance_before = Balances::balance(&TEST_SENDER_ACCOUNT);
+		let pot_balance = Balances::balance(&Bridge::account_id());
+		let reward_balance = Balances::balance(&Bridge::reward_account_id());
+
+		let result = fulfill_step_update();
+
+		assert_eq!(result.unwrap().pays_fee, Pays::No);
+		assert_eq!(
+			Balances::balance(&TEST_SENDER_ACCOUNT),
+			balance_before + reward
+		);
+		assert_eq!(
+			Balances::balance(&Bridge::reward_account_id()),
+			reward_balance - reward
+		);
+		assert_eq!(Balances::balance(&Bridge::account_id()), pot_balance);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::UpdaterRewarded {
+			domain: SOURCE_DOMAIN,
+			updater: H256(TEST_SENDER_VEC),
+			amount: reward,
+		}));
+	});
+}
+
+#[test]
+fn rewards_never_spend_the_bridge_pot() {
+	use frame_support::traits::Currency;
+
+	new_test_ext().execute_with(|| {
+		setup_step_update();
+		assert_ok!(Bridge::set_updater_reward(RawOrigin::Root.into(), 1_000));
+		Balances::make_free_balance_be(&Bridge::reward_account_id(), 0);
+		let balance_before = Balances::balance(&TEST_SENDER_ACCOUNT);
+		let pot_balance = Balances::balance(&Bridge::account_id());
+
+		// The update succeeds without a reward.
+		assert_ok!(fulfill_step_update());
+		assert_eq!(Balances::balance(&TEST_SENDER_ACCOUNT), balance_before);
+		assert_eq!(Balances::balance(&Bridge::account_id()), pot_balance);
+		assert_eq!(Head::<Test>::get(SOURCE_DOMAIN), 7634848);
 	});
 }
 
@@ -1389,15 +2696,19 @@ fn update_updater_non_root() {
 fn set_sp1_verification_key() {
 	new_test_ext().execute_with(|| {
 		let expected_event = RuntimeEvent::Bridge(Event::NewSP1VerificationKey {
+			domain: SOURCE_DOMAIN,
 			old: H256::zero(),
 			new: H256(SP1_VERIFICATION_KEY),
 		});
 
-		let ok =
-			Bridge::set_sp1_verification_key(RawOrigin::Root.into(), H256(SP1_VERIFICATION_KEY));
+		let ok = Bridge::set_sp1_verification_key(
+			RawOrigin::Root.into(),
+			SOURCE_DOMAIN,
+			H256(SP1_VERIFICATION_KEY),
+		);
 		assert_ok!(ok);
 		assert_eq!(
-			SP1VerificationKey::<Test>::get(),
+			SP1VerificationKey::<Test>::get(SOURCE_DOMAIN),
 			H256(SP1_VERIFICATION_KEY)
 		);
 		System::assert_last_event(expected_event);
@@ -1408,10 +2719,11 @@ fn set_sp1_verification_key() {
 fn set_sp1_verification_key_non_root() {
 	new_test_ext().execute_with(|| {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let err = Bridge::set_sp1_verification_key(origin, H256(SP1_VERIFICATION_KEY));
+		let err =
+			Bridge::set_sp1_verification_key(origin, SOURCE_DOMAIN, H256(SP1_VERIFICATION_KEY));
 
 		assert_err!(err, BadOrigin);
-		assert_eq!(SP1VerificationKey::<Test>::get(), H256::zero());
+		assert_eq!(SP1VerificationKey::<Test>::get(SOURCE_DOMAIN), H256::zero());
 	});
 }
 
@@ -1423,15 +2735,16 @@ fn test_fulfill_successfully() {
 
 		let public_inputs = sp1_proof_with_public_values.public_values.to_vec();
 
-		SP1VerificationKey::<Test>::set(H256(SP1_VERIFICATION_KEY));
+		SP1VerificationKey::<Test>::insert(SOURCE_DOMAIN, H256(SP1_VERIFICATION_KEY));
 		let proof_outputs: ProofOutputs = SolValue::abi_decode(&public_inputs, true).unwrap();
 		let slots_per_period = 8192;
 		let finality_threshold = 342u16;
 		let last_slot = 6867616u64;
 		let current_period = last_slot / slots_per_period;
-		Head::<Test>::set(last_slot);
+		Head::<Test>::insert(SOURCE_DOMAIN, last_slot);
 
-		SyncCommitteeHashes::<Test>::set(
+		SyncCommitteeHashes::<Test>::insert(
+			SOURCE_DOMAIN,
 			current_period,
 			H256(hex!(
 				"1010a184305750d5dbc946a74673f8391044ff0600b64a5d08b970fcdea4c055"
@@ -1440,30 +2753,34 @@ fn test_fulfill_successfully() {
 
 		let new_head = 6867936u64;
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period,
-			finality_threshold,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period,
+				finality_threshold,
+			},
+		);
 
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
 		let ok = Bridge::fulfill(
 			origin,
+			SOURCE_DOMAIN,
 			BoundedVec::truncate_from(proof),
 			BoundedVec::truncate_from(public_inputs),
 		);
 
 		assert_ok!(ok);
 
-		let header = Headers::<Test>::get(new_head);
+		let header = Headers::<Test>::get(SOURCE_DOMAIN, new_head);
 		assert_eq!(
 			H256(hex!(
 				"60d50794a5e1606dec159b5355fa24c59bb142e4f6ccd7ee1ab24ed4c569bac9"
 			)),
 			header
 		);
-		let execution_state_root = ExecutionStateRoots::<Test>::get(new_head);
+		let execution_state_root = ExecutionStateRoots::<Test>::get(SOURCE_DOMAIN, new_head);
 		assert_eq!(
 			H256(hex!(
 				"0b069d85ef05bb9ef9b803d8a3bc0a87ba417ab48755e34110d98200711a914f"
@@ -1471,7 +2788,7 @@ fn test_fulfill_successfully() {
 			execution_state_root
 		);
 
-		let sync_committee_hash = SyncCommitteeHashes::<Test>::get(new_head / 8192);
+		let sync_committee_hash = SyncCommitteeHashes::<Test>::get(SOURCE_DOMAIN, new_head / 8192);
 		assert_eq!(
 			H256(hex!(
 				"1010a184305750d5dbc946a74673f8391044ff0600b64a5d08b970fcdea4c055"
@@ -1479,7 +2796,8 @@ fn test_fulfill_successfully() {
 			sync_committee_hash
 		);
 
-		let next_sync_committee_hash = SyncCommitteeHashes::<Test>::get((new_head / 8192) + 1);
+		let next_sync_committee_hash =
+			SyncCommitteeHashes::<Test>::get(SOURCE_DOMAIN, (new_head / 8192) + 1);
 		assert_eq!(
 			H256(hex!(
 				"ade9dc800ea6fd6a4364e8db7f776b92c3736bca8ceea7b8eba4e37ea60a3a39"
@@ -1497,24 +2815,28 @@ fn test_fulfill_successfully_mock_enabled() {
 		let proof = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
 		let public_inputs = sp1_proof_with_public_values.public_values.to_vec();
 
-		SP1VerificationKey::<Test>::set(H256(SP1_VERIFICATION_KEY));
+		SP1VerificationKey::<Test>::insert(SOURCE_DOMAIN, H256(SP1_VERIFICATION_KEY));
 		let slots_per_period = 8192;
 		let finality_threshold = 342u16;
 		let last_slot = 6867616u64;
 		let current_period = last_slot / slots_per_period;
-		Head::<Test>::set(last_slot);
-		SyncCommitteeHashes::<Test>::set(
+		Head::<Test>::insert(SOURCE_DOMAIN, last_slot);
+		SyncCommitteeHashes::<Test>::insert(
+			SOURCE_DOMAIN,
 			current_period,
 			H256(hex!(
 				"1010a184305750d5dbc946a74673f8391044ff0600b64a5d08b970fcdea4c055"
 			)),
 		);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period,
-			finality_threshold,
-		});
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period,
+				finality_threshold,
+			},
+		);
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
 		// set mock flag
 		MockEnabled::<Test>::set(true);
@@ -1522,16 +2844,18 @@ fn test_fulfill_successfully_mock_enabled() {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
 		let ok = Bridge::mock_fulfill(
 			origin.clone(),
+			SOURCE_DOMAIN,
 			BoundedVec::truncate_from(public_inputs.clone()),
 		);
 
 		assert_ok!(ok);
 		// move head so that proof can pass to the validation point
-		Head::<Test>::set(last_slot + 1);
+		Head::<Test>::insert(SOURCE_DOMAIN, last_slot + 1);
 		// put back mock enabled to false, and the empty proof should fail
 		MockEnabled::<Test>::set(false);
 		let err = Bridge::fulfill(
 			origin,
+			SOURCE_DOMAIN,
 			BoundedVec::truncate_from(proof),
 			BoundedVec::truncate_from(public_inputs),
 		);
@@ -1547,24 +2871,28 @@ fn test_mock_fulfill_disabled() {
 		let sp1_proof_with_public_values = SP1ProofWithPublicValues::load(PROOF_FILE).unwrap();
 		let public_inputs = sp1_proof_with_public_values.public_values.to_vec();
 
-		SP1VerificationKey::<Test>::set(H256(SP1_VERIFICATION_KEY));
+		SP1VerificationKey::<Test>::insert(SOURCE_DOMAIN, H256(SP1_VERIFICATION_KEY));
 		let slots_per_period = 8192;
 		let finality_threshold = 342u16;
 		let last_slot = 6867616u64;
 		let current_period = last_slot / slots_per_period;
-		Head::<Test>::set(last_slot);
-		SyncCommitteeHashes::<Test>::set(
+		Head::<Test>::insert(SOURCE_DOMAIN, last_slot);
+		SyncCommitteeHashes::<Test>::insert(
+			SOURCE_DOMAIN,
 			current_period,
 			H256(hex!(
 				"1010a184305750d5dbc946a74673f8391044ff0600b64a5d08b970fcdea4c055"
 			)),
 		);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period,
-			finality_threshold,
-		});
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period,
+				finality_threshold,
+			},
+		);
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
 		// set mock flag
 		MockEnabled::<Test>::set(false);
@@ -1572,6 +2900,7 @@ fn test_mock_fulfill_disabled() {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
 		let err = Bridge::mock_fulfill(
 			origin.clone(),
+			SOURCE_DOMAIN,
 			BoundedVec::truncate_from(public_inputs.clone()),
 		);
 		assert_err!(err, Error::<Test>::MockIsNotEnabled);
@@ -1584,32 +2913,37 @@ fn test_fulfill_successfully_sync_committee_not_set() {
 		let sp1_proof_with_public_values = SP1ProofWithPublicValues::load(PROOF_FILE).unwrap();
 		let proof = sp1_proof_with_public_values.bytes();
 		let public_inputs = sp1_proof_with_public_values.public_values.to_vec();
-		SP1VerificationKey::<Test>::set(H256(SP1_VERIFICATION_KEY));
+		SP1VerificationKey::<Test>::insert(SOURCE_DOMAIN, H256(SP1_VERIFICATION_KEY));
 
 		let proof_outputs: ProofOutputs = SolValue::abi_decode(&public_inputs, true).unwrap();
 		let slots_per_period = 8192;
 		let finality_threshold = 342u16;
 		let last_slot = 6867616u64;
 		let current_period = last_slot / slots_per_period;
-		Head::<Test>::set(last_slot);
+		Head::<Test>::insert(SOURCE_DOMAIN, last_slot);
 
-		SyncCommitteeHashes::<Test>::set(
+		SyncCommitteeHashes::<Test>::insert(
+			SOURCE_DOMAIN,
 			current_period,
 			H256(hex!(
 				"1010a184305750d5dbc946a74673f8391044ff0600b64a5d08b970fcdea4c055"
 			)),
 		);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period,
-			finality_threshold,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period,
+				finality_threshold,
+			},
+		);
 
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
 		let ok = Bridge::fulfill(
 			origin,
+			SOURCE_DOMAIN,
 			BoundedVec::truncate_from(proof),
 			BoundedVec::truncate_from(public_inputs),
 		);
@@ -1617,23 +2951,23 @@ fn test_fulfill_successfully_sync_committee_not_set() {
 		assert_ok!(ok);
 		let new_head = 6867936u64;
 
-		let header = Headers::<Test>::get(new_head);
+		let header = Headers::<Test>::get(SOURCE_DOMAIN, new_head);
 
 		// assert proof outputs
 		let period = new_head / 8192;
 		assert_eq!(H256(proof_outputs.newHeader.0), header);
-		let execution_state_root = ExecutionStateRoots::<Test>::get(new_head);
+		let execution_state_root = ExecutionStateRoots::<Test>::get(SOURCE_DOMAIN, new_head);
 		assert_eq!(
 			H256(proof_outputs.executionStateRoot.0),
 			execution_state_root
 		);
-		let sync_committee_hash = SyncCommitteeHashes::<Test>::get(period + 1);
+		let sync_committee_hash = SyncCommitteeHashes::<Test>::get(SOURCE_DOMAIN, period + 1);
 		assert_eq!(
 			H256(proof_outputs.nextSyncCommitteeHash.0),
 			sync_committee_hash
 		);
 
-		let sync_committee_hash = SyncCommitteeHashes::<Test>::get(period);
+		let sync_committee_hash = SyncCommitteeHashes::<Test>::get(SOURCE_DOMAIN, period);
 		assert_eq!(H256(proof_outputs.syncCommitteeHash.0), sync_committee_hash);
 	});
 }
@@ -1648,7 +2982,7 @@ fn test_fulfill_successfully_sync_committee_not_set() {
 //         proof[10] = 0x01;
 //
 //         let public_inputs = sp1_proof_with_public_values.public_values.to_vec();
-//         SP1VerificationKey::<Test>::set(H256(SP1_VERIFICATION_KEY));
+//         SP1VerificationKey::<Test>::insert(SOURCE_DOMAIN, H256(SP1_VERIFICATION_KEY));
 //
 //         let proof_outputs: ProofOutputs = SolValue::abi_decode(&public_inputs, true).unwrap();
 //         let slots_per_period = 8192;
@@ -1656,13 +2990,14 @@ fn test_fulfill_successfully_sync_committee_not_set() {
 //         let slot = 6724864u64;
 //         let current_period = slot / slots_per_period;
 //
-//         ConfigurationStorage::<Test>::set(Configuration {
+//         ConfigurationStorage::<Test>::insert(SOURCE_DOMAIN, Configuration {
 //             slots_per_period,
 //             finality_threshold: finality_threshold as u16,
 //         });
 //
-//         Updater::<Test>::set(H256(TEST_SENDER_VEC));
-//         SyncCommitteeHashes::<Test>::set(
+//         set_updaters(&[H256(TEST_SENDER_VEC)]);
+//         SyncCommitteeHashes::<Test>::insert(
+//             SOURCE_DOMAIN,
 //             current_period,
 //             H256::from(proof_outputs.syncCommitteeHash.0),
 //         );
@@ -1670,6 +3005,7 @@ fn test_fulfill_successfully_sync_committee_not_set() {
 //         let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
 //         let err = Bridge::fulfill(
 //             origin,
+//             SOURCE_DOMAIN,
 //             BoundedVec::truncate_from(proof),
 //             BoundedVec::truncate_from(public_inputs),
 //         );
@@ -1691,31 +3027,36 @@ fn test_fulfill_incorrect_proof_output() {
 		proof_outputs.syncCommitteeHash.0 = H256::random().0;
 		let proof_outputs_vec = proof_outputs.abi_encode();
 
-		SP1VerificationKey::<Test>::set(H256(SP1_VERIFICATION_KEY));
+		SP1VerificationKey::<Test>::insert(SOURCE_DOMAIN, H256(SP1_VERIFICATION_KEY));
 
 		let proof_outputs: ProofOutputs = SolValue::abi_decode(&proof_outputs_vec, true).unwrap();
 		let slots_per_period = 8192;
 		let finality_threshold = 342u16;
 		let last_slot = 6867616u64;
 		let current_period = last_slot / slots_per_period;
-		Head::<Test>::set(last_slot);
-		SyncCommitteeHashes::<Test>::set(
+		Head::<Test>::insert(SOURCE_DOMAIN, last_slot);
+		SyncCommitteeHashes::<Test>::insert(
+			SOURCE_DOMAIN,
 			current_period,
 			H256(hex!(
 				"1010a184305750d5dbc946a74673f8391044ff0600b64a5d08b970fcdea4c055"
 			)),
 		);
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period,
-			finality_threshold,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period,
+				finality_threshold,
+			},
+		);
 
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
 
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
 		let err = Bridge::fulfill(
 			origin,
+			SOURCE_DOMAIN,
 			BoundedVec::truncate_from(proof),
 			BoundedVec::truncate_from(proof_outputs.abi_encode()),
 		);
@@ -1730,14 +3071,15 @@ fn test_fulfill_head_not_greater() {
 		let sp1_proof_with_public_values = SP1ProofWithPublicValues::load(PROOF_FILE).unwrap();
 		let proof = sp1_proof_with_public_values.bytes();
 		let public_inputs = sp1_proof_with_public_values.public_values.to_vec();
-		SP1VerificationKey::<Test>::set(H256(SP1_VERIFICATION_KEY));
+		SP1VerificationKey::<Test>::insert(SOURCE_DOMAIN, H256(SP1_VERIFICATION_KEY));
 
 		let proof_outputs: ProofOutputs = SolValue::abi_decode(&public_inputs, true).unwrap();
 		let slots_per_period = 8192;
 		let finality_threshold = 342u16;
 		let last_slot = 6867616u64;
 		let current_period = last_slot / slots_per_period;
-		SyncCommitteeHashes::<Test>::set(
+		SyncCommitteeHashes::<Test>::insert(
+			SOURCE_DOMAIN,
 			current_period,
 			H256(hex!(
 				"1010a184305750d5dbc946a74673f8391044ff0600b64a5d08b970fcdea4c055"
@@ -1746,16 +3088,20 @@ fn test_fulfill_head_not_greater() {
 
 		let new_head = 6867936u64;
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period,
-			finality_threshold: finality_threshold as u16,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period,
+				finality_threshold: finality_threshold as u16,
+			},
+		);
 
 		// set current head
-		Head::<Test>::set(new_head + 1);
+		Head::<Test>::insert(SOURCE_DOMAIN, new_head + 1);
 
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
-		SyncCommitteeHashes::<Test>::set(
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
+		SyncCommitteeHashes::<Test>::insert(
+			SOURCE_DOMAIN,
 			current_period,
 			H256::from(proof_outputs.syncCommitteeHash.0),
 		);
@@ -1763,6 +3109,7 @@ fn test_fulfill_head_not_greater() {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
 		let err = Bridge::fulfill(
 			origin,
+			SOURCE_DOMAIN,
 			BoundedVec::truncate_from(proof),
 			BoundedVec::truncate_from(public_inputs),
 		);
@@ -1777,7 +3124,7 @@ fn test_fulfill_next_without_continuation() {
 		let sp1_proof_with_public_values = SP1ProofWithPublicValues::load(PROOF_FILE).unwrap();
 		let proof = sp1_proof_with_public_values.bytes();
 		let public_inputs = sp1_proof_with_public_values.public_values.to_vec();
-		SP1VerificationKey::<Test>::set(H256(SP1_VERIFICATION_KEY));
+		SP1VerificationKey::<Test>::insert(SOURCE_DOMAIN, H256(SP1_VERIFICATION_KEY));
 
 		let proof_outputs: ProofOutputs = SolValue::abi_decode(&public_inputs, true).unwrap();
 		let slots_per_period = 8192;
@@ -1785,13 +3132,17 @@ fn test_fulfill_next_without_continuation() {
 		let last_slot = 6867616u64;
 		let current_period = last_slot / slots_per_period;
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period,
-			finality_threshold,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period,
+				finality_threshold,
+			},
+		);
 
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
-		SyncCommitteeHashes::<Test>::set(
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
+		SyncCommitteeHashes::<Test>::insert(
+			SOURCE_DOMAIN,
 			current_period,
 			H256::from(proof_outputs.syncCommitteeHash.0),
 		);
@@ -1799,6 +3150,7 @@ fn test_fulfill_next_without_continuation() {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
 		let err = Bridge::fulfill(
 			origin,
+			SOURCE_DOMAIN,
 			BoundedVec::truncate_from(proof),
 			BoundedVec::truncate_from(public_inputs),
 		);
@@ -1813,20 +3165,24 @@ fn test_fulfill_configuration_not_set() {
 		let sp1_proof_with_public_values = SP1ProofWithPublicValues::load(PROOF_FILE).unwrap();
 		let proof = sp1_proof_with_public_values.bytes();
 		let public_inputs = sp1_proof_with_public_values.public_values.to_vec();
-		SP1VerificationKey::<Test>::set(H256(SP1_VERIFICATION_KEY));
+		SP1VerificationKey::<Test>::insert(SOURCE_DOMAIN, H256(SP1_VERIFICATION_KEY));
 
 		let proof_outputs: ProofOutputs = SolValue::abi_decode(&public_inputs, true).unwrap();
 		let slots_per_period = 8192;
 		let slot = 6724864u64;
 		let current_period = slot / slots_per_period;
 
-		ConfigurationStorage::<Test>::set(Configuration {
-			slots_per_period: 0,
-			finality_threshold: 0,
-		});
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 0,
+				finality_threshold: 0,
+			},
+		);
 
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
-		SyncCommitteeHashes::<Test>::set(
+		set_updaters(&[H256(TEST_SENDER_VEC)]);
+		SyncCommitteeHashes::<Test>::insert(
+			SOURCE_DOMAIN,
 			current_period,
 			H256::from(proof_outputs.syncCommitteeHash.0),
 		);
@@ -1834,6 +3190,7 @@ fn test_fulfill_configuration_not_set() {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
 		let err = Bridge::fulfill(
 			origin,
+			SOURCE_DOMAIN,
 			BoundedVec::truncate_from(proof),
 			BoundedVec::truncate_from(public_inputs),
 		);
@@ -1849,11 +3206,19 @@ fn set_sync_committee_hash() {
 		let period = 1;
 		let hash = H256::random();
 
-		let expected_event = RuntimeEvent::Bridge(Event::SyncCommitteeHashUpdated { period, hash });
+		let expected_event = RuntimeEvent::Bridge(Event::SyncCommitteeHashUpdated {
+			domain: SOURCE_DOMAIN,
+			period,
+			hash,
+		});
 
-		let ok = Bridge::set_sync_committee_hash(RawOrigin::Root.into(), period, hash);
+		let ok =
+			Bridge::set_sync_committee_hash(RawOrigin::Root.into(), SOURCE_DOMAIN, period, hash);
 		assert_ok!(ok);
-		assert_eq!(SyncCommitteeHashes::<Test>::get(period), hash);
+		assert_eq!(
+			SyncCommitteeHashes::<Test>::get(SOURCE_DOMAIN, period),
+			hash
+		);
 		System::assert_last_event(expected_event);
 	});
 }
@@ -1864,10 +3229,36 @@ fn set_sync_committee_hash_non_root() {
 		let period = 1;
 		let hash = H256::random();
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let err = Bridge::set_sync_committee_hash(origin, period, hash);
+		let err = Bridge::set_sync_committee_hash(origin, SOURCE_DOMAIN, period, hash);
 
 		assert_err!(err, BadOrigin);
-		assert_eq!(SyncCommitteeHashes::<Test>::get(period), H256::zero());
+		assert_eq!(
+			SyncCommitteeHashes::<Test>::get(SOURCE_DOMAIN, period),
+			H256::zero()
+		);
+	});
+}
+
+#[test]
+fn current_sync_committee_hash() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Bridge::current_sync_committee_hash(SOURCE_DOMAIN), None);
+
+		let hash = H256::random();
+		ConfigurationStorage::<Test>::insert(
+			SOURCE_DOMAIN,
+			Configuration {
+				slots_per_period: 8192,
+				finality_threshold: 342,
+			},
+		);
+		Head::<Test>::insert(SOURCE_DOMAIN, 8192 * 3 + 10);
+		SyncCommitteeHashes::<Test>::insert(SOURCE_DOMAIN, 3, hash);
+
+		assert_eq!(
+			Bridge::current_sync_committee_hash(SOURCE_DOMAIN),
+			Some((3, hash))
+		);
 	});
 }
 
@@ -1,15 +1,19 @@
 use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
 use ark_groth16::{prepare_verifying_key, verify_proof, Proof, VerifyingKey};
 use ark_std::boxed::Box;
+use ark_std::format;
 use ark_std::str::FromStr;
 use ark_std::string::String;
 use ark_std::string::ToString;
 use ark_std::vec;
 use ark_std::vec::Vec;
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use ethabi::ParamType;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
+use sp1_verifier::{
+	Groth16Verifier, PlonkVerifier as SP1PlonkVerifier, GROTH16_VK_BYTES, PLONK_VK_BYTES,
+};
 use sp_core::{H256, U256};
 
 use crate::state::{CircomProof, PublicSignals};
@@ -18,6 +22,8 @@ use crate::state::{CircomProof, PublicSignals};
 pub enum VerificationError {
 	InvalidProof,
 	InvalidVK,
+	MalformedProof,
+	InvalidPublicInputs,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode, TypeInfo)]
@@ -271,6 +277,106 @@ pub fn encode_packed(poseidon: U256, slot: u64) -> Vec<u8> {
 	result
 }
 
+/// Proving system used to verify the proofs of a function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum ProofSystem {
+	/// Circom Groth16 proofs over BN254, verified with a JSON verification key.
+	Groth16Bn254,
+	/// SP1 proofs wrapped in Groth16, verified with the hash of the program verification key.
+	SP1,
+	/// SP1 proofs wrapped in PLONK, verified with the hash of the program verification key.
+	Plonk,
+}
+
+impl ProofSystem {
+	/// Verifies `proof` with the backend of the proving system.
+	pub fn verify(
+		&self,
+		vk: &[u8],
+		public_inputs: &[u8],
+		proof: &[u8],
+	) -> Result<(), VerificationError> {
+		match self {
+			Self::Groth16Bn254 => Groth16Bn254Verifier::verify(vk, public_inputs, proof),
+			Self::SP1 => SP1Verifier::verify(vk, public_inputs, proof),
+			Self::Plonk => PlonkVerifier::verify(vk, public_inputs, proof),
+		}
+	}
+
+	/// Checks that `vk` is a well-formed verification key of the proving system.
+	pub fn check_verification_key(&self, vk: &[u8]) -> Result<(), VerificationError> {
+		match self {
+			Self::Groth16Bn254 => Verifier::from_json_u8_slice(vk)
+				.map(|_| ())
+				.map_err(|_| VerificationError::InvalidVK),
+			Self::SP1 | Self::Plonk => sp1_vk_hash(vk).map(|_| ()),
+		}
+	}
+}
+
+/// Proof verification backend.
+pub trait ProofVerifier {
+	/// Verifies that `proof` proves `public_inputs` under the verification key `vk`.
+	fn verify(vk: &[u8], public_inputs: &[u8], proof: &[u8]) -> Result<(), VerificationError>;
+}
+
+/// Circom Groth16 backend.
+/// The public inputs are the input hash followed by the output hash of the function.
+pub struct Groth16Bn254Verifier;
+
+impl ProofVerifier for Groth16Bn254Verifier {
+	fn verify(vk: &[u8], public_inputs: &[u8], proof: &[u8]) -> Result<(), VerificationError> {
+		if public_inputs.len() != 64 {
+			return Err(VerificationError::InvalidPublicInputs);
+		}
+		let (input_hash, output_hash) = public_inputs.split_at(32);
+		let verifier =
+			Verifier::from_json_u8_slice(vk).map_err(|_| VerificationError::InvalidVK)?;
+
+		match verifier.verify(
+			H256::from_slice(input_hash),
+			H256::from_slice(output_hash),
+			proof.to_vec(),
+		) {
+			Ok(true) => Ok(()),
+			Ok(false) => Err(VerificationError::InvalidProof),
+			Err(_) => Err(VerificationError::MalformedProof),
+		}
+	}
+}
+
+/// SP1 Groth16 backend.
+/// The public inputs are the public values committed by the program.
+pub struct SP1Verifier;
+
+impl ProofVerifier for SP1Verifier {
+	fn verify(vk: &[u8], public_inputs: &[u8], proof: &[u8]) -> Result<(), VerificationError> {
+		let vk_hash = sp1_vk_hash(vk)?;
+		Groth16Verifier::verify(proof, public_inputs, &vk_hash, &GROTH16_VK_BYTES)
+			.map_err(|_| VerificationError::InvalidProof)
+	}
+}
+
+/// SP1 PLONK backend.
+/// The public inputs are the public values committed by the program.
+pub struct PlonkVerifier;
+
+impl ProofVerifier for PlonkVerifier {
+	fn verify(vk: &[u8], public_inputs: &[u8], proof: &[u8]) -> Result<(), VerificationError> {
+		let vk_hash = sp1_vk_hash(vk)?;
+		SP1PlonkVerifier::verify(proof, public_inputs, &vk_hash, &PLONK_VK_BYTES)
+			.map_err(|_| VerificationError::InvalidProof)
+	}
+}
+
+/// Formats the 32 bytes hash of an SP1 program verification key as expected by the SP1 verifiers.
+fn sp1_vk_hash(vk: &[u8]) -> Result<String, VerificationError> {
+	if vk.len() != 32 {
+		return Err(VerificationError::InvalidVK);
+	}
+	Ok(format!("{:?}", H256::from_slice(vk)))
+}
+
 #[cfg(test)]
 mod tests {
 	use frame_support::assert_ok;
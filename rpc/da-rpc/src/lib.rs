@@ -0,0 +1,194 @@
+use avail_core::{currency::Balance, AppId};
+use da_control::{AppKeyInfo, SubmitDataFeeEstimate};
+use da_runtime::{apis::DataAvailApi, AccountId};
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::ErrorObject,
+};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, marker::Sync, sync::Arc};
+
+pub type HashOf<Block> = <Block as BlockT>::Hash;
+
+/// Number of application keys returned by each page of `da_appKeys`.
+pub const APP_KEYS_PAGE_SIZE: u32 = 100;
+
+/// First version of `DataAvailApi` providing `app_keys` and `app_by_id`.
+const APP_KEYS_VERSION: u32 = 4;
+
+/// First version of `DataAvailApi` providing `estimate_submit_data_fee`.
+const ESTIMATE_SUBMIT_DATA_FEE_VERSION: u32 = 5;
+
+/// Application key and its associated information.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppKey {
+	pub id: AppId,
+	pub key: Bytes,
+	pub owner: AccountId,
+}
+
+impl From<(Vec<u8>, AppKeyInfo<AccountId>)> for AppKey {
+	fn from((key, info): (Vec<u8>, AppKeyInfo<AccountId>)) -> Self {
+		Self {
+			id: info.id,
+			key: key.into(),
+			owner: info.owner,
+		}
+	}
+}
+
+#[rpc(client, server)]
+pub trait DaApi<Block>
+where
+	Block: BlockT,
+{
+	/// Returns the application keys of the `page`-th range of application IDs.
+	#[method(name = "da_appKeys")]
+	async fn app_keys(&self, page: u32, at: Option<HashOf<Block>>) -> RpcResult<Vec<AppKey>>;
+
+	/// Returns the application key registered under `id`, if any.
+	#[method(name = "da_appById")]
+	async fn app_by_id(&self, id: AppId, at: Option<HashOf<Block>>) -> RpcResult<Option<AppKey>>;
+
+	/// Estimates the fee of a `submit_data` extrinsic carrying `len` bytes of data for `app_id`.
+	#[method(name = "da_estimateSubmitDataFee")]
+	async fn estimate_submit_data_fee(
+		&self,
+		len: u32,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<SubmitDataFeeEstimate<Balance>>;
+}
+
+pub struct Da<Client, Block: BlockT> {
+	client: Arc<Client>,
+	_block: PhantomData<Block>,
+}
+
+impl<Client, Block: BlockT> Da<Client, Block> {
+	pub fn new(client: Arc<Client>) -> Self {
+		Self {
+			client,
+			_block: PhantomData,
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The runtime API call failed.
+	DaRPCError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::DaRPCError => 1,
+		}
+	}
+}
+
+macro_rules! internal_err {
+	($($arg:tt)*) => {{
+		ErrorObject::owned(
+			Error::DaRPCError.into(),
+			format!($($arg)*),
+			None::<()>
+		)
+	}}
+}
+
+impl<Client, Block> Da<Client, Block>
+where
+	Block: BlockT,
+	Client: HeaderBackend<Block>,
+{
+	fn at_or_best(&self, at: Option<<Block as BlockT>::Hash>) -> <Block as BlockT>::Hash {
+		at.unwrap_or_else(|| self.client.info().best_hash)
+	}
+}
+
+impl<Client, Block> Da<Client, Block>
+where
+	Block: BlockT,
+	Client: ProvideRuntimeApi<Block>,
+	Client::Api: DataAvailApi<Block>,
+{
+	/// Fails if the runtime at `at` implements a version of `DataAvailApi` older than `version`.
+	fn ensure_api_version(&self, at: HashOf<Block>, version: u32, method: &str) -> RpcResult<()> {
+		let at_version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn DataAvailApi<Block>>(at)
+			.map_err(|e| internal_err!("DataAvailApi version unavailable at {at:?}: {e:?}"))?
+			.unwrap_or_default();
+
+		if at_version < version {
+			return Err(internal_err!(
+				"DataAvailApi::{method} is unsupported at {at:?}: runtime API version {at_version} < {version}"
+			));
+		}
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl<Client, Block> DaApiServer<Block> for Da<Client, Block>
+where
+	Block: BlockT,
+	Client: Send + Sync + 'static,
+	Client: HeaderBackend<Block> + ProvideRuntimeApi<Block>,
+	Client::Api: DataAvailApi<Block>,
+{
+	async fn app_keys(&self, page: u32, at: Option<HashOf<Block>>) -> RpcResult<Vec<AppKey>> {
+		let at = self.at_or_best(at);
+		self.ensure_api_version(at, APP_KEYS_VERSION, "app_keys")?;
+		let app_keys = self
+			.client
+			.runtime_api()
+			.app_keys(at, page, APP_KEYS_PAGE_SIZE)
+			.map_err(|e| internal_err!("DataAvailApi::app_keys failed at {at:?}: {e:?}"))?;
+
+		Ok(app_keys.into_iter().map(AppKey::from).collect())
+	}
+
+	async fn app_by_id(&self, id: AppId, at: Option<HashOf<Block>>) -> RpcResult<Option<AppKey>> {
+		let at = self.at_or_best(at);
+		self.ensure_api_version(at, APP_KEYS_VERSION, "app_by_id")?;
+		let app_key = self
+			.client
+			.runtime_api()
+			.app_by_id(at, id)
+			.map_err(|e| internal_err!("DataAvailApi::app_by_id failed at {at:?}: {e:?}"))?;
+
+		Ok(app_key.map(AppKey::from))
+	}
+
+	async fn estimate_submit_data_fee(
+		&self,
+		len: u32,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<SubmitDataFeeEstimate<Balance>> {
+		let at = self.at_or_best(at);
+		self.ensure_api_version(
+			at,
+			ESTIMATE_SUBMIT_DATA_FEE_VERSION,
+			"estimate_submit_data_fee",
+		)?;
+		self.client
+			.runtime_api()
+			.estimate_submit_data_fee(at, len, app_id)
+			.map_err(|e| {
+				internal_err!("DataAvailApi::estimate_submit_data_fee failed at {at:?}: {e:?}")
+			})?
+			.ok_or_else(|| internal_err!("Invalid data length {len}"))
+	}
+}
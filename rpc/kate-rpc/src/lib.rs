@@ -1,6 +1,10 @@
-use avail_base::metrics::avail::{MetricObserver, ObserveKind};
+use avail_base::{
+	header_extension::{AppDataProofResponse, DataRootVersion, MAX_DATA_PROOFS},
+	metrics::avail::{MetricObserver, ObserveKind},
+};
 use avail_core::{
-	data_proof::ProofResponse, header::HeaderExtension, traits::ExtendedHeader, OpaqueExtrinsic,
+	data_proof::ProofResponse, header::HeaderExtension, traits::ExtendedHeader, AppId,
+	OpaqueExtrinsic,
 };
 use da_runtime::apis::{DataAvailApi, KateApi as RTKateApi};
 use da_runtime::kate::{GDataProof, GRow};
@@ -14,7 +18,7 @@ use jsonrpsee::{
 	types::error::{ErrorCode, ErrorObject},
 };
 use sc_client_api::BlockBackend;
-use sp_api::{ApiRef, ProvideRuntimeApi};
+use sp_api::{ApiExt, ApiRef, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{
 	generic::SignedBlock,
@@ -27,6 +31,8 @@ pub type MaxRows = ConstU32<64>;
 pub type Rows = BoundedVec<u32, MaxRows>;
 pub type MaxCells = ConstU32<10_000>;
 pub type Cells = BoundedVec<Cell, MaxCells>;
+pub type MaxDataProofs = ConstU32<{ MAX_DATA_PROOFS as u32 }>;
+pub type TxIndices = BoundedVec<u32, MaxDataProofs>;
 
 pub mod metrics;
 
@@ -68,6 +74,24 @@ where
 		transaction_index: u32,
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<ProofResponse>;
+
+	/// Data proofs of the transactions at `transaction_indices`, or of the first `MAX_DATA_PROOFS`
+	/// bridge messages of the block if omitted. Transactions without a data proof are skipped.
+	#[method(name = "kate_queryDataProofs")]
+	async fn query_data_proofs(
+		&self,
+		transaction_indices: Option<TxIndices>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<(u32, ProofResponse)>>;
+
+	/// Proof of inclusion and completeness, or of absence, of the data submissions of `app_id`
+	/// against the namespaced data root.
+	#[method(name = "kate_queryAppDataProof")]
+	async fn query_app_data_proof(
+		&self,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<AppDataProofResponse>;
 }
 
 #[allow(clippy::type_complexity)]
@@ -184,6 +208,32 @@ where
 	}
 }
 
+/// First version of `KateApi` providing `app_data_proof`.
+const APP_DATA_PROOF_VERSION: u32 = 2;
+
+/// First version of `KateApi` providing `data_proofs`.
+const DATA_PROOFS_VERSION: u32 = 3;
+
+/// Fails if the runtime at `at` implements a version of `KateApi` older than `version`.
+fn ensure_kate_api_version<Block: BlockT>(
+	api: &impl ApiExt<Block>,
+	at: Block::Hash,
+	version: u32,
+	method: &str,
+) -> RpcResult<()> {
+	let at_version = api
+		.api_version::<dyn RTKateApi<Block>>(at)
+		.map_err(|e| internal_err!("KateApi version unavailable at {at:?}: {e:?}"))?
+		.unwrap_or_default();
+
+	if at_version < version {
+		return Err(internal_err!(
+			"KateApi::{method} is unsupported at {at:?}: runtime API version {at_version} < {version}"
+		));
+	}
+	Ok(())
+}
+
 #[async_trait]
 impl<Client, Block> KateApiServer<Block> for Kate<Client, Block>
 where
@@ -282,4 +332,46 @@ where
 
 		Ok(proof)
 	}
+
+	async fn query_data_proofs(
+		&self,
+		transaction_indices: Option<TxIndices>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<(u32, ProofResponse)>> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryDataProofs);
+
+		let (api, at, number, _, extrinsics, _) = self.scope(at)?;
+		ensure_kate_api_version::<Block>(&*api, at, DATA_PROOFS_VERSION, "data_proofs")?;
+		let tx_indices = transaction_indices.map(BoundedVec::into_inner);
+		let proofs = api
+			.data_proofs(at, number, extrinsics, tx_indices)
+			.map_err(|e| internal_err!("KateApi::data_proofs failed: {e:?}"))?;
+
+		Ok(proofs)
+	}
+
+	async fn query_app_data_proof(
+		&self,
+		app_id: AppId,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<AppDataProofResponse> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryAppDataProof);
+
+		let (api, at, number, _, extrinsics, header) = self.scope(at)?;
+		let version = DataRootVersion::from_digest(header.digest());
+		if version == DataRootVersion::V1 {
+			return Err(internal_err!(
+				"Data submissions are not namespaced by AppId at block {at:?}: data root {version:?}"
+			));
+		}
+		ensure_kate_api_version::<Block>(&*api, at, APP_DATA_PROOF_VERSION, "app_data_proof")?;
+		let proof = api
+			.app_data_proof(at, number, extrinsics, app_id)
+			.map_err(|e| internal_err!("KateApi::app_data_proof failed: {e:?}"))?
+			.ok_or_else(|| {
+				internal_err!("Data submissions are not namespaced by AppId at block {at:?}")
+			})?;
+
+		Ok(proof)
+	}
 }
@@ -0,0 +1,318 @@
+use da_runtime::{apis::VectorApi as VectorRuntimeApi, VectorSourceDomain};
+use pallet_vector::{Configuration, MessageStatusEnum};
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::ErrorObject,
+};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{Get, H256};
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, marker::Sync, sync::Arc};
+
+pub type HashOf<Block> = <Block as BlockT>::Hash;
+
+/// First version of `VectorApi` exposing the bridge state, and tracking several source chains.
+const BRIDGE_STATE_VERSION: u32 = 2;
+
+/// Sync committee hash of a period.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncCommitteeHash {
+	pub period: u64,
+	pub hash: H256,
+}
+
+#[rpc(client, server)]
+pub trait VectorApi<Block>
+where
+	Block: BlockT,
+{
+	/// Returns the latest finalized slot of the light client of `domain`.
+	#[method(name = "vector_head")]
+	async fn head(&self, domain: u32, at: Option<HashOf<Block>>) -> RpcResult<u64>;
+
+	/// Returns the header root of `slot` of `domain`.
+	#[method(name = "vector_header")]
+	async fn header(&self, domain: u32, slot: u64, at: Option<HashOf<Block>>) -> RpcResult<H256>;
+
+	/// Returns the execution status of the message with root `message_root`.
+	#[method(name = "vector_messageStatus")]
+	async fn message_status(
+		&self,
+		message_root: H256,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<MessageStatusEnum>;
+
+	/// Returns the execution state root of `slot` of `domain`.
+	#[method(name = "vector_executionStateRoot")]
+	async fn execution_state_root(
+		&self,
+		domain: u32,
+		slot: u64,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<H256>;
+
+	/// Returns the broadcaster of `domain`.
+	#[method(name = "vector_broadcaster")]
+	async fn broadcaster(&self, domain: u32, at: Option<HashOf<Block>>) -> RpcResult<H256>;
+
+	/// Returns the whitelisted domains.
+	#[method(name = "vector_whitelistedDomains")]
+	async fn whitelisted_domains(&self, at: Option<HashOf<Block>>) -> RpcResult<Vec<u32>>;
+
+	/// Returns whether `source_chain_id` is frozen.
+	#[method(name = "vector_sourceChainFrozen")]
+	async fn source_chain_frozen(
+		&self,
+		source_chain_id: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<bool>;
+
+	/// Returns the light client configuration of `domain`.
+	#[method(name = "vector_configuration")]
+	async fn configuration(
+		&self,
+		domain: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Configuration>;
+
+	/// Returns the sync committee hash of `period` of `domain`, or of the period of its head if omitted.
+	#[method(name = "vector_syncCommitteeHash")]
+	async fn sync_committee_hash(
+		&self,
+		domain: u32,
+		period: Option<u64>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<SyncCommitteeHash>;
+}
+
+pub struct Vector<Client, Block: BlockT> {
+	client: Arc<Client>,
+	_block: PhantomData<Block>,
+}
+
+impl<Client, Block: BlockT> Vector<Client, Block> {
+	pub fn new(client: Arc<Client>) -> Self {
+		Self {
+			client,
+			_block: PhantomData,
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The runtime API call failed.
+	VectorRPCError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::VectorRPCError => 1,
+		}
+	}
+}
+
+macro_rules! internal_err {
+	($($arg:tt)*) => {{
+		ErrorObject::owned(
+			Error::VectorRPCError.into(),
+			format!($($arg)*),
+			None::<()>
+		)
+	}}
+}
+
+impl<Client, Block> Vector<Client, Block>
+where
+	Block: BlockT,
+	Client: HeaderBackend<Block>,
+{
+	fn at_or_best(&self, at: Option<<Block as BlockT>::Hash>) -> <Block as BlockT>::Hash {
+		at.unwrap_or_else(|| self.client.info().best_hash)
+	}
+}
+
+impl<Client, Block> Vector<Client, Block>
+where
+	Block: BlockT,
+	Client: ProvideRuntimeApi<Block>,
+	Client::Api: VectorRuntimeApi<Block>,
+{
+	/// Version of `VectorApi` implemented by the runtime at `at`.
+	fn api_version(&self, at: HashOf<Block>) -> RpcResult<u32> {
+		let version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn VectorRuntimeApi<Block>>(at)
+			.map_err(|e| internal_err!("VectorApi version unavailable at {at:?}: {e:?}"))?
+			.unwrap_or_default();
+		Ok(version)
+	}
+
+	/// Fails if the runtime at `at` implements a version of `VectorApi` older than `version`.
+	fn ensure_api_version(&self, at: HashOf<Block>, version: u32, method: &str) -> RpcResult<()> {
+		let at_version = self.api_version(at)?;
+		if at_version < version {
+			return Err(internal_err!(
+				"VectorApi::{method} is unsupported at {at:?}: runtime API version {at_version} < {version}"
+			));
+		}
+		Ok(())
+	}
+
+	/// Fails unless `domain` is the single source chain tracked by runtimes older than
+	/// `VectorApi` v2.
+	fn ensure_single_chain_domain(&self, at: HashOf<Block>, domain: u32) -> RpcResult<()> {
+		let tracked = VectorSourceDomain::get();
+		if domain != tracked {
+			return Err(internal_err!(
+				"Only domain {tracked} is tracked at {at:?}, domain {domain} is unsupported"
+			));
+		}
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl<Client, Block> VectorApiServer<Block> for Vector<Client, Block>
+where
+	Block: BlockT,
+	Client: Send + Sync + 'static,
+	Client: HeaderBackend<Block> + ProvideRuntimeApi<Block>,
+	Client::Api: VectorRuntimeApi<Block>,
+{
+	async fn head(&self, domain: u32, at: Option<HashOf<Block>>) -> RpcResult<u64> {
+		let at = self.at_or_best(at);
+		let api = self.client.runtime_api();
+		if self.api_version(at)? < BRIDGE_STATE_VERSION {
+			self.ensure_single_chain_domain(at, domain)?;
+			#[allow(deprecated)]
+			return api
+				.head_before_version_2(at)
+				.map_err(|e| internal_err!("VectorApi::head failed at {at:?}: {e:?}"));
+		}
+
+		api.head(at, domain)
+			.map_err(|e| internal_err!("VectorApi::head failed at {at:?}: {e:?}"))
+	}
+
+	async fn header(&self, domain: u32, slot: u64, at: Option<HashOf<Block>>) -> RpcResult<H256> {
+		let at = self.at_or_best(at);
+		let api = self.client.runtime_api();
+		if self.api_version(at)? < BRIDGE_STATE_VERSION {
+			self.ensure_single_chain_domain(at, domain)?;
+			#[allow(deprecated)]
+			return api
+				.headers_before_version_2(at, slot)
+				.map_err(|e| internal_err!("VectorApi::headers failed at {at:?}: {e:?}"));
+		}
+
+		api.headers(at, domain, slot)
+			.map_err(|e| internal_err!("VectorApi::headers failed at {at:?}: {e:?}"))
+	}
+
+	async fn message_status(
+		&self,
+		message_root: H256,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<MessageStatusEnum> {
+		let at = self.at_or_best(at);
+		self.ensure_api_version(at, BRIDGE_STATE_VERSION, "message_status")?;
+		self.client
+			.runtime_api()
+			.message_status(at, message_root)
+			.map_err(|e| internal_err!("VectorApi::message_status failed at {at:?}: {e:?}"))
+	}
+
+	async fn execution_state_root(
+		&self,
+		domain: u32,
+		slot: u64,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<H256> {
+		let at = self.at_or_best(at);
+		self.ensure_api_version(at, BRIDGE_STATE_VERSION, "execution_state_root")?;
+		self.client
+			.runtime_api()
+			.execution_state_root(at, domain, slot)
+			.map_err(|e| internal_err!("VectorApi::execution_state_root failed at {at:?}: {e:?}"))
+	}
+
+	async fn broadcaster(&self, domain: u32, at: Option<HashOf<Block>>) -> RpcResult<H256> {
+		let at = self.at_or_best(at);
+		self.ensure_api_version(at, BRIDGE_STATE_VERSION, "broadcaster")?;
+		self.client
+			.runtime_api()
+			.broadcaster(at, domain)
+			.map_err(|e| internal_err!("VectorApi::broadcaster failed at {at:?}: {e:?}"))
+	}
+
+	async fn whitelisted_domains(&self, at: Option<HashOf<Block>>) -> RpcResult<Vec<u32>> {
+		let at = self.at_or_best(at);
+		self.ensure_api_version(at, BRIDGE_STATE_VERSION, "whitelisted_domains")?;
+		self.client
+			.runtime_api()
+			.whitelisted_domains(at)
+			.map_err(|e| internal_err!("VectorApi::whitelisted_domains failed at {at:?}: {e:?}"))
+	}
+
+	async fn source_chain_frozen(
+		&self,
+		source_chain_id: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<bool> {
+		let at = self.at_or_best(at);
+		self.ensure_api_version(at, BRIDGE_STATE_VERSION, "source_chain_frozen")?;
+		self.client
+			.runtime_api()
+			.source_chain_frozen(at, source_chain_id)
+			.map_err(|e| internal_err!("VectorApi::source_chain_frozen failed at {at:?}: {e:?}"))
+	}
+
+	async fn configuration(
+		&self,
+		domain: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Configuration> {
+		let at = self.at_or_best(at);
+		self.ensure_api_version(at, BRIDGE_STATE_VERSION, "configuration")?;
+		self.client
+			.runtime_api()
+			.configuration(at, domain)
+			.map_err(|e| internal_err!("VectorApi::configuration failed at {at:?}: {e:?}"))
+	}
+
+	async fn sync_committee_hash(
+		&self,
+		domain: u32,
+		period: Option<u64>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<SyncCommitteeHash> {
+		let at = self.at_or_best(at);
+		self.ensure_api_version(at, BRIDGE_STATE_VERSION, "sync_committee_hash")?;
+		let api = self.client.runtime_api();
+
+		let Some(period) = period else {
+			let (period, hash) = api
+				.current_sync_committee_hash(at, domain)
+				.map_err(|e| {
+					internal_err!("VectorApi::current_sync_committee_hash failed at {at:?}: {e:?}")
+				})?
+				.ok_or_else(|| {
+					internal_err!("Vector configuration of domain {domain} is not set at {at:?}")
+				})?;
+			return Ok(SyncCommitteeHash { period, hash });
+		};
+
+		let hash = api
+			.sync_committee_hash(at, domain, period)
+			.map_err(|e| internal_err!("VectorApi::sync_committee_hash failed at {at:?}: {e:?}"))?;
+		Ok(SyncCommitteeHash { period, hash })
+	}
+}
@@ -3,35 +3,43 @@ use crate::{
 	constants::{self},
 	mmr,
 	version::VERSION,
-	AccountId, AuthorityDiscovery, Babe, Block, BlockNumber, EpochDuration, Executive, Grandpa,
-	Historical, Index, InherentDataExt, Mmr, NominationPools, OpaqueMetadata, Runtime, RuntimeCall,
-	RuntimeGenesisConfig, SessionKeys, Staking, System, TransactionPayment, LOG_TARGET,
+	AccountId, AuthorityDiscovery, Babe, Block, BlockHashCount, BlockNumber, EpochDuration,
+	Executive, Grandpa, Historical, Index, InherentDataExt, Mmr, NominationPools, OpaqueMetadata,
+	PostInherentProviders, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, SignedExtra,
+	Staking, System, TransactionPayment, UncheckedExtrinsic, LOG_TARGET,
+};
+use avail_base::{
+	header_extension::{AppDataProofResponse, DataRootVersion},
+	HeaderExtensionBuilderData, PostInherentsError, ProvidePostInherents,
 };
-use avail_base::{HeaderExtensionBuilderData, ProvidePostInherent};
 use avail_core::{
 	currency::Balance,
-	data_proof::{DataProof, ProofResponse, SubTrie},
+	data_proof::{DataProof, ProofResponse, SubTrie, TxDataRoots},
 	header::HeaderExtension,
-	OpaqueExtrinsic,
+	AppId, OpaqueExtrinsic,
 };
+use codec::{Compact, CompactLen as _, Encode as _};
+use da_control::{AppKeyInfo, BlockLenProposal, SubmitDataFeeEstimate};
 
 use frame_system::limits::BlockLength;
 
 use frame_support::{
 	genesis_builder_helper::{build_config, create_default_config},
-	traits::KeyOwnerProofSystem,
+	traits::{Get, KeyOwnerProofSystem},
 	weights::Weight,
 };
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
+use pallet_vector::{Configuration as VectorConfiguration, MessageStatusEnum};
 use sp_api::{decl_runtime_apis, impl_runtime_apis};
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{crypto::KeyTypeId, H256, U256};
 use sp_inherents::{CheckInherentsResult, InherentData};
 use sp_runtime::{
+	generic::Era,
 	traits::{Block as BlockT, Extrinsic as ExtrinsicT, NumberFor},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult,
+	ApplyExtrinsicResult, SaturatedConversion,
 };
 use sp_std::{borrow::Cow, vec::Vec};
 use sp_version::RuntimeVersion;
@@ -43,6 +51,14 @@ decl_runtime_apis! {
 	#[api_version(2)]
 	pub trait DataAvailApi {
 		fn block_length() -> BlockLength;
+		#[api_version(3)]
+		fn pending_block_length_proposals() -> Vec<(u32, BlockLenProposal<BlockNumber>)>;
+		#[api_version(4)]
+		fn app_keys(page: u32, page_size: u32) -> Vec<(Vec<u8>, AppKeyInfo<AccountId>)>;
+		#[api_version(4)]
+		fn app_by_id(id: AppId) -> Option<(Vec<u8>, AppKeyInfo<AccountId>)>;
+		#[api_version(5)]
+		fn estimate_submit_data_fee(len: u32, app_id: AppId) -> Option<SubmitDataFeeEstimate<Balance>>;
 	}
 
 	pub trait ExtensionBuilder {
@@ -56,16 +72,45 @@ decl_runtime_apis! {
 
 		fn build_data_root(block: u32, extrinsics: Vec<OpaqueExtrinsic>) -> H256;
 		fn check_if_extrinsic_is_post_inherent(uxt: &<Block as BlockT>::Extrinsic) -> bool;
+		#[api_version(5)]
+		fn check_post_inherents(extrinsics: Vec<<Block as BlockT>::Extrinsic>) -> Result<(), PostInherentsError>;
 	}
 
+	#[api_version(2)]
 	pub trait VectorApi {
+		#[changed_in(2)]
 		fn sync_committee_poseidons(slot: u64) -> U256;
+		fn sync_committee_poseidons(domain: u32, slot: u64) -> U256;
+		#[changed_in(2)]
 		fn head() -> u64;
+		fn head(domain: u32) -> u64;
+		#[changed_in(2)]
 		fn headers(slot: u64) -> H256;
+		fn headers(domain: u32, slot: u64) -> H256;
+		#[api_version(2)]
+		fn message_status(message_root: H256) -> MessageStatusEnum;
+		#[api_version(2)]
+		fn execution_state_root(domain: u32, slot: u64) -> H256;
+		#[api_version(2)]
+		fn broadcaster(domain: u32) -> H256;
+		#[api_version(2)]
+		fn whitelisted_domains() -> Vec<u32>;
+		#[api_version(2)]
+		fn source_chain_frozen(source_chain_id: u32) -> bool;
+		#[api_version(2)]
+		fn configuration(domain: u32) -> VectorConfiguration;
+		#[api_version(2)]
+		fn sync_committee_hash(domain: u32, period: u64) -> H256;
+		#[api_version(2)]
+		fn current_sync_committee_hash(domain: u32) -> Option<(u64, H256)>;
 	}
 
 	pub trait KateApi {
 		fn data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32) -> Option<ProofResponse>;
+		#[api_version(2)]
+		fn app_data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, app_id: AppId) -> Option<AppDataProofResponse>;
+		#[api_version(3)]
+		fn data_proofs(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_indices: Option<Vec<u32>>) -> Vec<(u32, ProofResponse)>;
 		fn rows(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, rows: Vec<u32>) -> Result<Vec<GRow>, RTKateError >;
 		fn proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, cells: Vec<(u32,u32)> ) -> Result<Vec<GDataProof>, RTKateError>;
 	}
@@ -77,6 +122,47 @@ pub(crate) const fn runtime_api_versions() -> Cow<'static, [([u8; 8], u32)]> {
 
 pub static NATIVE_VERSION: &RuntimeVersion = &VERSION;
 
+/// Encoded length of a signed `submit_data` extrinsic carrying `data_len` bytes of data.
+///
+/// It assumes a mortal era, an sr25519 signature, a zero tip and a small nonce.
+fn submit_data_extrinsic_len(data_len: u32, app_id: AppId) -> u32 {
+	let call = RuntimeCall::DataAvailability(da_control::Call::submit_data {
+		data: Default::default(),
+	});
+	let extra: SignedExtra = (
+		frame_system::CheckNonZeroSender::new(),
+		frame_system::CheckSpecVersion::new(),
+		frame_system::CheckTxVersion::new(),
+		frame_system::CheckGenesis::new(),
+		frame_system::CheckEra::from(Era::mortal(BlockHashCount::get().into(), 0)),
+		frame_system::CheckNonce::from(0),
+		frame_system::CheckWeight::new(),
+		pallet_transaction_payment::ChargeTransactionPayment::from(0),
+		da_control::CheckAppId::from(app_id),
+	);
+	let signature = sp_core::sr25519::Signature::from_raw([0u8; 64]);
+	let xt = UncheckedExtrinsic::new_signed(
+		call,
+		AccountId::from([0u8; 32]).into(),
+		signature.into(),
+		extra,
+	);
+
+	// Replace the empty data by `data_len` bytes, which may also grow the extrinsic length prefix.
+	let empty_len = xt.encoded_size().saturated_into::<u32>();
+	let empty_body_len = empty_len.saturating_sub(compact_len(empty_len));
+	let body_len = empty_body_len
+		.saturating_sub(compact_len(0))
+		.saturating_add(compact_len(data_len))
+		.saturating_add(data_len);
+
+	compact_len(body_len).saturating_add(body_len)
+}
+
+fn compact_len(value: u32) -> u32 {
+	Compact::<u32>::compact_len(&value).saturated_into()
+}
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -376,16 +462,42 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(5)]
 	impl crate::apis::DataAvailApi<Block> for Runtime {
 		fn block_length() -> frame_system::limits::BlockLength {
 			frame_system::Pallet::<Runtime>::block_length()
 		}
+
+		fn pending_block_length_proposals() -> Vec<(u32, BlockLenProposal<BlockNumber>)> {
+			da_control::Pallet::<Runtime>::pending_block_length_proposals()
+		}
+
+		fn app_keys(page: u32, page_size: u32) -> Vec<(Vec<u8>, AppKeyInfo<AccountId>)> {
+			da_control::Pallet::<Runtime>::app_keys(page, page_size)
+				.into_iter()
+				.map(|(key, info)| (key.into_inner(), info))
+				.collect()
+		}
+
+		fn app_by_id(id: AppId) -> Option<(Vec<u8>, AppKeyInfo<AccountId>)> {
+			da_control::Pallet::<Runtime>::app_by_id(id).map(|(key, info)| (key.into_inner(), info))
+		}
+
+		fn estimate_submit_data_fee(len: u32, app_id: AppId) -> Option<SubmitDataFeeEstimate<Balance>> {
+			if len == 0 || len > constants::da::MaxAppDataLength::get() {
+				return None;
+			}
+
+			let tx_len = submit_data_extrinsic_len(len, app_id);
+			Some(da_control::Pallet::<Runtime>::estimate_submit_data_fee(len, tx_len))
+		}
 	}
 
-	#[api_version(4)]
+	#[api_version(5)]
 	impl crate::apis::ExtensionBuilder<Block> for Runtime {
 		fn build_data_root(block: u32, extrinsics: Vec<OpaqueExtrinsic>) -> H256  {
-			HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block, &extrinsics).data_root()
+			let version = <Runtime as frame_system::Config>::DataRootVersion::get();
+			HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block, &extrinsics).data_root_of(version)
 		}
 
 		fn build_extension(
@@ -408,30 +520,72 @@ impl_runtime_apis! {
 				return false;
 			};
 
-			let vector_pallet_call = match xt.call() {
-				RuntimeCall::Vector(call) => call,
-				_ => return false
-			};
+			<PostInherentProviders as ProvidePostInherents<RuntimeCall>>::provider_of(xt.call()).is_some()
+		}
 
+		fn check_post_inherents(extrinsics: Vec<<Block as BlockT>::Extrinsic>) -> Result<(), PostInherentsError> {
+			use frame_support::traits::ExtrinsicCall;
 
-			matches!(vector_pallet_call, pallet_vector::Call::failed_send_message_txs {failed_txs: _})
+			// Signed extrinsics are never post-inherents.
+			let calls = extrinsics
+				.iter()
+				.map(|uxt| {
+					let xt = TryInto::<&RTExtrinsic>::try_into(uxt).ok()?;
+					(xt.is_signed() == Some(false)).then(|| xt.call())
+				})
+				.collect::<Vec<_>>();
+
+			avail_base::check_post_inherents::<RuntimeCall, PostInherentProviders>(&calls)
 		}
 	}
 
 	impl crate::apis::VectorApi<Block> for Runtime {
-		fn sync_committee_poseidons(slot: u64) -> U256 {
-			pallet_vector::Pallet::<Runtime>::sync_committee_poseidons(slot)
+		fn sync_committee_poseidons(domain: u32, slot: u64) -> U256 {
+			pallet_vector::Pallet::<Runtime>::sync_committee_poseidons(domain, slot)
+		}
+
+		fn head(domain: u32) -> u64 {
+			pallet_vector::Pallet::<Runtime>::head(domain)
+		}
+
+		fn headers(domain: u32, slot: u64) -> H256 {
+			pallet_vector::Pallet::<Runtime>::headers(domain, slot)
+		}
+
+		fn message_status(message_root: H256) -> MessageStatusEnum {
+			pallet_vector::MessageStatus::<Runtime>::get(message_root)
 		}
 
-		fn head() -> u64 {
-			pallet_vector::Pallet::<Runtime>::head()
+		fn execution_state_root(domain: u32, slot: u64) -> H256 {
+			pallet_vector::ExecutionStateRoots::<Runtime>::get(domain, slot)
 		}
 
-		fn headers(slot: u64) -> H256 {
-			pallet_vector::Pallet::<Runtime>::headers(slot)
+		fn broadcaster(domain: u32) -> H256 {
+			pallet_vector::Broadcasters::<Runtime>::get(domain)
+		}
+
+		fn whitelisted_domains() -> Vec<u32> {
+			pallet_vector::WhitelistedDomains::<Runtime>::get().into_inner()
+		}
+
+		fn source_chain_frozen(source_chain_id: u32) -> bool {
+			pallet_vector::SourceChainFrozen::<Runtime>::get(source_chain_id)
+		}
+
+		fn configuration(domain: u32) -> VectorConfiguration {
+			pallet_vector::ConfigurationStorage::<Runtime>::get(domain)
+		}
+
+		fn sync_committee_hash(domain: u32, period: u64) -> H256 {
+			pallet_vector::Pallet::<Runtime>::sync_committee_hashes(domain, period)
+		}
+
+		fn current_sync_committee_hash(domain: u32) -> Option<(u64, H256)> {
+			pallet_vector::Pallet::<Runtime>::current_sync_committee_hash(domain)
 		}
 	}
 
+	#[api_version(3)]
 	impl crate::apis::KateApi<Block> for Runtime {
 		fn data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32) -> Option<ProofResponse> {
 			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
@@ -440,7 +594,14 @@ impl_runtime_apis! {
 				target: LOG_TARGET,
 				"KateApi::data_proof: tx_idx={tx_idx:?} leaf_idx={leaf_idx:?}, sub_trie:{sub_trie:?}");
 
+			let version = <Runtime as frame_system::Config>::DataRootVersion::get();
 			let (sub_proof, message) = match sub_trie {
+				SubTrie::DataSubmit if version != DataRootVersion::V1 => {
+					log::debug!(
+						target: LOG_TARGET,
+						"KateApi::data_proof: data submissions are namespaced by {version:?}, use `app_data_proof`");
+					return None;
+				},
 				SubTrie::DataSubmit => {
 					let proof = data.submitted_proof_of(leaf_idx)?;
 					(proof, None)
@@ -452,7 +613,7 @@ impl_runtime_apis! {
 				},
 			};
 
-			let roots = data.roots();
+			let roots = data.roots_of(version);
 			let data_proof = DataProof::new(sub_trie, roots, sub_proof);
 			let proof = ProofResponse::new(data_proof, message);
 			log::trace!(
@@ -462,6 +623,58 @@ impl_runtime_apis! {
 			Some(proof)
 		}
 
+		fn app_data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, app_id: AppId) -> Option<AppDataProofResponse> {
+			let version = <Runtime as frame_system::Config>::DataRootVersion::get();
+			if version == DataRootVersion::V1 {
+				log::debug!(
+					target: LOG_TARGET,
+					"KateApi::app_data_proof: data submissions are not namespaced by {version:?}");
+				return None;
+			}
+
+			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
+			let proof = AppDataProofResponse {
+				roots: data.roots_of(version),
+				proof: data.app_data_proof(app_id),
+			};
+			log::trace!(
+				target: LOG_TARGET,
+				"KateApi::app_data_proof: app_id={app_id:?} proof={proof:#?}");
+
+			Some(proof)
+		}
+
+		fn data_proofs(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_indices: Option<Vec<u32>>) -> Vec<(u32, ProofResponse)> {
+			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
+			let version = <Runtime as frame_system::Config>::DataRootVersion::get();
+			// All bridge messages of the block by default, up to `MAX_DATA_PROOFS` proofs.
+			let tx_indices = tx_indices.unwrap_or_else(|| data.bridge_messages.iter().map(|b| b.tx_index).collect());
+
+			let roots = data.roots_of(version);
+			let proofs = data
+				.proofs_of(&tx_indices)
+				.into_iter()
+				.filter(|(_, sub_trie, _)| *sub_trie == SubTrie::Bridge || version == DataRootVersion::V1)
+				.map(|(tx_idx, sub_trie, sub_proof)| {
+					let message = match sub_trie {
+						SubTrie::DataSubmit => None,
+						SubTrie::Bridge => data
+							.bridge_messages
+							.get(sub_proof.leaf_index)
+							.map(|b| b.addr_msg.clone()),
+					};
+					let roots = TxDataRoots::new(roots.blob_root, roots.bridge_root);
+					let data_proof = DataProof::new(sub_trie, roots, sub_proof);
+					(tx_idx, ProofResponse::new(data_proof, message))
+				})
+				.collect::<Vec<_>>();
+			log::trace!(
+				target: LOG_TARGET,
+				"KateApi::data_proofs: tx_indices={tx_indices:?} proofs={}", proofs.len());
+
+			proofs
+		}
+
 		fn rows(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, rows: Vec<u32>) -> Result<Vec<GRow>, RTKateError> {
 			let app_extrinsics = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics).to_app_extrinsics();
 			let grid_rows = super::kate::grid::<Runtime>(app_extrinsics, block_len, rows)?;
@@ -479,11 +692,9 @@ impl_runtime_apis! {
 
 	impl avail_base::PostInherentsProvider<Block> for Runtime {
 		fn create_post_inherent_extrinsics(data: avail_base::StorageMap) -> Vec<<Block as BlockT>::Extrinsic> {
-			pallet_vector::Pallet::<Runtime>::create_inherent(&data)
+			<PostInherentProviders as ProvidePostInherents<RuntimeCall>>::create_post_inherents(&data)
 				.into_iter()
-				.filter_map(|inherent| {
-					<Block as BlockT>::Extrinsic::new(inherent.into(), None)
-				})
+				.filter_map(|call| <Block as BlockT>::Extrinsic::new(call, None))
 				.collect()
 		}
 	}
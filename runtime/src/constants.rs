@@ -147,6 +147,14 @@ pub mod system {
 			.avg_block_initialization(AVERAGE_ON_INITIALIZE_RATIO)
 			.build_or_panic();
 	}
+
+	parameter_types! {
+		/// Layout of the submitted data sub-trie in the data root. Switching to
+		/// `DataRootVersion::V2` namespaces data submissions per `AppId`.
+		/// `DataAvailability::submit_data_hash` is only accepted under `DataRootVersion::V2`.
+		pub const DataRootVersion: avail_base::header_extension::DataRootVersion =
+			avail_base::header_extension::DataRootVersion::V1;
+	}
 	const_assert!(NORMAL_DISPATCH_RATIO.deconstruct() >= AVERAGE_ON_INITIALIZE_RATIO.deconstruct());
 }
 
@@ -356,6 +364,7 @@ pub mod da {
 	}
 	pub type MaxAppKeyLength = ConstU32<64>;
 	pub type MaxAppDataLength = ConstU32<1_048_576>; // 1 Mb
+	pub type MaxPendingBlockLenProposals = ConstU32<16>;
 }
 
 /// Macro to set a value (e.g. when using the `parameter_types` macro) to either a production value
@@ -1,7 +1,7 @@
 use super::*;
 use crate::{Runtime, SignedExtra, UncheckedExtrinsic};
 
-use avail_base::HeaderExtensionBuilderData;
+use avail_base::{header_extension::MAX_DATA_PROOFS, HeaderExtensionBuilderData};
 use avail_core::data_proof::{BoundedData, Message, TxDataRoots};
 use da_control::{AppDataFor, Call as DaCall, CheckAppId};
 use frame_system::{
@@ -13,12 +13,13 @@ use pallet_vector::Call as VectorCall;
 
 use avail_core::data_proof::AddressedMessage;
 use avail_core::data_proof::SubTrie;
-use binary_merkle_tree::{verify_proof, Leaf, MerkleProof};
+use binary_merkle_tree::{merkle_root, verify_proof, Leaf, MerkleProof};
 use codec::{Compact, Encode};
 use derive_more::Constructor;
 use hex_literal::hex;
 use pallet_transaction_payment::ChargeTransactionPayment;
 use sp_core::H256;
+use sp_io::hashing::keccak_256;
 use sp_keyring::AccountKeyring::{Alice, Bob};
 use sp_runtime::traits::Keccak256;
 use sp_runtime::{
@@ -142,6 +143,14 @@ fn submit_data(data: Vec<u8>) -> Vec<u8> {
 	signed_extrinsic(function)
 }
 
+fn submit_data_hash(data: &[u8]) -> Vec<u8> {
+	let data_hash = H256(keccak_256(data));
+	let len = data.len() as u32;
+	let function = DaCall::submit_data_hash { data_hash, len }.into();
+
+	signed_extrinsic(function)
+}
+
 fn transfer_keep_alive() -> Vec<u8> {
 	let bob = Bob.to_account_id();
 	let amount = 1 * AVAIL;
@@ -294,6 +303,257 @@ mod to_app_extrinsics_tests {
 	}
 }
 
+#[cfg(test)]
+mod submit_data_hash_tests {
+	use super::*;
+	use avail_base::header_extension::{
+		DataRootVersion, SubmittedData, SubmittedDataKind, DATA_HASH_LEAF_PREFIX,
+		DATA_HASH_LEAF_TAG,
+	};
+
+	// The runtime uses `DataRootVersion::V1`, under which hash-only submissions fail to dispatch
+	// and are left out of the data root.
+	#[test]
+	fn hash_only_is_ignored_by_data_root_v1() {
+		let extrinsics = vec![
+			submit_data(hex!("abcd").to_vec()),
+			submit_data_hash(&hex!("abcd")),
+		];
+
+		let data = HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(0, &extrinsics);
+		assert_eq!(data.app_extrinsics.len(), 1);
+		assert_eq!(data.data_submissions.len(), 1);
+		assert_eq!(data.data_submissions[0].kind, SubmittedDataKind::Full);
+		assert_eq!(data.leaf_idx(1), None);
+	}
+
+	// The same blob submitted in full or as a hash must use different leaves.
+	#[test]
+	fn hash_only_leaf_is_distinct() {
+		let blob = hex!("abcd");
+		let full = SubmittedData::new(AppId(1), 0, blob.to_vec());
+		let hash_only = SubmittedData::new_hash_only(AppId(1), 0, H256(keccak_256(&blob)), 2);
+		assert_ne!(full.leaf(), hash_only.leaf());
+		assert_ne!(H256(keccak_256(&blob)), hash_only.leaf());
+
+		let submitted_root = |submission: SubmittedData| {
+			HeaderExtensionBuilderData {
+				data_submissions: vec![submission],
+				..Default::default()
+			}
+			.roots_of(DataRootVersion::V2)
+			.blob_root
+		};
+		let full_root = submitted_root(full);
+		let hash_only_root = submitted_root(hash_only);
+		assert_ne!(full_root, hash_only_root);
+		assert_ne!(hash_only_root, H256::zero());
+	}
+
+	// A full submission can forge the V1 leaf of a hash-only one, but not the V2 leaf.
+	#[test]
+	fn hash_only_leaf_is_domain_separated_from_v2() {
+		let data_hash = H256(keccak_256(&hex!("abcd")));
+		let hash_only = SubmittedData::new_hash_only(AppId(1), 0, data_hash, 2);
+
+		let mut forged = DATA_HASH_LEAF_PREFIX.to_vec();
+		forged.extend_from_slice(data_hash.as_bytes());
+		forged.extend_from_slice(&2u32.to_be_bytes());
+		let forged = SubmittedData::new(AppId(1), 1, forged);
+		assert_eq!(forged.leaf(), hash_only.leaf());
+		assert_eq!(
+			forged.leaf_of(DataRootVersion::V1),
+			hash_only.leaf_of(DataRootVersion::V1)
+		);
+		assert_ne!(
+			forged.leaf_of(DataRootVersion::V2),
+			hash_only.leaf_of(DataRootVersion::V2)
+		);
+
+		let mut forged = vec![DATA_HASH_LEAF_TAG];
+		forged.extend_from_slice(data_hash.as_bytes());
+		forged.extend_from_slice(&2u32.to_be_bytes());
+		let forged = SubmittedData::new(AppId(1), 1, forged);
+		assert_ne!(
+			forged.leaf_of(DataRootVersion::V2),
+			hash_only.leaf_of(DataRootVersion::V2)
+		);
+	}
+
+	#[test]
+	fn zero_len_hash_only_is_ignored() {
+		let data_hash = H256(keccak_256(&[]));
+		let function = DaCall::submit_data_hash { data_hash, len: 0 }.into();
+		let extrinsics = vec![signed_extrinsic(function)];
+
+		let data = HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(0, &extrinsics);
+		assert!(data.is_empty());
+	}
+}
+
+#[cfg(test)]
+mod app_roots_tests {
+	use super::*;
+	use avail_base::header_extension::{
+		AppDataProof, AppRoot, AppRootProof, DataRootVersion, SubmittedData,
+		DATA_ROOT_VERSION_ENGINE_ID,
+	};
+	use sp_runtime::{Digest, DigestItem};
+
+	fn namespaced_data() -> HeaderExtensionBuilderData {
+		HeaderExtensionBuilderData {
+			data_submissions: vec![
+				SubmittedData::new(AppId(3), 0, b"a".to_vec()),
+				SubmittedData::new(AppId(1), 1, b"b".to_vec()),
+				SubmittedData::new(AppId(3), 2, b"c".to_vec()),
+				SubmittedData::new(AppId(5), 3, b"d".to_vec()),
+			],
+			..Default::default()
+		}
+	}
+
+	fn verify_app_root(root: H256, proof: &AppRootProof) -> bool {
+		let leaf = proof
+			.app_root
+			.as_ref()
+			.map(AppRoot::leaf)
+			.unwrap_or_else(H256::zero);
+		verify_proof::<Keccak256, _, _>(
+			&root,
+			proof.proof.clone(),
+			proof.number_of_leaves as usize,
+			proof.leaf_index as usize,
+			Leaf::Value(leaf.as_bytes()),
+		)
+	}
+
+	fn app_ids(proof: &Option<AppRootProof>) -> Option<Option<u32>> {
+		proof
+			.as_ref()
+			.map(|p| p.app_root.as_ref().map(|r| r.app_id.0))
+	}
+
+	// Submissions are grouped per `AppId`, sorted by `AppId`, and keep the transaction order.
+	#[test]
+	fn app_roots_are_sorted_by_app_id() {
+		let data = namespaced_data();
+		let app_roots = data.app_roots();
+
+		let ids = app_roots
+			.iter()
+			.map(|r| (r.app_id.0, r.count))
+			.collect::<Vec<_>>();
+		assert_eq!(ids, vec![(1, 1), (3, 2), (5, 1)]);
+
+		let app_3_leaves = [
+			SubmittedData::new(AppId(3), 0, b"a".to_vec()).leaf_of(DataRootVersion::V2),
+			SubmittedData::new(AppId(3), 2, b"c".to_vec()).leaf_of(DataRootVersion::V2),
+		];
+		assert_eq!(
+			app_roots[1].root,
+			merkle_root::<Keccak256, _>(app_3_leaves.into_iter())
+		);
+	}
+
+	#[test]
+	fn data_root_depends_on_version() {
+		let data = namespaced_data();
+		assert_eq!(data.roots_of(DataRootVersion::V1), data.roots());
+		assert_eq!(
+			data.roots_of(DataRootVersion::V2).blob_root,
+			data.namespaced_submitted_root()
+		);
+		assert_ne!(
+			data.data_root_of(DataRootVersion::V1),
+			data.data_root_of(DataRootVersion::V2)
+		);
+
+		let empty = HeaderExtensionBuilderData::default();
+		assert_eq!(empty.namespaced_submitted_root(), H256::zero());
+		assert_eq!(
+			empty.data_root_of(DataRootVersion::V1),
+			empty.data_root_of(DataRootVersion::V2)
+		);
+	}
+
+	// Only namespaced data roots are signalled, and unsignalled headers default to `V1`.
+	#[test]
+	fn data_root_version_is_signalled_in_digest() {
+		assert_eq!(DataRootVersion::V1.digest_item(), None);
+		assert_eq!(
+			DataRootVersion::from_digest(&Digest::default()),
+			DataRootVersion::V1
+		);
+
+		let item = DataRootVersion::V2.digest_item().unwrap();
+		assert_eq!(
+			item,
+			DigestItem::Consensus(DATA_ROOT_VERSION_ENGINE_ID, DataRootVersion::V2.encode())
+		);
+		let digest = Digest {
+			logs: vec![DigestItem::Other(vec![1]), item],
+		};
+		assert_eq!(DataRootVersion::from_digest(&digest), DataRootVersion::V2);
+	}
+
+	#[test]
+	fn app_inclusion_proof_is_complete() {
+		let data = namespaced_data();
+		let root = data.namespaced_submitted_root();
+
+		let AppDataProof::Inclusion { app_root, leaves } = data.app_data_proof(AppId(3)) else {
+			panic!("AppId 3 has data submissions");
+		};
+		assert!(verify_app_root(root, &app_root));
+
+		let expected = vec![
+			SubmittedData::new(AppId(3), 0, b"a".to_vec()).leaf_of(DataRootVersion::V2),
+			SubmittedData::new(AppId(3), 2, b"c".to_vec()).leaf_of(DataRootVersion::V2),
+		];
+		assert_eq!(leaves, expected);
+		let app_root = app_root.app_root.unwrap();
+		assert_eq!(app_root.count as usize, leaves.len());
+		assert_eq!(
+			app_root.root,
+			merkle_root::<Keccak256, _>(leaves.into_iter())
+		);
+	}
+
+	#[test_case(0 => (None, Some(Some(1))) ; "before first app")]
+	#[test_case(2 => (Some(Some(1)), Some(Some(3))) ; "between apps")]
+	#[test_case(7 => (Some(Some(5)), Some(None)) ; "after last app")]
+	fn app_absence_proof_encloses_app_id(
+		app_id: u32,
+	) -> (Option<Option<u32>>, Option<Option<u32>>) {
+		let data = namespaced_data();
+		let root = data.namespaced_submitted_root();
+
+		let AppDataProof::Absence { left, right } = data.app_data_proof(AppId(app_id)) else {
+			panic!("AppId {app_id} has no data submissions");
+		};
+		for proof in left.iter().chain(right.iter()) {
+			assert!(verify_app_root(root, proof));
+		}
+		if let (Some(left), Some(right)) = (&left, &right) {
+			assert_eq!(left.leaf_index + 1, right.leaf_index);
+		}
+
+		(app_ids(&left), app_ids(&right))
+	}
+
+	#[test]
+	fn app_absence_proof_of_empty_block() {
+		let data = HeaderExtensionBuilderData::default();
+		assert_eq!(
+			data.app_data_proof(AppId(1)),
+			AppDataProof::Absence {
+				left: None,
+				right: None
+			}
+		);
+	}
+}
+
 #[cfg(test)]
 mod bridge_tests {
 
@@ -372,6 +632,46 @@ mod bridge_tests {
 		assert_eq!(data.roots().bridge_root, expected.roots().bridge_root);
 	}
 
+	// Batched proofs are built from one tree per sub-trie and must match the single proofs.
+	#[test]
+	fn batched_proofs_match_single_proofs() {
+		let extrinsics = vec![
+			bridge_msg(b"123".to_vec()),
+			submit_data(hex!("abcd").to_vec()),
+			bridge_fungible_msg(H256::zero(), 42_000_000_000_000_000_000u128),
+			submit_data(hex!("ef").to_vec()),
+			bridge_msg(b"456".to_vec()),
+			submit_data(hex!("0123").to_vec()),
+		];
+		let data = HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(0, &extrinsics);
+
+		let proofs = data.proofs_of(&[4, 0, 1, 5, 3, 2, 42]);
+		let tx_indices = proofs
+			.iter()
+			.map(|(tx_idx, ..)| *tx_idx)
+			.collect::<Vec<_>>();
+		assert_eq!(tx_indices, vec![4, 0, 1, 5, 3, 2]);
+
+		for (tx_idx, sub_trie, proof) in proofs {
+			let (leaf_idx, expected_sub_trie) = data.leaf_idx(tx_idx).unwrap();
+			assert_eq!(sub_trie, expected_sub_trie);
+			let expected = match sub_trie {
+				SubTrie::DataSubmit => data.submitted_proof_of(leaf_idx),
+				SubTrie::Bridge => data.bridged_proof_of(leaf_idx),
+			};
+			assert_eq!(Some(proof), expected, "proof of tx {tx_idx}");
+		}
+	}
+
+	#[test]
+	fn batched_proofs_are_capped() {
+		let extrinsics = vec![bridge_msg(b"123".to_vec())];
+		let data = HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(0, &extrinsics);
+
+		let tx_indices = vec![0; MAX_DATA_PROOFS + 1];
+		assert_eq!(data.proofs_of(&tx_indices).len(), MAX_DATA_PROOFS);
+	}
+
 	// Bridges message and roots should be same for same data and ignore failed txs
 	#[test]
 	fn bridge_roots_should_be_same() {
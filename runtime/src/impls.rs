@@ -1,6 +1,6 @@
 use crate::{
-	constants, prod_or_fast, voter_bags, weights, AccountId, AccountIndex, Babe, Balances, Block,
-	BlockNumber, ElectionProviderMultiPhase, Everything, Hash, Header, Historical, ImOnline,
+	constants, prod_or_fast, voter_bags, weights, AccountId, AccountIndex, Assets, Babe, Balances,
+	Block, BlockNumber, ElectionProviderMultiPhase, Everything, Hash, Header, Historical, ImOnline,
 	ImOnlineId, Index, Indices, Moment, NominationPools, Offences, OriginCaller, PalletInfo,
 	Preimage, ReserveIdentifier, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
 	RuntimeHoldReason, RuntimeOrigin, RuntimeVersion, Session, SessionKeys, Signature,
@@ -26,8 +26,9 @@ use frame_support::{
 		tokens::{
 			imbalance::ResolveTo, pay::PayFromAccount, Imbalance, UnityAssetBalanceConversion,
 		},
-		ConstU32, Contains, Currency, EitherOf, EitherOfDiverse, EqualPrivilegeOnly, InsideBoth,
-		InstanceFilter, LinearStoragePrice, OnUnbalanced,
+		AsEnsureOriginWithArg, ConstU32, Contains, Currency, EitherOf, EitherOfDiverse,
+		EqualPrivilegeOnly, InsideBoth, InstanceFilter, LinearStoragePrice, NeverEnsureOrigin,
+		OnUnbalanced,
 	},
 	weights::{constants::RocksDbWeight, ConstantMultiplier},
 	PalletId,
@@ -75,9 +76,16 @@ impl pallet_vector::Config for Runtime {
 	type WeightInfo = weights::pallet_vector::WeightInfo<Runtime>;
 	type TimeProvider = pallet_timestamp::Pallet<Runtime>;
 	type Currency = Balances;
+	type Assets = Assets;
+	type GuardianOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 2>,
+	>;
 	type MessageMappingStorageIndex = ConstU64<1>;
 	type PalletId = BridgePalletId;
 	type AvailDomain = ConstU32<1>;
+	type TransferLimitWindow = ConstU32<DAYS>;
+	type MaxUpdaters = ConstU32<16>;
 }
 
 parameter_types! {
@@ -123,6 +131,7 @@ impl da_control::Config for Runtime {
 	type MaxAppKeyLength = constants::da::MaxAppKeyLength;
 	type MaxBlockCols = constants::da::MaxBlockCols;
 	type MaxBlockRows = constants::da::MaxBlockRows;
+	type MaxPendingBlockLenProposals = constants::da::MaxPendingBlockLenProposals;
 	type MinBlockCols = constants::da::MinBlockCols;
 	type MinBlockRows = constants::da::MinBlockRows;
 	type RuntimeEvent = RuntimeEvent;
@@ -715,6 +724,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			ProxyType::NonTransfer => !matches!(
 				c,
 				RuntimeCall::Balances(..)
+					| RuntimeCall::Assets(..)
 					| RuntimeCall::Indices(pallet_indices::Call::transfer { .. })
 			),
 			ProxyType::Governance => matches!(
@@ -790,6 +800,39 @@ impl Contains<RuntimeCallNameOf<Runtime>> for TxPauseWhitelistedCalls {
 	}
 }
 
+parameter_types! {
+	pub const AssetDeposit: Balance = 100 * AVAIL;
+	pub const AssetAccountDeposit: Balance = constants::currency::deposit(1, 16);
+	pub const AssetsStringLimit: u32 = 50;
+	pub const MetadataDepositBase: Balance = constants::currency::deposit(1, 68);
+	pub const MetadataDepositPerByte: Balance = constants::currency::deposit(0, 1);
+	pub const ApprovalDeposit: Balance = constants::currency::deposit(1, 0);
+}
+
+/// Assets can only be created by root, see `pallet_assets::Call::force_create`.
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<NeverEnsureOrigin<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = AssetsStringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 impl pallet_tx_pause::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -870,6 +913,8 @@ impl frame_system::Config for Runtime {
 	type Header = Header;
 	type MaxDiffAppIdPerBlock = ConstU32<1_024>;
 	type MaxTxPerAppIdPerBlock = ConstU32<8_192>;
+	/// Layout of the submitted data sub-trie in the data root.
+	type DataRootVersion = constants::system::DataRootVersion;
 }
 
 impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
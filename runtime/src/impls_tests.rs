@@ -473,6 +473,7 @@ mod tests {
 		type Extrinsic = Extrinsic;
 		type MaxDiffAppIdPerBlock = ConstU32<1_024>;
 		type MaxTxPerAppIdPerBlock = ConstU32<8_192>;
+		type DataRootVersion = ();
 	}
 
 	parameter_types! {
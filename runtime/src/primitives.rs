@@ -72,8 +72,24 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations executed on runtime upgrade.
+pub type Migrations = (
+	da_control::migrations::v1::MigrateToV1<Runtime>,
+	pallet_vector::migrations::v1::MigrateToV1<Runtime, VectorSourceDomain>,
+	pallet_vector::migrations::v2::MigrateToV2<Runtime>,
+	pallet_vector::migrations::v3::MigrateToV3<Runtime>,
+);
+
+/// Pallets appending post-inherents at the end of each block, in order.
+pub type PostInherentProviders = (pallet_vector::Pallet<Runtime>,);
+
+/// Domain of the source chain tracked by the Vector light client before it supported several chains.
+pub type VectorSourceDomain =
+	frame_support::traits::ConstU32<{ pallet_vector::constants::BROADCASTER_DOMAIN }>;
+
 /// ID type for named reserves.
 pub type ReserveIdentifier = [u8; 8];
 
@@ -1,6 +1,6 @@
 use crate::{AccountId, Runtime, RuntimeCall as Call, UncheckedExtrinsic};
 use avail_base::header_extension::{
-	BridgedData, ExtractedTxData, HeaderExtensionDataFilter, SubmittedData,
+	BridgedData, DataRootVersion, ExtractedTxData, HeaderExtensionDataFilter, SubmittedData,
 };
 use avail_core::{
 	data_proof::{tx_uid, AddressedMessage},
@@ -9,11 +9,13 @@ use avail_core::{
 };
 
 use da_control::Call as DACall;
+use frame_support::traits::Get;
 use pallet_vector::Call as VectorCall;
 use sp_core::H256;
 use sp_std::vec::Vec;
 
-/// Filters and extracts `data` from `call` if it is a `DataAvailability::submit_data` type.
+/// Filters and extracts `data` from `call` if it is a `DataAvailability::submit_data` or
+/// `DataAvailability::submit_data_hash` type.
 impl HeaderExtensionDataFilter for Runtime {
 	fn filter(
 		failed_transactions: &[u32],
@@ -64,26 +66,44 @@ fn filter_da_call(
 	app_id: AppId,
 	tx_index: usize,
 ) -> Option<ExtractedTxData> {
-	let DACall::submit_data { data } = call else {
-		return None;
-	};
-
-	if data.is_empty() {
-		return None;
-	}
-
 	let tx_index = u32::try_from(tx_index).ok()?;
-	let submitted_data = Some(SubmittedData::new(
-		app_id,
-		tx_index,
-		data.as_slice().to_vec(),
-	));
-
-	Some(ExtractedTxData {
-		submitted_data,
-		app_extrinsic: Some(app_extrinsic),
-		..Default::default()
-	})
+	match call {
+		DACall::submit_data { data } => {
+			if data.is_empty() {
+				return None;
+			}
+
+			let submitted_data = Some(SubmittedData::new(
+				app_id,
+				tx_index,
+				data.as_slice().to_vec(),
+			));
+
+			Some(ExtractedTxData {
+				submitted_data,
+				app_extrinsic: Some(app_extrinsic),
+				..Default::default()
+			})
+		},
+		// Commitment-only submissions are part of the data root but not of the data matrix.
+		// They fail to dispatch under `DataRootVersion::V1`, whose leaves could collide.
+		DACall::submit_data_hash { data_hash, len } => {
+			let version = <Runtime as frame_system::Config>::DataRootVersion::get();
+			if *len == 0 || version != DataRootVersion::V2 {
+				return None;
+			}
+
+			let submitted_data = Some(SubmittedData::new_hash_only(
+				app_id, tx_index, *data_hash, *len,
+			));
+
+			Some(ExtractedTxData {
+				submitted_data,
+				..Default::default()
+			})
+		},
+		_ => None,
+	}
 }
 
 /// Filters and extracts message references from `call`
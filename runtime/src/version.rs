@@ -17,7 +17,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// Per convention: if the runtime behavior changes, increment spec_version
 	// and set impl_version to 0. This paramenter is typically incremented when
 	// there's an update to the transaction_version.
-	spec_version: 44,
+	spec_version: 45,
 	// The version of the implementation of the specification. Nodes can ignore this. It is only
 	// used to indicate that the code is different. As long as the authoring_version and the
 	// spec_version are the same, the code itself might have changed, but the native and Wasm
@@ -32,7 +32,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// macro or if there are any changes to dispatchable functions, such as the number of
 	// parameters or parameter types. If this number is updated, then the spec_version must also
 	// be updated.
-	transaction_version: 1,
+	transaction_version: 2,
 	apis: apis::runtime_api_versions(),
 	state_version: 1,
 };
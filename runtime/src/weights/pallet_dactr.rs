@@ -22,6 +22,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ip-172-31-12-189`, CPU: `Intel(R) Xeon(R) Platinum 8175M CPU @ 2.50GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! Entries commented as placeholders were not produced by the benchmark CLI and must be
+//! regenerated with `benchmarking.rs`.
 
 // Executed Command:
 // ./target/release/avail-node
@@ -53,6 +56,8 @@ impl<T: frame_system::Config> da_control::WeightInfo for WeightInfo<T> {
 	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	/// Storage: `DataAvailability::NextAppId` (r:1 w:1)
 	/// Proof: `DataAvailability::NextAppId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppKeysById` (r:0 w:1)
+	/// Proof: `DataAvailability::AppKeysById` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	fn create_application_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `312`
@@ -61,7 +66,8 @@ impl<T: frame_system::Config> da_control::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(24_376_000, 0)
 			.saturating_add(Weight::from_parts(0, 3583))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			// Placeholder: the `AppKeysById` write is added by hand, regenerate with `benchmarking.rs`.
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `System::DynamicBlockLength` (r:1 w:1)
 	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
@@ -88,6 +94,8 @@ impl<T: frame_system::Config> da_control::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `DataAvailability::AppKeys` (r:2 w:2)
 	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppKeysById` (r:0 w:1)
+	/// Proof: `DataAvailability::AppKeysById` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	fn set_application_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `377`
@@ -96,7 +104,8 @@ impl<T: frame_system::Config> da_control::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(33_685_000, 0)
 			.saturating_add(Weight::from_parts(0, 6176))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			// Placeholder: the `AppKeysById` write is added by hand, regenerate with `benchmarking.rs`.
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// The range of component `i` is `[0, 524288]`.
 	fn data_root(i: u32, ) -> Weight {
@@ -131,4 +140,86 @@ impl<T: frame_system::Config> da_control::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn submit_data_hash() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(11_297_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// Storage: `DataAvailability::CounterForBlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::CounterForBlockLenProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::NextBlockLenProposalId` (r:1 w:1)
+	/// Proof: `DataAvailability::NextBlockLenProposalId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::BlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::BlockLenProposals` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn schedule_block_length_proposal() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(15_681_000, 0)
+			.saturating_add(Weight::from_parts(0, 3489))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `DataAvailability::BlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::BlockLenProposals` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::CounterForBlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::CounterForBlockLenProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn cancel_block_length_proposal() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(14_694_000, 0)
+			.saturating_add(Weight::from_parts(0, 3489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::CounterForBlockLenProposals` (r:1 w:1)
+	/// Proof: `DataAvailability::CounterForBlockLenProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::BlockLenProposals` (r:17 w:16)
+	/// Proof: `DataAvailability::BlockLenProposals` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:0 w:1)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 16]`.
+	fn apply_block_length_proposals(p: u32, ) -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(6_083_115, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			// Standard Error: 6_145
+			.saturating_add(Weight::from_parts(7_471_632, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2499).saturating_mul(p.into()))
+	}
+	/// Storage: `DataAvailability::BlockLengthController` (r:0 w:1)
+	/// Proof: `DataAvailability::BlockLengthController` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::ScalarUsage` (r:0 w:1)
+	/// Proof: `DataAvailability::ScalarUsage` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn set_block_length_controller() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(7_701_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `System::DynamicBlockLength` (r:1 w:0)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// Storage: `System::AllExtrinsicsLen` (r:1 w:0)
+	/// Proof: `System::AllExtrinsicsLen` (`max_values`: Some(1), `max_size`: Some(5134), added: 5629, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::ScalarUsage` (r:1 w:1)
+	/// Proof: `DataAvailability::ScalarUsage` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn note_scalar_usage() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(14_338_000, 0)
+			.saturating_add(Weight::from_parts(0, 6619))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::ScalarUsage` (r:1 w:1)
+	/// Proof: `DataAvailability::ScalarUsage` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:1 w:1)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	fn adjust_block_length() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(12_604_000, 0)
+			.saturating_add(Weight::from_parts(0, 1509))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -22,6 +22,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ip-172-31-12-189`, CPU: `Intel(R) Xeon(R) Platinum 8175M CPU @ 2.50GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! Entries commented as placeholders were not produced by the benchmark CLI and must be
+//! regenerated with `benchmarking.rs`.
 
 // Executed Command:
 // ./target/release/avail-node
@@ -329,5 +332,150 @@ impl<T: frame_system::Config> pallet_vector::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Vector::BridgedAssets` (r:1 w:1)
+	/// Proof: `Vector::BridgedAssets` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	fn register_asset() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(9_012_000, 0)
+			.saturating_add(Weight::from_parts(0, 3502))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::BridgedAssets` (r:1 w:1)
+	/// Proof: `Vector::BridgedAssets` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	fn deregister_asset() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(9_795_000, 0)
+			.saturating_add(Weight::from_parts(0, 3502))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::AssetTransferLimits` (r:0 w:1)
+	/// Proof: `Vector::AssetTransferLimits` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	fn set_transfer_limits() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(8_302_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::PendingMessages` (r:1 w:1)
+	/// Proof: `Vector::PendingMessages` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AssetTransferLimits` (r:2 w:0)
+	/// Proof: `Vector::AssetTransferLimits` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AssetTransferUsage` (r:2 w:2)
+	/// Proof: `Vector::AssetTransferUsage` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::MessageStatus` (r:0 w:1)
+	/// Proof: `Vector::MessageStatus` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	fn execute_pending_message() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(63_090_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Vector::PendingMessages` (r:1 w:1)
+	/// Proof: `Vector::PendingMessages` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::MessageStatus` (r:0 w:1)
+	/// Proof: `Vector::MessageStatus` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	fn cancel_pending_message() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(12_806_000, 0)
+			.saturating_add(Weight::from_parts(0, 3626))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Vector::ChallengeWindow` (r:0 w:1)
+	/// Proof: `Vector::ChallengeWindow` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_challenge_window() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(6_480_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:0)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn verify_account_proof() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(97_108_000, 0)
+			.saturating_add(Weight::from_parts(0, 3537))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	/// Storage: `Vector::FunctionVerifiers` (r:0 w:1)
+	/// Proof: `Vector::FunctionVerifiers` (`max_values`: None, `max_size`: Some(10036), added: 12511, mode: `MaxEncodedLen`)
+	fn set_function_verifier() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(39_620_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::RetentionWindow` (r:0 w:1)
+	/// Proof: `Vector::RetentionWindow` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_retention_window() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(6_512_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::Head` (r:1 w:0)
+	/// Proof: `Vector::Head` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::OldestSlot` (r:1 w:1)
+	/// Proof: `Vector::OldestSlot` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::PinnedSlots` (r:1 w:0)
+	/// Proof: `Vector::PinnedSlots` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::NextSlot` (r:1 w:1)
+	/// Proof: `Vector::NextSlot` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:0 w:1)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Timestamps` (r:0 w:1)
+	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:0 w:1)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn prune_slot() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(21_947_000, 0)
+			.saturating_add(Weight::from_parts(0, 3485))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Vector::Updaters` (r:1 w:1)
+	/// Proof: `Vector::Updaters` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+	fn add_updater() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(15_802_000, 0)
+			.saturating_add(Weight::from_parts(0, 3986))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::Updaters` (r:1 w:1)
+	/// Proof: `Vector::Updaters` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+	fn remove_updater() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(16_117_000, 0)
+			.saturating_add(Weight::from_parts(0, 3986))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::UpdaterRotations` (r:0 w:1)
+	/// Proof: `Vector::UpdaterRotations` (`max_values`: None, `max_size`: Some(9), added: 2484, mode: `MaxEncodedLen`)
+	fn set_updater_rotation() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(7_044_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::UpdaterReward` (r:0 w:1)
+	/// Proof: `Vector::UpdaterReward` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_updater_reward() -> Weight {
+		// Placeholder, not benchmarked: regenerate with `benchmarking.rs`.
+		Weight::from_parts(6_623_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
 